@@ -4,10 +4,18 @@
 
 use crate::config::SettingsManager;
 use crate::core::mouse::MouseController;
+#[cfg(feature = "tray")]
+use crate::ui::tray::{self, Tray};
 use crate::ui::MainWindow;
 use crate::utils::Result;
 use eframe::egui;
 
+/// 持久化存储中保存暗色模式的键
+const DARK_MODE_KEY: &str = "dark_mode";
+
+/// 默认窗口大小，同时也是最小窗口大小
+const DEFAULT_WINDOW_SIZE: [f32; 2] = [306.0, 308.0];
+
 pub struct MouseClickerApp {
     /// 主窗口
     main_window: MainWindow,
@@ -17,6 +25,9 @@ pub struct MouseClickerApp {
     initialized: bool,
     /// 初始化错误
     init_error: Option<String>,
+    /// 系统托盘（Linux上托盘由独立线程持有，此处为None）
+    #[cfg(feature = "tray")]
+    tray: Option<Tray>,
 }
 
 impl MouseClickerApp {
@@ -30,29 +41,44 @@ impl MouseClickerApp {
         // 尝试加载保存的设置
         let settings_manager = SettingsManager::new().unwrap_or_default();
         // 传递已加载的设置给主窗口
-        let main_window = MainWindow::new(settings_manager.get().clone());
+        let mut main_window = MainWindow::new(settings_manager.get().clone());
+
+        // 恢复上次选择的主题，窗口位置和大小由eframe自动恢复
+        if let Some(dark_mode) = cc
+            .storage
+            .and_then(|storage| eframe::get_value::<bool>(storage, DARK_MODE_KEY))
+        {
+            log::info!("恢复主题: {}", if dark_mode { "暗色" } else { "亮色" });
+            main_window.set_dark_mode(dark_mode);
+        }
 
-        let app = Self {
+        Self {
             main_window,
             settings_manager,
             initialized: false,
             init_error: None,
-        };
-
-        // 尝试从持久化存储中恢复窗口状态
-        if let Some(storage) = cc.storage {
-            if let Some(window_state) = storage.get_string("window_state") {
-                log::info!("恢复窗口状态: {:?}", window_state);
-            }
+            #[cfg(feature = "tray")]
+            tray: None,
         }
-
-        app
     }
 
     /// 初始化应用程序
-    fn initialize(&mut self) -> Result<()> {
+    #[cfg_attr(not(feature = "tray"), allow(unused_variables))]
+    fn initialize(&mut self, ctx: &egui::Context) -> Result<()> {
         // 初始化主窗口的连点器
         self.main_window.initialize_clicker()?;
+
+        // 托盘不可用时仍可正常使用主窗口
+        #[cfg(feature = "tray")]
+        if let Some(handle) = self.main_window.clicker_handle() {
+            match tray::spawn(handle, ctx.clone()) {
+                Ok(tray) => {
+                    self.tray = tray;
+                    self.main_window.set_tray_enabled(true);
+                }
+                Err(e) => log::warn!("创建系统托盘失败: {}", e),
+            }
+        }
         log::info!("应用程序初始化完成");
         Ok(())
     }
@@ -68,22 +94,22 @@ impl MouseClickerApp {
     /// 显示初始化错误
     fn show_init_error(&mut self, ctx: &egui::Context) {
         if let Some(error) = self.init_error.clone() {
-            egui::Window::new("初始化错误")
+            egui::Window::new(t!("app.init_error.title"))
                 .collapsible(false)
                 .resizable(false)
                 .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
                 .show(ctx, |ui| {
-                    ui.label("应用程序初始化失败:");
+                    ui.label(t!("app.init_error.message"));
                     ui.add_space(10.0);
                     ui.label(error);
                     ui.add_space(10.0);
 
-                    if ui.button("重试").clicked() {
+                    if ui.button(t!("app.init_error.retry")).clicked() {
                         self.init_error = None;
                         self.initialized = false;
                     }
 
-                    if ui.button("退出").clicked() {
+                    if ui.button(t!("app.quit")).clicked() {
                         std::process::exit(1);
                     }
                 });
@@ -96,7 +122,7 @@ impl eframe::App for MouseClickerApp {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
         // 如果还没有初始化，尝试初始化
         if !self.initialized && self.init_error.is_none() {
-            match self.initialize() {
+            match self.initialize(ctx) {
                 Ok(_) => {
                     self.initialized = true;
                     log::info!("应用程序初始化成功");
@@ -114,6 +140,11 @@ impl eframe::App for MouseClickerApp {
             return;
         }
 
+        #[cfg(feature = "tray")]
+        if let Some(tray) = &mut self.tray {
+            tray.refresh();
+        }
+
         // 如果已初始化，更新主窗口
         if self.initialized {
             self.main_window.update(ctx);
@@ -124,7 +155,7 @@ impl eframe::App for MouseClickerApp {
                     ui.add_space(100.0);
                     ui.spinner();
                     ui.add_space(20.0);
-                    ui.label("正在初始化...");
+                    ui.label(t!("app.initializing"));
                 });
             });
         }
@@ -135,6 +166,9 @@ impl eframe::App for MouseClickerApp {
 
     /// 应用程序关闭时的清理工作
     fn on_exit(&mut self, _gl: Option<&eframe::glow::Context>) {
+        // 停止连点，确保长按中的按键被释放
+        self.main_window.shutdown();
+
         // 保存设置
         if let Err(e) = self.save_settings() {
             log::error!("保存设置失败: {}", e);
@@ -143,29 +177,32 @@ impl eframe::App for MouseClickerApp {
         log::info!("应用程序正在退出");
     }
 
+    /// 点击指示层需要透明背景，主窗口由面板填充，不受影响
+    fn clear_color(&self, _visuals: &egui::Visuals) -> [f32; 4] {
+        [0.0; 4]
+    }
+
     /// 自动保存
-    fn save(&mut self, _storage: &mut dyn eframe::Storage) {
-        // 简化存储处理
-        let _settings = self.main_window.get_settings();
-        log::info!("保存应用程序状态");
+    fn save(&mut self, storage: &mut dyn eframe::Storage) {
+        eframe::set_value(storage, DARK_MODE_KEY, &self.main_window.dark_mode());
+        log::debug!("保存应用程序状态");
     }
 }
 
 /// 应用程序启动器
 pub fn run_app() -> Result<()> {
-    env_logger::init();
     log::info!("启动应用程序 run_app");
 
     // 窗口选项
     let options = eframe::NativeOptions {
         viewport: egui::ViewportBuilder::default()
-            .with_inner_size([306.0, 308.0])
-            .with_min_inner_size([306.0, 308.0])
-            .with_max_inner_size([306.0, 308.0])
-            .with_resizable(false)
-            .with_maximize_button(false)
+            .with_inner_size(DEFAULT_WINDOW_SIZE)
+            .with_min_inner_size(DEFAULT_WINDOW_SIZE)
+            .with_resizable(true)
+            // 透明的点击指示层要求GL配置支持透明，主窗口由面板填充，外观不变
+            .with_transparent(true)
             .with_title(if MouseController::is_admin() {
-                "Mouse Clicker (管理员)"
+                t!("app.title_admin")
             } else {
                 "Mouse Clicker"
             })
@@ -174,6 +211,8 @@ pub fn run_app() -> Result<()> {
                 eframe::icon_data::from_png_bytes(&include_bytes!("../assets/icon.png")[..])
                     .expect("Failed to load icon"),
             ),
+        // 退出时保存窗口位置和大小，下次启动时恢复
+        persist_window: true,
         ..Default::default()
     };
 
@@ -183,5 +222,5 @@ pub fn run_app() -> Result<()> {
         options,
         Box::new(|cc| Box::new(MouseClickerApp::new(cc))),
     )
-    .map_err(|e| format!("启动应用程序失败: {}", e))
+    .map_err(|e| t!("app.error.start", error = e))
 }
@@ -0,0 +1,233 @@
+//! 命令行参数模块
+//!
+//! 解析启动参数，并实现无界面的子命令
+
+use crate::config::{InputBackend, MouseButton, ScriptSettings, SettingsManager};
+use crate::core::backend::dry_run::format_elapsed;
+use crate::core::backend::BackendConfig;
+use crate::core::diagnostics::{DiagnosticReport, HotkeyProbe};
+use crate::core::mouse::MouseController;
+use crate::core::{ClickerEvent, ClickerManager, ErrorKind, StopReason};
+use crate::utils::Result;
+use std::path::PathBuf;
+
+/// 要执行的命令
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    /// 启动图形界面
+    Gui,
+    /// 检查输入后端：读取屏幕尺寸和指针位置，移动指针并点击
+    Probe,
+    /// 运行诊断并输出修复建议
+    Doctor,
+    /// 运行脚本文件
+    Run(PathBuf),
+    /// 显示帮助
+    Help,
+}
+
+/// 命令行参数
+#[derive(Debug, Clone)]
+pub struct CliArgs {
+    pub command: Command,
+    /// 覆盖配置文件中的输入后端
+    pub backend: Option<InputBackend>,
+    /// 覆盖配置文件中的X11显示
+    pub display: Option<String>,
+    /// run命令只模拟运行，打印本应执行的输入
+    pub dry_run: bool,
+    /// 模拟运行时快进，不实际等待
+    pub fast: bool,
+}
+
+/// 解析命令行参数（不含程序名）
+pub fn parse_args(args: impl IntoIterator<Item = String>) -> Result<CliArgs> {
+    let mut parsed = CliArgs {
+        command: Command::Gui,
+        backend: None,
+        display: None,
+        dry_run: false,
+        fast: false,
+    };
+
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--backend" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| t!("cli.error.missing_value", option = "--backend"))?;
+                parsed.backend = Some(value.parse()?);
+            }
+            "--display" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| t!("cli.error.missing_value", option = "--display"))?;
+                parsed.display = Some(value);
+            }
+            "--dry-run" => parsed.dry_run = true,
+            "--fast" => parsed.fast = true,
+            "-h" | "--help" | "help" => parsed.command = Command::Help,
+            "probe" => parsed.command = Command::Probe,
+            "doctor" => parsed.command = Command::Doctor,
+            "run" => {
+                let path = args
+                    .next()
+                    .ok_or_else(|| t!("cli.error.missing_value", option = "run"))?;
+                parsed.command = Command::Run(PathBuf::from(path));
+            }
+            other => {
+                return Err(format!(
+                    "{}\n\n{}",
+                    t!("cli.error.unknown_argument", argument = other),
+                    usage()
+                ))
+            }
+        }
+    }
+
+    Ok(parsed)
+}
+
+/// 帮助文本
+pub fn usage() -> &'static str {
+    t!("cli.usage")
+}
+
+/// 执行probe命令
+pub fn run_probe() -> Result<()> {
+    let settings = SettingsManager::new().unwrap_or_default().get().clone();
+    let config = BackendConfig::from_settings(&settings);
+    let mut mouse = MouseController::new(&config)?;
+    println!(
+        "{}",
+        t!("cli.probe.backend", backend = mouse.backend_name())
+    );
+
+    let (width, height) = mouse.display_size()?;
+    println!("{}", t!("cli.probe.screen", width = width, height = height));
+
+    let (x, y) = mouse.location()?;
+    println!("{}", t!("cli.probe.pointer", x = x, y = y));
+
+    let target = (width / 2, height / 2);
+    mouse.move_to(target.0, target.1)?;
+    let actual = mouse.location()?;
+    if actual != target {
+        return Err(t!(
+            "cli.probe.move_mismatch",
+            expected = format!("{:?}", target),
+            actual = format!("{:?}", actual)
+        ));
+    }
+    println!("{}", t!("cli.probe.moved", x = actual.0, y = actual.1));
+
+    mouse.click(MouseButton::Left)?;
+    println!("{}", t!("cli.probe.clicked"));
+    Ok(())
+}
+
+/// 执行doctor命令
+pub fn run_doctor() -> Result<()> {
+    let settings = SettingsManager::new().unwrap_or_default().get().clone();
+    let report = DiagnosticReport::run(&settings, HotkeyProbe::Register);
+    print!("{}", report.to_text());
+
+    if report.has_failures() {
+        Err(t!("cli.doctor.failed").to_string())
+    } else {
+        Ok(())
+    }
+}
+
+/// 执行run命令
+///
+/// 使用配置文件中的设置运行脚本文件，脚本结束或按下热键时退出。
+/// `dry_run` 为true时不执行输入，按时间顺序打印本应执行的输入；
+/// 同时指定 `fast` 时使用虚拟时钟，不实际等待
+pub fn run_script(path: PathBuf, dry_run: bool, fast: bool) -> Result<()> {
+    let mut settings = SettingsManager::new().unwrap_or_default().get().clone();
+    settings.script = ScriptSettings {
+        enabled: true,
+        path,
+        source: String::new(),
+    };
+    let hotkey = settings.hotkey;
+
+    let mut manager = ClickerManager::new(settings)?;
+    manager.set_dry_run(dry_run);
+    manager.set_fast_forward(fast);
+    let events = manager.subscribe();
+    manager.toggle()?;
+    println!("{}", t!("cli.run.started", hotkey = hotkey));
+
+    for event in events {
+        match event {
+            ClickerEvent::ScriptLog(message) => println!("{}", message),
+            ClickerEvent::Simulated { elapsed, input } => {
+                println!("{}  {}", format_elapsed(elapsed), input)
+            }
+            event @ (ClickerEvent::EventsDropped(_) | ClickerEvent::CoordinatesUnchecked(_)) => {
+                eprintln!("{}", event)
+            }
+            // 权限错误不会停止运行，其余错误随停止事件一起报告
+            ClickerEvent::Error(ErrorKind::Permission(e)) => {
+                eprintln!("{}", t!("error.permission", error = e))
+            }
+            ClickerEvent::Stopped(reason @ (StopReason::Error(_) | StopReason::FailSafe(_))) => {
+                return Err(reason.to_string());
+            }
+            ClickerEvent::Stopped(reason) => {
+                println!("{}", t!("event.stopped", reason = reason));
+                return Ok(());
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<CliArgs> {
+        parse_args(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn no_arguments_start_the_gui() {
+        let args = parse(&[]).unwrap();
+        assert_eq!(args.command, Command::Gui);
+        assert_eq!(args.backend, None);
+        assert_eq!(args.display, None);
+        assert!(!args.dry_run && !args.fast);
+    }
+
+    #[test]
+    fn options_may_surround_the_command() {
+        let args = parse(&["--backend", "XTest", "probe", "--display", ":99"]).unwrap();
+        assert_eq!(args.command, Command::Probe);
+        assert_eq!(args.backend, Some(InputBackend::XTest));
+        assert_eq!(args.display.as_deref(), Some(":99"));
+    }
+
+    #[test]
+    fn run_takes_a_script_path() {
+        let args = parse(&["--dry-run", "run", "demo.rhai", "--fast"]).unwrap();
+        assert_eq!(args.command, Command::Run(PathBuf::from("demo.rhai")));
+        assert!(args.dry_run && args.fast);
+
+        assert_eq!(parse(&["doctor"]).unwrap().command, Command::Doctor);
+        assert_eq!(parse(&["-h"]).unwrap().command, Command::Help);
+    }
+
+    #[test]
+    fn invalid_arguments_are_rejected() {
+        assert!(parse(&["--backend"]).is_err());
+        assert!(parse(&["--display"]).is_err());
+        assert!(parse(&["run"]).is_err());
+        assert!(parse(&["--backend", "wayland"]).is_err());
+        assert!(parse(&["--verbose"]).is_err());
+    }
+}
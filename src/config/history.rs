@@ -0,0 +1,270 @@
+//! 连点历史记录
+//!
+//! 每次运行结束后追加一条记录到配置目录下的 `history.jsonl`，并支持导出CSV
+
+use crate::config::SettingsManager;
+use crate::utils::Result;
+use jiff::Timestamp;
+use serde::{Deserialize, Serialize};
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::PathBuf;
+
+/// 单次运行的统计记录
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionRecord {
+    /// 开始时间
+    pub started_at: Timestamp,
+    /// 结束时间
+    pub ended_at: Timestamp,
+    /// 配置名称
+    pub profile: String,
+    /// 目标点击次数（None表示无限次）
+    pub target_count: Option<u32>,
+    /// 实际点击次数
+    pub click_count: u32,
+    /// 失败的点击次数
+    pub failures: u32,
+    /// 平均点击间隔（毫秒）
+    pub avg_interval_ms: Option<f64>,
+    /// 最短点击间隔（毫秒）
+    pub min_interval_ms: Option<f64>,
+    /// 最长点击间隔（毫秒）
+    pub max_interval_ms: Option<f64>,
+    /// 停止原因
+    pub stop_reason: StopKind,
+}
+
+/// 停止原因的类别
+///
+/// 历史文件中以与语言无关的键保存，显示时再翻译
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StopKind {
+    /// 用户停止
+    User,
+    /// 达到目标次数
+    TargetReached,
+    /// 紧急停止
+    FailSafe,
+    /// 像素颜色不再匹配
+    ColorMismatch,
+    /// 未找到模板图像
+    TemplateNotFound,
+    /// 脚本执行完毕
+    ScriptFinished,
+    /// 快进模拟达到上限
+    SimulationLimit,
+    /// 连发全部完成
+    BurstsCompleted,
+    /// 发生错误
+    Error,
+    /// 应用退出
+    Exit,
+}
+
+impl std::fmt::Display for StopKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let text = match self {
+            Self::User => t!("stop_reason.user"),
+            Self::TargetReached => t!("stop_reason.target_reached"),
+            Self::FailSafe => t!("history.stop.failsafe"),
+            Self::ColorMismatch => t!("stop_reason.color_mismatch"),
+            Self::TemplateNotFound => t!("stop_reason.template_not_found"),
+            Self::ScriptFinished => t!("stop_reason.script_finished"),
+            Self::SimulationLimit => t!("stop_reason.simulation_limit"),
+            Self::BurstsCompleted => t!("stop_reason.bursts_completed"),
+            Self::Error => t!("history.stop.error"),
+            Self::Exit => t!("stop_reason.exit"),
+        };
+        write!(f, "{}", text)
+    }
+}
+
+impl StopKind {
+    /// 写入文件时使用的键，与JSON中的取值一致
+    pub fn key(self) -> &'static str {
+        match self {
+            Self::User => "user",
+            Self::TargetReached => "target_reached",
+            Self::FailSafe => "fail_safe",
+            Self::ColorMismatch => "color_mismatch",
+            Self::TemplateNotFound => "template_not_found",
+            Self::ScriptFinished => "script_finished",
+            Self::SimulationLimit => "simulation_limit",
+            Self::BurstsCompleted => "bursts_completed",
+            Self::Error => "error",
+            Self::Exit => "exit",
+        }
+    }
+}
+
+impl SessionRecord {
+    /// 运行时长（秒）
+    pub fn duration_seconds(&self) -> f64 {
+        (self.ended_at.as_millisecond() - self.started_at.as_millisecond()) as f64 / 1000.0
+    }
+
+    /// 实际点击速率（次/秒）
+    pub fn clicks_per_second(&self) -> Option<f64> {
+        let seconds = self.duration_seconds();
+        (seconds > 0.0).then(|| f64::from(self.click_count) / seconds)
+    }
+}
+
+/// 历史记录存储
+pub struct HistoryStore {
+    path: PathBuf,
+}
+
+impl HistoryStore {
+    /// 使用配置目录下的 `history.jsonl`
+    pub fn new() -> Result<Self> {
+        Ok(Self {
+            path: SettingsManager::config_dir()?.join("history.jsonl"),
+        })
+    }
+
+    /// 追加一条记录
+    pub fn append(&self, record: &SessionRecord) -> Result<()> {
+        let line =
+            serde_json::to_string(record).map_err(|e| t!("history.error.serialize", error = e))?;
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .map_err(|e| t!("history.error.open", error = e))?;
+        writeln!(file, "{}", line).map_err(|e| t!("history.error.write", error = e))?;
+        Ok(())
+    }
+
+    /// 读取全部记录，跳过无法解析的行
+    pub fn load(&self) -> Result<Vec<SessionRecord>> {
+        if !self.path.exists() {
+            return Ok(Vec::new());
+        }
+
+        let content =
+            std::fs::read_to_string(&self.path).map_err(|e| t!("history.error.read", error = e))?;
+        let records = content
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .filter_map(|line| match serde_json::from_str(line) {
+                Ok(record) => Some(record),
+                Err(e) => {
+                    log::warn!("跳过无法解析的历史记录: {}", e);
+                    None
+                }
+            })
+            .collect();
+        Ok(records)
+    }
+
+    /// 导出CSV到历史文件旁的 `history.csv`，返回导出路径
+    pub fn export_csv(&self, records: &[SessionRecord]) -> Result<PathBuf> {
+        let path = self.path.with_extension("csv");
+        std::fs::write(&path, to_csv(records))
+            .map_err(|e| t!("history.error.export", error = e))?;
+        log::info!("历史记录已导出到: {:?}", path);
+        Ok(path)
+    }
+}
+
+/// 将记录转换为CSV文本
+pub fn to_csv(records: &[SessionRecord]) -> String {
+    let mut csv = String::from(
+        "started_at,ended_at,profile,target_count,click_count,failures,\
+         avg_interval_ms,min_interval_ms,max_interval_ms,clicks_per_second,stop_reason\n",
+    );
+    let number = |value: Option<f64>| value.map(|v| format!("{:.1}", v)).unwrap_or_default();
+
+    for record in records {
+        let fields = [
+            record.started_at.to_string(),
+            record.ended_at.to_string(),
+            csv_field(&record.profile),
+            record
+                .target_count
+                .map(|c| c.to_string())
+                .unwrap_or_default(),
+            record.click_count.to_string(),
+            record.failures.to_string(),
+            number(record.avg_interval_ms),
+            number(record.min_interval_ms),
+            number(record.max_interval_ms),
+            record
+                .clicks_per_second()
+                .map(|r| format!("{:.2}", r))
+                .unwrap_or_default(),
+            record.stop_reason.key().to_string(),
+        ];
+        csv.push_str(&fields.join(","));
+        csv.push('\n');
+    }
+    csv
+}
+
+/// 按需为CSV字段加引号
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(profile: &str, stop_reason: StopKind) -> SessionRecord {
+        SessionRecord {
+            started_at: Timestamp::from_second(1_700_000_000).unwrap(),
+            ended_at: Timestamp::from_second(1_700_000_010).unwrap(),
+            profile: profile.to_string(),
+            target_count: None,
+            click_count: 20,
+            failures: 0,
+            avg_interval_ms: Some(500.0),
+            min_interval_ms: None,
+            max_interval_ms: None,
+            stop_reason,
+        }
+    }
+
+    #[test]
+    fn csv_field_quotes_only_when_needed() {
+        assert_eq!(csv_field("默认"), "默认");
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(csv_field("two\nlines"), "\"two\nlines\"");
+    }
+
+    #[test]
+    fn stop_kind_key_matches_json() {
+        for kind in [
+            StopKind::User,
+            StopKind::TargetReached,
+            StopKind::FailSafe,
+            StopKind::ColorMismatch,
+            StopKind::TemplateNotFound,
+            StopKind::ScriptFinished,
+            StopKind::SimulationLimit,
+            StopKind::BurstsCompleted,
+            StopKind::Error,
+            StopKind::Exit,
+        ] {
+            let json = serde_json::to_string(&kind).unwrap();
+            assert_eq!(json, format!("\"{}\"", kind.key()));
+            assert_eq!(serde_json::from_str::<StopKind>(&json).unwrap(), kind);
+        }
+    }
+
+    #[test]
+    fn csv_uses_stable_stop_keys() {
+        let csv = to_csv(&[record("a,b", StopKind::TargetReached)]);
+        let row = csv.lines().nth(1).unwrap();
+        assert!(row.contains(",\"a,b\","));
+        assert!(row.ends_with(",2.00,target_reached"));
+    }
+}
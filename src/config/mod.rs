@@ -2,6 +2,12 @@
 //!
 //! 负责应用程序设置的保存、加载和管理
 
+pub mod history;
+pub mod profiles;
+pub mod sequence;
 pub mod settings;
 
+pub use history::*;
+pub use profiles::*;
+pub use sequence::*;
 pub use settings::*;
@@ -0,0 +1,70 @@
+//! 配置方案管理
+//!
+//! 每个配置方案保存为配置目录下 `profiles/<名称>.json`，便于在界面和托盘中快速切换
+
+use crate::config::{AppSettings, SettingsManager};
+use crate::utils::Result;
+use std::path::PathBuf;
+
+/// 配置方案存储
+pub struct ProfileStore {
+    dir: PathBuf,
+}
+
+impl ProfileStore {
+    /// 使用配置目录下的 `profiles` 目录，不存在时自动创建
+    pub fn new() -> Result<Self> {
+        let dir = SettingsManager::config_dir()?.join("profiles");
+        std::fs::create_dir_all(&dir).map_err(|e| t!("profile.error.create_dir", error = e))?;
+        Ok(Self { dir })
+    }
+
+    /// 列出所有配置方案名称，按名称排序
+    pub fn list(&self) -> Result<Vec<String>> {
+        let entries =
+            std::fs::read_dir(&self.dir).map_err(|e| t!("profile.error.read_dir", error = e))?;
+        let mut names: Vec<String> = entries
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+            .filter_map(|path| Some(path.file_stem()?.to_str()?.to_string()))
+            .collect();
+        names.sort();
+        Ok(names)
+    }
+
+    /// 加载配置方案
+    pub fn load(&self, name: &str) -> Result<AppSettings> {
+        let path = self.path(name)?;
+        let content = std::fs::read_to_string(&path)
+            .map_err(|e| t!("profile.error.read", name = name, error = e))?;
+        let mut settings: AppSettings = serde_json::from_str(&content)
+            .map_err(|e| t!("profile.error.parse", name = name, error = e))?;
+        // 以文件名为准，手动重命名文件后依然能正确显示
+        settings.profile = name.to_string();
+        settings.validate()?;
+        Ok(settings)
+    }
+
+    /// 以设置中的配置名称保存，已存在时覆盖
+    pub fn save(&self, settings: &AppSettings) -> Result<()> {
+        settings.validate()?;
+        let path = self.path(&settings.profile)?;
+        let content = serde_json::to_string_pretty(settings)
+            .map_err(|e| t!("profile.error.serialize", error = e))?;
+        std::fs::write(&path, content).map_err(|e| t!("profile.error.write", error = e))?;
+        log::info!("配置方案已保存到: {:?}", path);
+        Ok(())
+    }
+
+    /// 配置方案文件路径
+    fn path(&self, name: &str) -> Result<PathBuf> {
+        if name.trim().is_empty() {
+            return Err(t!("settings.error.empty_profile").to_string());
+        }
+        if name.starts_with('.') || name.contains(['/', '\\', ':']) {
+            return Err(t!("profile.error.invalid_name", name = name));
+        }
+        Ok(self.dir.join(format!("{}.json", name)))
+    }
+}
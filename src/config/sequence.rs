@@ -0,0 +1,180 @@
+//! 操作序列
+//!
+//! 描述按顺序执行的鼠标和键盘操作，连点器可以按序列循环执行
+
+use crate::config::{ClickPoint, MouseButton};
+use serde::{Deserialize, Serialize};
+
+/// 序列中的一步操作
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Action {
+    /// 点击，`point` 为None时在指针当前位置点击
+    Click {
+        button: MouseButton,
+        #[serde(default)]
+        point: Option<ClickPoint>,
+    },
+    /// 移动指针
+    Move { point: ClickPoint },
+    /// 等待（毫秒）
+    Wait { ms: u64 },
+    /// 垂直滚动，正数向下，负数向上
+    Scroll { amount: i32 },
+    /// 敲击按键
+    KeyTap { key: String },
+    /// 按下按键，直到对应的松开操作或序列结束
+    KeyPress { key: String },
+    /// 松开按键
+    KeyRelease { key: String },
+    /// 输入文本
+    TypeText { text: String },
+    /// 按住左键从一点拖动到另一点
+    Drag { from: ClickPoint, to: ClickPoint },
+    /// 循环开始，到对应的循环结束之间的步骤重复执行 `count` 次
+    Loop { count: u32 },
+    /// 循环结束
+    EndLoop,
+}
+
+impl Action {
+    /// 操作类型
+    pub fn kind(&self) -> ActionKind {
+        match self {
+            Self::Click { .. } => ActionKind::Click,
+            Self::Move { .. } => ActionKind::Move,
+            Self::Wait { .. } => ActionKind::Wait,
+            Self::Scroll { .. } => ActionKind::Scroll,
+            Self::KeyTap { .. } => ActionKind::KeyTap,
+            Self::KeyPress { .. } => ActionKind::KeyPress,
+            Self::KeyRelease { .. } => ActionKind::KeyRelease,
+            Self::TypeText { .. } => ActionKind::TypeText,
+            Self::Drag { .. } => ActionKind::Drag,
+            Self::Loop { .. } => ActionKind::Loop,
+            Self::EndLoop => ActionKind::EndLoop,
+        }
+    }
+}
+
+impl std::fmt::Display for Action {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.kind())?;
+        match self {
+            Self::Click {
+                button,
+                point: Some(point),
+            } => write!(f, " {} @ {}", button, point),
+            Self::Click {
+                button,
+                point: None,
+            } => write!(f, " {}", button),
+            Self::Move { point } => write!(f, " {}", point),
+            Self::Wait { ms } => write!(f, " {} ms", ms),
+            Self::Scroll { amount } => write!(f, " {}", amount),
+            Self::KeyTap { key } | Self::KeyPress { key } | Self::KeyRelease { key } => {
+                write!(f, " {}", key)
+            }
+            Self::TypeText { text } => write!(f, " \"{}\"", text),
+            Self::Drag { from, to } => write!(f, " {} → {}", from, to),
+            Self::Loop { count } => write!(f, " ×{}", count),
+            Self::EndLoop => Ok(()),
+        }
+    }
+}
+
+/// 操作类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ActionKind {
+    Click,
+    Move,
+    Wait,
+    Scroll,
+    KeyTap,
+    KeyPress,
+    KeyRelease,
+    TypeText,
+    Drag,
+    Loop,
+    EndLoop,
+}
+
+impl std::fmt::Display for ActionKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let text = match self {
+            Self::Click => t!("action.click"),
+            Self::Move => t!("action.move"),
+            Self::Wait => t!("action.wait"),
+            Self::Scroll => t!("action.scroll"),
+            Self::KeyTap => t!("action.key_tap"),
+            Self::KeyPress => t!("action.key_press"),
+            Self::KeyRelease => t!("action.key_release"),
+            Self::TypeText => t!("action.type_text"),
+            Self::Drag => t!("action.drag"),
+            Self::Loop => t!("action.loop"),
+            Self::EndLoop => t!("action.end_loop"),
+        };
+        write!(f, "{}", text)
+    }
+}
+
+impl ActionKind {
+    /// 获取所有操作类型
+    pub fn all() -> Vec<ActionKind> {
+        vec![
+            Self::Click,
+            Self::Move,
+            Self::Wait,
+            Self::Scroll,
+            Self::KeyTap,
+            Self::KeyPress,
+            Self::KeyRelease,
+            Self::TypeText,
+            Self::Drag,
+            Self::Loop,
+            Self::EndLoop,
+        ]
+    }
+
+    /// 该类型的默认操作，用于新增步骤
+    pub fn default_action(self) -> Action {
+        match self {
+            Self::Click => Action::Click {
+                button: MouseButton::default(),
+                point: None,
+            },
+            Self::Move => Action::Move {
+                point: ClickPoint { x: 0, y: 0 },
+            },
+            Self::Wait => Action::Wait { ms: 500 },
+            Self::Scroll => Action::Scroll { amount: 3 },
+            Self::KeyTap => Action::KeyTap {
+                key: "enter".to_string(),
+            },
+            Self::KeyPress => Action::KeyPress {
+                key: "shift".to_string(),
+            },
+            Self::KeyRelease => Action::KeyRelease {
+                key: "shift".to_string(),
+            },
+            Self::TypeText => Action::TypeText {
+                text: String::new(),
+            },
+            Self::Drag => Action::Drag {
+                from: ClickPoint { x: 0, y: 0 },
+                to: ClickPoint { x: 100, y: 0 },
+            },
+            Self::Loop => Action::Loop { count: 2 },
+            Self::EndLoop => Action::EndLoop,
+        }
+    }
+}
+
+/// 操作序列设置
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SequenceSettings {
+    /// 是否启用，启用后每轮按顺序执行所有步骤，轮与轮之间等待点击间隔
+    pub enabled: bool,
+    /// 步骤
+    #[serde(default)]
+    pub steps: Vec<Action>,
+}
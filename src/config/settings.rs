@@ -2,6 +2,8 @@
 //!
 //! 负责配置文件的读取、保存和默认值管理
 
+use crate::config::SequenceSettings;
+use crate::i18n::Locale;
 use crate::utils::Result;
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
@@ -32,17 +34,47 @@ impl Default for MouseButton {
 impl std::fmt::Display for MouseButton {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let text = match self {
-            Self::Left => "左键单击",
-            Self::Right => "右键单击",
-            Self::LeftLongPress => "左键长按",
-            Self::RightLongPress => "右键长按",
-            Self::ScrollUp => "向上滚动",
-            Self::ScrollDown => "向下滚动",
+            Self::Left => t!("mouse_button.left"),
+            Self::Right => t!("mouse_button.right"),
+            Self::LeftLongPress => t!("mouse_button.left_long_press"),
+            Self::RightLongPress => t!("mouse_button.right_long_press"),
+            Self::ScrollUp => t!("mouse_button.scroll_up"),
+            Self::ScrollDown => t!("mouse_button.scroll_down"),
         };
         write!(f, "{}", text)
     }
 }
 
+impl MouseButton {
+    /// 获取所有按键类型
+    pub fn all() -> Vec<MouseButton> {
+        vec![
+            Self::Left,
+            Self::Right,
+            Self::LeftLongPress,
+            Self::RightLongPress,
+            Self::ScrollUp,
+            Self::ScrollDown,
+        ]
+    }
+}
+
+impl std::str::FromStr for MouseButton {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "left" => Ok(Self::Left),
+            "right" => Ok(Self::Right),
+            "left_long" => Ok(Self::LeftLongPress),
+            "right_long" => Ok(Self::RightLongPress),
+            "scroll_up" => Ok(Self::ScrollUp),
+            "scroll_down" => Ok(Self::ScrollDown),
+            _ => Err(t!("mouse_button.unknown", name = s)),
+        }
+    }
+}
+
 /// 功能键类型
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum FunctionKey {
@@ -92,9 +124,490 @@ impl FunctionKey {
     }
 }
 
+/// 鼠标输入后端
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum InputBackend {
+    /// 根据会话类型自动选择
+    #[default]
+    Auto,
+    /// enigo（Windows/macOS/X11）
+    Enigo,
+    /// Linux uinput 虚拟设备（支持Wayland）
+    Uinput,
+    /// X11 XTest扩展，可指定显示
+    XTest,
+}
+
+impl std::fmt::Display for InputBackend {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let text = match self {
+            Self::Auto => t!("input_backend.auto"),
+            Self::Enigo => "enigo",
+            Self::Uinput => "uinput",
+            Self::XTest => "XTest",
+        };
+        write!(f, "{}", text)
+    }
+}
+
+impl std::str::FromStr for InputBackend {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "auto" => Ok(Self::Auto),
+            "enigo" => Ok(Self::Enigo),
+            "uinput" => Ok(Self::Uinput),
+            "xtest" => Ok(Self::XTest),
+            _ => Err(t!("input_backend.unknown", name = s)),
+        }
+    }
+}
+
+impl InputBackend {
+    /// 获取当前平台可用的输入后端
+    pub fn all() -> Vec<InputBackend> {
+        if cfg!(target_os = "linux") {
+            vec![Self::Auto, Self::Enigo, Self::Uinput, Self::XTest]
+        } else {
+            vec![Self::Auto, Self::Enigo]
+        }
+    }
+}
+
+/// 屏幕角落
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ScreenCorner {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+impl std::fmt::Display for ScreenCorner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let text = match self {
+            Self::TopLeft => t!("corner.top_left"),
+            Self::TopRight => t!("corner.top_right"),
+            Self::BottomLeft => t!("corner.bottom_left"),
+            Self::BottomRight => t!("corner.bottom_right"),
+        };
+        write!(f, "{}", text)
+    }
+}
+
+impl ScreenCorner {
+    /// 获取所有屏幕角落
+    pub fn all() -> Vec<ScreenCorner> {
+        vec![
+            Self::TopLeft,
+            Self::TopRight,
+            Self::BottomLeft,
+            Self::BottomRight,
+        ]
+    }
+}
+
+/// 屏幕坐标点
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClickPoint {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl std::fmt::Display for ClickPoint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+/// 点击位置的参照
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PointAnchor {
+    /// 屏幕绝对坐标
+    #[default]
+    Screen,
+    /// 相对目标窗口客户区左上角的像素
+    Window,
+    /// 目标窗口客户区宽高的百分比
+    WindowPercent,
+    /// 相对指定显示器左上角的逻辑像素
+    Monitor,
+}
+
+impl std::fmt::Display for PointAnchor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let text = match self {
+            Self::Screen => t!("point_anchor.screen"),
+            Self::Window => t!("point_anchor.window"),
+            Self::WindowPercent => t!("point_anchor.window_percent"),
+            Self::Monitor => t!("point_anchor.monitor"),
+        };
+        write!(f, "{}", text)
+    }
+}
+
+impl PointAnchor {
+    /// 获取所有参照
+    pub fn all() -> Vec<PointAnchor> {
+        vec![
+            Self::Screen,
+            Self::Window,
+            Self::WindowPercent,
+            Self::Monitor,
+        ]
+    }
+
+    /// 是否相对目标窗口
+    pub fn is_window_relative(&self) -> bool {
+        matches!(self, Self::Window | Self::WindowPercent)
+    }
+}
+
+/// 点击位置
+///
+/// 相对窗口的位置在点击时按目标窗口当前的位置和尺寸换算，
+/// 窗口移动或分辨率变化后依然有效
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ClickTarget {
+    /// 旧版本只保存屏幕坐标，缺省时按屏幕坐标处理
+    #[serde(default)]
+    pub anchor: PointAnchor,
+    /// 横坐标，按百分比参照时为0-100
+    pub x: f32,
+    /// 纵坐标，按百分比参照时为0-100
+    pub y: f32,
+    /// 相对显示器时的显示器名称
+    #[serde(default)]
+    pub monitor: Option<String>,
+}
+
+impl ClickTarget {
+    /// 是否需要目标窗口才能换算
+    pub fn needs_window(&self) -> bool {
+        self.anchor.is_window_relative()
+    }
+
+    /// 由屏幕坐标创建，`area` 为目标窗口客户区，相对窗口的参照必须提供。
+    /// 相对显示器的位置需要显示器布局，见 `MonitorLayout::target_from_screen`
+    pub fn from_screen(
+        anchor: PointAnchor,
+        point: ClickPoint,
+        area: Option<ScreenRegion>,
+    ) -> Option<Self> {
+        let (x, y) = (point.x as f32, point.y as f32);
+        let (x, y) = match (anchor, area) {
+            (PointAnchor::Screen, _) => (x, y),
+            (PointAnchor::Window, Some(area)) => (x - area.x as f32, y - area.y as f32),
+            (PointAnchor::WindowPercent, Some(area)) if area.width > 0 && area.height > 0 => (
+                (x - area.x as f32) * 100.0 / area.width as f32,
+                (y - area.y as f32) * 100.0 / area.height as f32,
+            ),
+            _ => return None,
+        };
+        Some(Self {
+            anchor,
+            x,
+            y,
+            monitor: None,
+        })
+    }
+
+    /// 换算为屏幕坐标，`area` 为目标窗口客户区，相对窗口的参照缺少窗口时返回None。
+    /// 相对显示器的位置需要显示器布局，见 `MonitorLayout::resolve`
+    pub fn resolve(&self, area: Option<ScreenRegion>) -> Option<ClickPoint> {
+        let (x, y) = match (self.anchor, area) {
+            (PointAnchor::Screen, _) => (self.x, self.y),
+            (PointAnchor::Window, Some(area)) => (area.x as f32 + self.x, area.y as f32 + self.y),
+            (PointAnchor::WindowPercent, Some(area)) => (
+                area.x as f32 + area.width as f32 * self.x / 100.0,
+                area.y as f32 + area.height as f32 * self.y / 100.0,
+            ),
+            _ => return None,
+        };
+        Some(ClickPoint {
+            x: x.round() as i32,
+            y: y.round() as i32,
+        })
+    }
+}
+
+impl std::fmt::Display for ClickTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.anchor {
+            PointAnchor::Screen => write!(f, "({:.0}, {:.0})", self.x, self.y),
+            PointAnchor::Window => {
+                let point = format!("({:.0}, {:.0})", self.x, self.y);
+                write!(f, "{}", t!("click_target.window", point = point))
+            }
+            PointAnchor::WindowPercent => {
+                let point = format!("({:.1}%, {:.1}%)", self.x, self.y);
+                write!(f, "{}", t!("click_target.window", point = point))
+            }
+            PointAnchor::Monitor => {
+                let point = format!("({:.0}, {:.0})", self.x, self.y);
+                let name = self.monitor.as_deref().unwrap_or_default();
+                write!(
+                    f,
+                    "{}",
+                    t!("click_target.monitor", name = name, point = point)
+                )
+            }
+        }
+    }
+}
+
+/// 条件的作用方式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ConditionMode {
+    /// 条件满足时才点击，不满足时跳过
+    #[default]
+    ClickWhileMatch,
+    /// 条件不再满足时停止
+    StopOnMismatch,
+    /// 等到条件满足后才开始点击
+    WaitForMatch,
+}
+
+impl std::fmt::Display for ConditionMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let text = match self {
+            Self::ClickWhileMatch => t!("condition_mode.click_while_match"),
+            Self::StopOnMismatch => t!("condition_mode.stop_on_mismatch"),
+            Self::WaitForMatch => t!("condition_mode.wait_for_match"),
+        };
+        write!(f, "{}", text)
+    }
+}
+
+impl ConditionMode {
+    /// 获取所有作用方式
+    pub fn all() -> Vec<ConditionMode> {
+        vec![
+            Self::ClickWhileMatch,
+            Self::StopOnMismatch,
+            Self::WaitForMatch,
+        ]
+    }
+}
+
+/// 目标窗口的匹配方式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WindowMatchKind {
+    /// 标题匹配正则表达式
+    #[default]
+    Title,
+    /// 窗口类名（WM_CLASS）相同
+    Class,
+    /// 所属进程名相同
+    Process,
+}
+
+impl std::fmt::Display for WindowMatchKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let text = match self {
+            Self::Title => t!("window_match.title"),
+            Self::Class => t!("window_match.class"),
+            Self::Process => t!("window_match.process"),
+        };
+        write!(f, "{}", text)
+    }
+}
+
+impl WindowMatchKind {
+    /// 获取所有匹配方式
+    pub fn all() -> Vec<WindowMatchKind> {
+        vec![Self::Title, Self::Class, Self::Process]
+    }
+}
+
+/// 目标窗口设置
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TargetWindowSettings {
+    /// 是否启用，启用后只在目标窗口处于前台时点击
+    pub enabled: bool,
+    /// 匹配方式
+    pub kind: WindowMatchKind,
+    /// 标题正则表达式、类名或进程名
+    pub pattern: String,
+}
+
+/// 点击的送达方式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ClickDelivery {
+    /// 移动真实指针后注入输入
+    #[default]
+    Cursor,
+    /// 直接向目标窗口发送合成事件，不移动指针
+    Window,
+}
+
+impl std::fmt::Display for ClickDelivery {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let text = match self {
+            Self::Cursor => t!("click_delivery.cursor"),
+            Self::Window => t!("click_delivery.window"),
+        };
+        write!(f, "{}", text)
+    }
+}
+
+impl ClickDelivery {
+    /// 获取所有送达方式
+    pub fn all() -> Vec<ClickDelivery> {
+        vec![Self::Cursor, Self::Window]
+    }
+}
+
+/// 脚本设置
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScriptSettings {
+    /// 是否启用，启用后按脚本执行，忽略固定间隔、点击位置和条件设置
+    pub enabled: bool,
+    /// 脚本文件路径（为空时使用内嵌脚本）
+    #[serde(default)]
+    pub path: PathBuf,
+    /// 内嵌脚本的源码
+    #[serde(default)]
+    pub source: String,
+}
+
+/// 屏幕矩形区域
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScreenRegion {
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+}
+
+/// 图像模板匹配设置
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TemplateSettings {
+    /// 是否启用，启用后在找到的模板位置点击，忽略固定点击位置
+    pub enabled: bool,
+    /// 参考图像（PNG）路径
+    pub path: PathBuf,
+    /// 最低相似度（0-1）
+    pub threshold: f32,
+    /// 搜索区域（None表示整个屏幕）
+    pub region: Option<ScreenRegion>,
+    /// 点击位置相对模板中心的偏移
+    pub offset: ClickPoint,
+    /// 找不到模板时持续重试的时长（毫秒）
+    pub timeout_ms: u64,
+}
+
+impl Default for TemplateSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            path: PathBuf::new(),
+            threshold: 0.9,
+            region: None,
+            offset: ClickPoint { x: 0, y: 0 },
+            timeout_ms: 5000,
+        }
+    }
+}
+
+/// 像素条件的最大采样半径
+pub const MAX_SAMPLE_RADIUS: u32 = 10;
+
+/// 像素颜色条件
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PixelCondition {
+    /// 是否启用
+    pub enabled: bool,
+    /// 采样位置
+    pub point: ClickPoint,
+    /// 采样半径（像素），取 (2r+1)×(2r+1) 区域的平均颜色
+    pub radius: u32,
+    /// 目标颜色（RGB）
+    pub color: [u8; 3],
+    /// 每个颜色分量允许的最大差值
+    pub tolerance: u8,
+    /// 作用方式
+    pub mode: ConditionMode,
+}
+
+impl Default for PixelCondition {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            point: ClickPoint { x: 0, y: 0 },
+            radius: 0,
+            color: [0, 0, 0],
+            tolerance: 16,
+            mode: ConditionMode::default(),
+        }
+    }
+}
+
+/// 紧急停止设置
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FailSafeSettings {
+    /// 是否启用紧急停止
+    pub enabled: bool,
+    /// 指针进入该角落时停止（None表示不检查）
+    pub corner: Option<ScreenCorner>,
+    /// 角落判定范围（像素）
+    pub corner_margin: u32,
+    /// 手动移动指针超过该距离（像素）时停止（None表示不检查）
+    pub max_drift: Option<u32>,
+}
+
+impl Default for FailSafeSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            corner: Some(ScreenCorner::TopLeft),
+            corner_margin: 5,
+            max_drift: None,
+        }
+    }
+}
+
+/// 连发设置
+///
+/// 以较短的组内间隔连续点击若干次，然后休息较长时间，如此重复
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BurstSettings {
+    /// 是否启用，启用后点击间隔设置不再生效
+    pub enabled: bool,
+    /// 每组点击次数
+    pub clicks_per_burst: u32,
+    /// 组内点击间隔（毫秒）
+    pub inner_interval_ms: u64,
+    /// 两组之间的休息时长（毫秒）
+    pub rest_ms: u64,
+    /// 组数（None表示无限组）
+    pub bursts: Option<u32>,
+}
+
+impl Default for BurstSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            clicks_per_burst: 5,
+            inner_interval_ms: 50,
+            rest_ms: 2000,
+            bursts: None,
+        }
+    }
+}
+
+/// 默认配置名称，使用当前语言
+fn default_profile() -> String {
+    t!("profile.default").to_string()
+}
+
 /// 应用程序配置
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AppSettings {
+    /// 配置名称
+    #[serde(default = "default_profile")]
+    pub profile: String,
     /// 点击间隔时间（毫秒）
     pub interval_ms: u64,
     ///鼠标按键类型
@@ -103,39 +616,199 @@ pub struct AppSettings {
     pub click_count: Option<u32>,
     /// 热键设置
     pub hotkey: FunctionKey,
+    /// 点击位置（None表示在指针当前位置点击）
+    ///
+    /// 后台点击时None表示窗口中心
+    #[serde(default)]
+    pub click_target: Option<ClickTarget>,
+    /// 点击的送达方式
+    #[serde(default)]
+    pub click_delivery: ClickDelivery,
+    /// 鼠标输入后端
+    #[serde(default)]
+    pub input_backend: InputBackend,
+    /// XTest后端使用的X11显示（None表示使用DISPLAY环境变量）
+    #[serde(default)]
+    pub x11_display: Option<String>,
+    /// 紧急停止设置
+    #[serde(default)]
+    pub failsafe: FailSafeSettings,
+    /// 连发设置
+    #[serde(default)]
+    pub burst: BurstSettings,
+    /// 像素颜色条件
+    #[serde(default)]
+    pub pixel_condition: PixelCondition,
+    /// 图像模板匹配
+    #[serde(default)]
+    pub template: TemplateSettings,
+    /// 目标窗口
+    #[serde(default)]
+    pub target_window: TargetWindowSettings,
+    /// 脚本
+    #[serde(default)]
+    pub script: ScriptSettings,
+    /// 操作序列
+    #[serde(default)]
+    pub sequence: SequenceSettings,
+    /// 是否在点击位置显示指示波纹
+    #[serde(default)]
+    pub click_indicator: bool,
+    /// 界面语言（None表示跟随系统）
+    #[serde(default)]
+    pub language: Option<Locale>,
 }
 
 impl Default for AppSettings {
     fn default() -> Self {
         Self {
+            profile: default_profile(),
             interval_ms: 1000,
             mouse_button: MouseButton::default(),
             click_count: None,
             hotkey: FunctionKey::default(),
+            click_target: None,
+            click_delivery: ClickDelivery::default(),
+            input_backend: InputBackend::default(),
+            x11_display: None,
+            failsafe: FailSafeSettings::default(),
+            burst: BurstSettings::default(),
+            pixel_condition: PixelCondition::default(),
+            template: TemplateSettings::default(),
+            target_window: TargetWindowSettings::default(),
+            script: ScriptSettings::default(),
+            sequence: SequenceSettings::default(),
+            click_indicator: false,
+            language: None,
         }
     }
 }
 
 impl AppSettings {
+    /// 是否启用了操作序列不支持的设置
+    ///
+    /// 序列的每一步自行指定位置，颜色条件、图像匹配和后台点击不适用
+    pub fn has_sequence_conflict(&self) -> bool {
+        self.pixel_condition.enabled
+            || self.template.enabled
+            || self.click_delivery == ClickDelivery::Window
+    }
+
     /// 验证设置的有效性
     pub fn validate(&self) -> Result<()> {
+        if self.profile.trim().is_empty() {
+            return Err(t!("settings.error.empty_profile").to_string());
+        }
+
         if self.interval_ms == 0 {
-            return Err("点击间隔不能为0".to_string());
+            return Err(t!("settings.error.zero_interval").to_string());
         }
 
         if self.interval_ms > 60000 {
-            return Err("点击间隔不能超过60秒".to_string());
+            return Err(t!("settings.error.interval_too_long").to_string());
         }
 
         if let Some(count) = self.click_count {
             if count == 0 {
-                return Err("点击次数不能为0".to_string());
+                return Err(t!("settings.error.zero_count").to_string());
             }
             if count > 1000000 {
-                return Err("点击次数不能超过100万次".to_string());
+                return Err(t!("settings.error.count_too_large").to_string());
+            }
+        }
+
+        if self.burst.enabled {
+            let burst = &self.burst;
+            if burst.clicks_per_burst == 0 || burst.clicks_per_burst > 10000 {
+                return Err(t!("settings.error.burst_clicks").to_string());
+            }
+            if burst.inner_interval_ms == 0 || burst.inner_interval_ms > 60000 {
+                return Err(t!("settings.error.burst_interval").to_string());
+            }
+            if burst.rest_ms > 3_600_000 {
+                return Err(t!("settings.error.burst_rest").to_string());
+            }
+            if burst.bursts == Some(0) {
+                return Err(t!("settings.error.zero_bursts").to_string());
             }
         }
 
+        if self.failsafe.corner_margin == 0 || self.failsafe.corner_margin > 200 {
+            return Err(t!("settings.error.corner_margin").to_string());
+        }
+
+        if self.failsafe.max_drift == Some(0) {
+            return Err(t!("settings.error.zero_drift").to_string());
+        }
+
+        if !(self.template.threshold > 0.0 && self.template.threshold <= 1.0) {
+            return Err(t!("settings.error.template_threshold").to_string());
+        }
+
+        if self.template.timeout_ms > 60000 {
+            return Err(t!("settings.error.template_timeout").to_string());
+        }
+
+        if self
+            .template
+            .region
+            .is_some_and(|region| region.width == 0 || region.height == 0)
+        {
+            return Err(t!("settings.error.empty_region").to_string());
+        }
+
+        if let Some(target) = &self.click_target {
+            if target.anchor == PointAnchor::Monitor && target.monitor.is_none() {
+                return Err(t!("settings.error.click_monitor").to_string());
+            }
+            if target.anchor == PointAnchor::WindowPercent
+                && !((0.0..=100.0).contains(&target.x) && (0.0..=100.0).contains(&target.y))
+            {
+                return Err(t!("settings.error.click_percent").to_string());
+            }
+        }
+
+        // 后台点击和相对窗口的点击位置同样用目标窗口的规则查找窗口
+        let target_window = &self.target_window;
+        if target_window.enabled
+            || self.click_delivery == ClickDelivery::Window
+            || self
+                .click_target
+                .as_ref()
+                .is_some_and(|target| target.needs_window())
+        {
+            if target_window.pattern.trim().is_empty() {
+                return Err(t!("settings.error.empty_window_pattern").to_string());
+            }
+            if target_window.kind == WindowMatchKind::Title {
+                regex::Regex::new(&target_window.pattern)
+                    .map_err(|e| t!("settings.error.window_regex", error = e))?;
+            }
+        }
+
+        if self.script.enabled
+            && self.script.path.as_os_str().is_empty()
+            && self.script.source.trim().is_empty()
+        {
+            return Err(t!("settings.error.empty_script").to_string());
+        }
+
+        if self.sequence.enabled {
+            if self.script.enabled {
+                return Err(t!("settings.error.script_and_sequence").to_string());
+            }
+            if self.sequence.steps.is_empty() {
+                return Err(t!("settings.error.empty_sequence").to_string());
+            }
+            if self.has_sequence_conflict() {
+                return Err(t!("settings.error.sequence_conflict").to_string());
+            }
+        }
+
+        if self.pixel_condition.radius > MAX_SAMPLE_RADIUS {
+            return Err(t!("settings.error.sample_radius", max = MAX_SAMPLE_RADIUS));
+        }
+
         Ok(())
     }
 }
@@ -158,14 +831,21 @@ impl SettingsManager {
         })
     }
 
-    /// 获取配置文件路径
-    fn get_config_path() -> Result<PathBuf> {
-        let config_dir = dirs::config_dir().ok_or_else(|| "无法获取配置目录".to_string())?;
+    /// 获取应用配置目录，不存在时自动创建
+    pub fn config_dir() -> Result<PathBuf> {
+        let config_dir =
+            dirs::config_dir().ok_or_else(|| t!("settings.error.no_config_dir").to_string())?;
 
         let app_config_dir = config_dir.join("mouse-clicker");
-        std::fs::create_dir_all(&app_config_dir).map_err(|e| format!("创建配置目录失败: {}", e))?;
+        std::fs::create_dir_all(&app_config_dir)
+            .map_err(|e| t!("settings.error.create_dir", error = e))?;
 
-        Ok(app_config_dir.join("settings.json"))
+        Ok(app_config_dir)
+    }
+
+    /// 获取配置文件路径
+    fn get_config_path() -> Result<PathBuf> {
+        Ok(Self::config_dir()?.join("settings.json"))
     }
 
     /// 从文件加载设置
@@ -175,16 +855,15 @@ impl SettingsManager {
         }
 
         let content =
-            std::fs::read_to_string(path).map_err(|e| format!("读取配置文件失败: {}", e))?;
+            std::fs::read_to_string(path).map_err(|e| t!("settings.error.read", error = e))?;
         let settings: AppSettings =
-            serde_json::from_str(&content).map_err(|e| format!("解析配置文件失败: {}", e))?;
+            serde_json::from_str(&content).map_err(|e| t!("settings.error.parse", error = e))?;
         settings.validate()?;
 
         Ok(settings)
     }
 
     /// 获取当前设置
-    #[allow(dead_code)]
     pub fn get(&self) -> &AppSettings {
         &self.settings
     }
@@ -199,9 +878,9 @@ impl SettingsManager {
     /// 保存设置到文件
     pub fn save(&self) -> Result<()> {
         let content = serde_json::to_string_pretty(&self.settings)
-            .map_err(|e| format!("序列化设置失败: {}", e))?;
+            .map_err(|e| t!("settings.error.serialize", error = e))?;
         std::fs::write(&self.config_path, content)
-            .map_err(|e| format!("写入配置文件失败: {}", e))?;
+            .map_err(|e| t!("settings.error.write", error = e))?;
         log::info!("设置已保存到: {:?}", self.config_path);
         Ok(())
     }
@@ -215,3 +894,137 @@ impl Default for SettingsManager {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::monitor::{Monitor, MonitorLayout};
+
+    /// 左侧为1倍缩放的1080p主显示器，右侧为2倍缩放的4K显示器，顶边对齐
+    fn layout() -> MonitorLayout {
+        MonitorLayout::from_monitors(vec![
+            Monitor {
+                name: "DP-1".to_string(),
+                bounds: ScreenRegion {
+                    x: 0,
+                    y: 0,
+                    width: 1920,
+                    height: 1080,
+                },
+                scale: 1.0,
+                primary: true,
+            },
+            Monitor {
+                name: "DP-2".to_string(),
+                bounds: ScreenRegion {
+                    x: 1920,
+                    y: 0,
+                    width: 3840,
+                    height: 2160,
+                },
+                scale: 2.0,
+                primary: false,
+            },
+        ])
+    }
+
+    /// 跨在两个显示器之间的窗口客户区
+    const WINDOW: ScreenRegion = ScreenRegion {
+        x: 1500,
+        y: 200,
+        width: 800,
+        height: 600,
+    };
+
+    #[test]
+    fn every_anchor_round_trips_through_screen_coordinates() {
+        let layout = layout();
+        let points = [
+            ClickPoint { x: 1500, y: 200 },
+            ClickPoint { x: 1837, y: 421 },
+            ClickPoint { x: 2299, y: 799 },
+        ];
+
+        for anchor in PointAnchor::all() {
+            for point in points {
+                let target = layout
+                    .target_from_screen(anchor, point, Some(WINDOW))
+                    .unwrap_or_else(|| panic!("{:?} 无法保存 {}", anchor, point));
+                assert_eq!(target.anchor, anchor);
+                assert_eq!(
+                    layout.resolve(&target, Some(WINDOW)),
+                    Some(point),
+                    "{:?} 保存为 {:?}",
+                    anchor,
+                    target
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn click_target_converts_without_a_layout() {
+        let point = ClickPoint { x: 1700, y: 350 };
+
+        let window = ClickTarget::from_screen(PointAnchor::Window, point, Some(WINDOW)).unwrap();
+        assert_eq!((window.x, window.y), (200.0, 150.0));
+        assert_eq!(window.resolve(Some(WINDOW)), Some(point));
+
+        let percent =
+            ClickTarget::from_screen(PointAnchor::WindowPercent, point, Some(WINDOW)).unwrap();
+        assert_eq!((percent.x, percent.y), (25.0, 25.0));
+        assert_eq!(percent.resolve(Some(WINDOW)), Some(point));
+
+        // 屏幕坐标不需要窗口
+        let screen = ClickTarget::from_screen(PointAnchor::Screen, point, None).unwrap();
+        assert_eq!(screen.resolve(None), Some(point));
+    }
+
+    #[test]
+    fn window_anchors_need_a_window() {
+        let point = ClickPoint { x: 1700, y: 350 };
+        for anchor in [PointAnchor::Window, PointAnchor::WindowPercent] {
+            assert_eq!(ClickTarget::from_screen(anchor, point, None), None);
+
+            let target = ClickTarget::from_screen(anchor, point, Some(WINDOW)).unwrap();
+            assert_eq!(target.resolve(None), None);
+            assert_eq!(layout().resolve(&target, None), None);
+        }
+
+        // 最小化的窗口客户区为空，无法换算百分比
+        let empty = ScreenRegion {
+            width: 0,
+            height: 0,
+            ..WINDOW
+        };
+        assert_eq!(
+            ClickTarget::from_screen(PointAnchor::WindowPercent, point, Some(empty)),
+            None
+        );
+    }
+
+    #[test]
+    fn monitor_anchor_needs_a_connected_monitor() {
+        let layout = layout();
+
+        // 不在任何显示器上的坐标无法保存
+        let off_screen = ClickPoint { x: 100, y: 1500 };
+        assert_eq!(
+            layout.target_from_screen(PointAnchor::Monitor, off_screen, None),
+            None
+        );
+
+        let mut target = layout
+            .target_from_screen(PointAnchor::Monitor, ClickPoint { x: 2020, y: 100 }, None)
+            .unwrap();
+        assert_eq!(target.monitor.as_deref(), Some("DP-2"));
+        assert_eq!((target.x, target.y), (50.0, 50.0));
+
+        // 显示器断开或名称缺失时无法换算；不经布局换算也不行
+        assert_eq!(ClickTarget::resolve(&target, None), None);
+        target.monitor = Some("HDMI-1".to_string());
+        assert_eq!(layout.resolve(&target, None), None);
+        target.monitor = None;
+        assert_eq!(layout.resolve(&target, None), None);
+    }
+}
@@ -0,0 +1,336 @@
+//! 操作执行模块
+//!
+//! 脚本和操作序列通过同一组操作控制鼠标和键盘，由连点线程实现
+
+use crate::config::{Action, ClickPoint, MouseButton};
+use crate::core::clicker::ClickerStatus;
+use crate::core::keyboard::parse_key;
+use crate::utils::Result;
+use enigo::Direction;
+use std::time::Duration;
+
+/// 可执行的操作
+pub trait ActionHost {
+    /// 点击，`button` 为None时使用设置中的按键，`point` 为None时在指针当前位置点击
+    fn click(&mut self, button: Option<MouseButton>, point: Option<ClickPoint>) -> Result<()>;
+
+    /// 将指针移动到屏幕坐标
+    fn move_to(&mut self, point: ClickPoint) -> Result<()>;
+
+    /// 垂直滚动，正数向下，负数向上
+    fn scroll(&mut self, length: i32) -> Result<()>;
+
+    /// 按下、释放或敲击键盘按键
+    fn key(&mut self, key: enigo::Key, direction: Direction) -> Result<()>;
+
+    /// 输入文本
+    fn type_text(&mut self, text: &str) -> Result<()>;
+
+    /// 按住左键从 `from` 拖动到 `to`
+    fn drag(&mut self, from: ClickPoint, to: ClickPoint) -> Result<()>;
+
+    /// 等待指定时长，连点器在等待期间停止时返回错误
+    fn sleep(&mut self, duration: Duration) -> Result<()>;
+
+    /// 读取屏幕坐标处的像素颜色
+    fn pixel(&mut self, point: ClickPoint) -> Result<[u8; 3]>;
+
+    /// 指针当前的屏幕坐标
+    fn cursor(&mut self) -> Result<ClickPoint>;
+
+    /// 连点器当前状态
+    fn status(&self) -> ClickerStatus;
+
+    /// 连点器是否仍在运行
+    fn is_running(&self) -> bool;
+
+    /// 输出脚本中 `print` 的文本
+    fn log(&mut self, message: String);
+}
+
+/// 执行序列中的一步操作
+pub fn perform(host: &mut impl ActionHost, action: &Action) -> Result<()> {
+    match action {
+        Action::Click { button, point } => host.click(Some(*button), *point),
+        Action::Move { point } => host.move_to(*point),
+        Action::Wait { ms } => host.sleep(Duration::from_millis(*ms)),
+        Action::Scroll { amount } => host.scroll(*amount),
+        Action::KeyTap { key } => host.key(parse_key(key)?, Direction::Click),
+        Action::KeyPress { key } => host.key(parse_key(key)?, Direction::Press),
+        Action::KeyRelease { key } => host.key(parse_key(key)?, Direction::Release),
+        Action::TypeText { text } => host.type_text(text),
+        Action::Drag { from, to } => host.drag(*from, *to),
+        // 循环由 run_steps 处理
+        Action::Loop { .. } | Action::EndLoop => Ok(()),
+    }
+}
+
+/// 执行一遍序列，`on_step` 在每步开始前以步骤序号调用
+///
+/// 序列需先通过 [`check`]，连点器停止时在下一步开始前返回
+pub fn run_steps(
+    host: &mut impl ActionHost,
+    steps: &[Action],
+    mut on_step: impl FnMut(usize),
+) -> Result<()> {
+    // 每层循环的开始位置和剩余次数
+    let mut loops: Vec<(usize, u32)> = Vec::new();
+    let mut index = 0;
+    while index < steps.len() {
+        if !host.is_running() {
+            return Ok(());
+        }
+        on_step(index);
+        match &steps[index] {
+            Action::Loop { count } => loops.push((index, *count)),
+            Action::EndLoop => {
+                if let Some((start, remaining)) = loops.last_mut() {
+                    *remaining = remaining.saturating_sub(1);
+                    if *remaining > 0 {
+                        index = *start + 1;
+                        continue;
+                    }
+                    loops.pop();
+                }
+            }
+            action => perform(host, action)?,
+        }
+        index += 1;
+    }
+    Ok(())
+}
+
+/// 逐步校验序列，返回每一步的错误，None表示该步有效
+pub fn validate(steps: &[Action]) -> Vec<Option<String>> {
+    let mut errors: Vec<Option<String>> = steps.iter().map(step_error).collect();
+
+    // 循环开始和结束必须成对出现
+    let mut open = Vec::new();
+    for (index, action) in steps.iter().enumerate() {
+        match action {
+            Action::Loop { .. } => open.push(index),
+            Action::EndLoop if open.pop().is_none() => {
+                errors[index] = Some(t!("sequence.error.unmatched_end").to_string());
+            }
+            _ => {}
+        }
+    }
+    for index in open {
+        errors[index] = Some(t!("sequence.error.unclosed_loop").to_string());
+    }
+    errors
+}
+
+/// 检查序列，在启动前发现无效的步骤
+pub fn check(steps: &[Action]) -> Result<()> {
+    let invalid = validate(steps)
+        .into_iter()
+        .enumerate()
+        .find_map(|(index, error)| Some((index, error?)));
+    match invalid {
+        Some((index, error)) => Err(t!("sequence.error.step", step = index + 1, error = error)),
+        None => Ok(()),
+    }
+}
+
+/// 单步的参数错误
+fn step_error(action: &Action) -> Option<String> {
+    match action {
+        Action::KeyTap { key } | Action::KeyPress { key } | Action::KeyRelease { key } => {
+            parse_key(key).err()
+        }
+        Action::TypeText { text } if text.is_empty() => {
+            Some(t!("sequence.error.empty_text").to_string())
+        }
+        Action::Loop { count: 0 } => Some(t!("sequence.error.zero_loop").to_string()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// 记录收到的操作，执行指定步数后停止
+    #[derive(Default)]
+    pub(crate) struct RecordingHost {
+        pub(crate) performed: Vec<String>,
+        /// 执行这么多次操作后停止，None表示不停止
+        pub(crate) stop_after: Option<usize>,
+    }
+
+    impl RecordingHost {
+        fn record(&mut self, action: String) -> Result<()> {
+            self.performed.push(action);
+            Ok(())
+        }
+    }
+
+    impl ActionHost for RecordingHost {
+        fn click(&mut self, button: Option<MouseButton>, _point: Option<ClickPoint>) -> Result<()> {
+            self.record(format!("click {:?}", button))
+        }
+
+        fn move_to(&mut self, point: ClickPoint) -> Result<()> {
+            self.record(format!("move {}", point))
+        }
+
+        fn scroll(&mut self, length: i32) -> Result<()> {
+            self.record(format!("scroll {}", length))
+        }
+
+        fn key(&mut self, key: enigo::Key, direction: Direction) -> Result<()> {
+            self.record(format!("key {:?} {:?}", key, direction))
+        }
+
+        fn type_text(&mut self, text: &str) -> Result<()> {
+            self.record(format!("text {}", text))
+        }
+
+        fn drag(&mut self, from: ClickPoint, to: ClickPoint) -> Result<()> {
+            self.record(format!("drag {} {}", from, to))
+        }
+
+        fn sleep(&mut self, duration: Duration) -> Result<()> {
+            self.record(format!("sleep {}", duration.as_millis()))
+        }
+
+        fn pixel(&mut self, _point: ClickPoint) -> Result<[u8; 3]> {
+            Ok([0, 0, 0])
+        }
+
+        fn cursor(&mut self) -> Result<ClickPoint> {
+            Ok(ClickPoint { x: 0, y: 0 })
+        }
+
+        fn status(&self) -> ClickerStatus {
+            ClickerStatus::default()
+        }
+
+        fn is_running(&self) -> bool {
+            self.stop_after
+                .is_none_or(|limit| self.performed.len() < limit)
+        }
+
+        fn log(&mut self, _message: String) {}
+    }
+
+    fn scroll(amount: i32) -> Action {
+        Action::Scroll { amount }
+    }
+
+    #[test]
+    fn validate_accepts_nested_loops() {
+        let steps = [
+            Action::Loop { count: 2 },
+            scroll(1),
+            Action::Loop { count: 3 },
+            scroll(2),
+            Action::EndLoop,
+            Action::EndLoop,
+        ];
+        assert!(validate(&steps).iter().all(Option::is_none));
+        assert!(check(&steps).is_ok());
+    }
+
+    #[test]
+    fn validate_reports_unmatched_loops() {
+        let steps = [
+            Action::EndLoop,
+            Action::Loop { count: 2 },
+            scroll(1),
+            Action::Loop { count: 2 },
+            Action::EndLoop,
+        ];
+        let errors = validate(&steps);
+        assert_eq!(
+            errors[0].as_deref(),
+            Some(t!("sequence.error.unmatched_end"))
+        );
+        assert_eq!(
+            errors[1].as_deref(),
+            Some(t!("sequence.error.unclosed_loop"))
+        );
+        assert!(errors[2..].iter().all(Option::is_none));
+        assert!(check(&steps).is_err());
+    }
+
+    #[test]
+    fn validate_reports_invalid_steps() {
+        let steps = [
+            Action::Loop { count: 0 },
+            Action::EndLoop,
+            Action::TypeText {
+                text: String::new(),
+            },
+            Action::KeyTap {
+                key: "no-such-key".to_string(),
+            },
+            Action::KeyTap {
+                key: "Enter".to_string(),
+            },
+        ];
+        let errors = validate(&steps);
+        assert_eq!(errors[0].as_deref(), Some(t!("sequence.error.zero_loop")));
+        assert!(errors[1].is_none());
+        assert_eq!(errors[2].as_deref(), Some(t!("sequence.error.empty_text")));
+        assert!(errors[3].is_some());
+        assert!(errors[4].is_none());
+    }
+
+    #[test]
+    fn run_steps_repeats_nested_loops() {
+        let steps = [
+            Action::Loop { count: 2 },
+            scroll(1),
+            Action::Loop { count: 3 },
+            scroll(2),
+            Action::EndLoop,
+            Action::EndLoop,
+            scroll(3),
+        ];
+        let mut host = RecordingHost::default();
+        let mut started = Vec::new();
+        run_steps(&mut host, &steps, |index| started.push(index)).unwrap();
+
+        let expected: Vec<String> = [1, 2, 2, 2, 1, 2, 2, 2, 3]
+            .iter()
+            .map(|amount| format!("scroll {}", amount))
+            .collect();
+        assert_eq!(host.performed, expected);
+        assert_eq!(
+            started,
+            [0, 1, 2, 3, 4, 3, 4, 3, 4, 5, 1, 2, 3, 4, 3, 4, 3, 4, 5, 6]
+        );
+    }
+
+    #[test]
+    fn run_steps_stops_before_next_step() {
+        let steps = [
+            Action::Loop { count: 100 },
+            scroll(1),
+            Action::Wait { ms: 5 },
+            Action::EndLoop,
+        ];
+        let mut host = RecordingHost {
+            stop_after: Some(3),
+            ..RecordingHost::default()
+        };
+        run_steps(&mut host, &steps, |_| {}).unwrap();
+        assert_eq!(host.performed, ["scroll 1", "sleep 5", "scroll 1"]);
+    }
+
+    #[test]
+    fn run_steps_propagates_step_errors() {
+        let steps = [
+            scroll(1),
+            Action::KeyTap {
+                key: "no-such-key".to_string(),
+            },
+            scroll(2),
+        ];
+        let mut host = RecordingHost::default();
+        assert!(run_steps(&mut host, &steps, |_| {}).is_err());
+        assert_eq!(host.performed, ["scroll 1"]);
+    }
+}
@@ -0,0 +1,157 @@
+//! 模拟运行后端
+//!
+//! 不注入任何输入，只把本应执行的操作连同发生时间广播为事件。
+//! 指针位置由模拟的移动决定，移动之前读取位置会失败
+
+use super::{InputError, MouseBackend};
+use crate::config::MouseButton;
+use crate::core::clock::Clock;
+use crate::core::events::{ClickerEvent, EventBus};
+use crate::core::window::WindowId;
+use enigo::{Button, Direction, Key};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// 模拟执行的一次输入
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SimulatedInput {
+    /// 按下、释放或单击鼠标按键
+    Button {
+        button: Button,
+        direction: Direction,
+    },
+    /// 垂直滚动
+    Scroll(i32),
+    /// 按下、释放或敲击键盘按键
+    Key { key: Key, direction: Direction },
+    /// 输入文本
+    Text(String),
+    /// 移动指针
+    Move { x: i32, y: i32 },
+    /// 向窗口发送点击，`position` 为窗口内坐标
+    WindowClick {
+        window: WindowId,
+        position: (i32, i32),
+        button: MouseButton,
+    },
+}
+
+impl std::fmt::Display for SimulatedInput {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let text = match self {
+            Self::Button { button, direction } => t!(
+                "dry_run.input.button",
+                button = format!("{:?}", button),
+                direction = format!("{:?}", direction)
+            ),
+            Self::Scroll(length) => t!("dry_run.input.scroll", length = length),
+            Self::Key { key, direction } => t!(
+                "dry_run.input.key",
+                key = format!("{:?}", key),
+                direction = format!("{:?}", direction)
+            ),
+            Self::Text(text) => t!("dry_run.input.text", text = text),
+            Self::Move { x, y } => t!("dry_run.input.move", x = x, y = y),
+            Self::WindowClick {
+                window,
+                position: (x, y),
+                button,
+            } => t!(
+                "dry_run.input.window_click",
+                window = format!("0x{:x}", window),
+                x = x,
+                y = y,
+                button = button
+            ),
+        };
+        write!(f, "{}", text)
+    }
+}
+
+/// 模拟输入记录器，记录的时间按 `clock` 从创建时开始计算
+#[derive(Clone)]
+pub struct DryRunRecorder {
+    events: EventBus,
+    clock: Arc<dyn Clock>,
+    start: Instant,
+}
+
+impl DryRunRecorder {
+    /// 创建记录器
+    pub fn new(events: EventBus, clock: Arc<dyn Clock>) -> Self {
+        let start = clock.now();
+        Self {
+            events,
+            clock,
+            start,
+        }
+    }
+
+    /// 记录一次输入
+    pub fn record(&self, input: SimulatedInput) {
+        self.events.emit(ClickerEvent::Simulated {
+            elapsed: self.clock.now().duration_since(self.start),
+            input,
+        });
+    }
+}
+
+/// 模拟运行后端
+pub struct DryRunBackend {
+    recorder: DryRunRecorder,
+    /// 最近一次模拟移动到的位置
+    position: Option<(i32, i32)>,
+}
+
+impl DryRunBackend {
+    /// 创建模拟运行后端
+    pub fn new(recorder: DryRunRecorder) -> Self {
+        Self {
+            recorder,
+            position: None,
+        }
+    }
+}
+
+impl MouseBackend for DryRunBackend {
+    fn name(&self) -> &'static str {
+        "dry-run"
+    }
+
+    fn button(&mut self, button: Button, direction: Direction) -> Result<(), InputError> {
+        self.recorder
+            .record(SimulatedInput::Button { button, direction });
+        Ok(())
+    }
+
+    fn scroll(&mut self, length: i32) -> Result<(), InputError> {
+        self.recorder.record(SimulatedInput::Scroll(length));
+        Ok(())
+    }
+
+    fn key(&mut self, key: Key, direction: Direction) -> Result<(), InputError> {
+        self.recorder.record(SimulatedInput::Key { key, direction });
+        Ok(())
+    }
+
+    fn text(&mut self, text: &str) -> Result<(), InputError> {
+        self.recorder.record(SimulatedInput::Text(text.to_string()));
+        Ok(())
+    }
+
+    fn move_to(&mut self, x: i32, y: i32) -> Result<(), InputError> {
+        self.position = Some((x, y));
+        self.recorder.record(SimulatedInput::Move { x, y });
+        Ok(())
+    }
+
+    fn location(&self) -> Result<(i32, i32), String> {
+        self.position
+            .ok_or_else(|| t!("backend.error.location_unsupported", backend = self.name()))
+    }
+}
+
+/// 把模拟时间格式化为 `秒.毫秒`
+pub fn format_elapsed(elapsed: Duration) -> String {
+    format!("{}.{:03}s", elapsed.as_secs(), elapsed.subsec_millis())
+}
@@ -0,0 +1,69 @@
+//! enigo输入后端
+//!
+//! Windows、macOS和X11下的默认实现
+
+use super::{InputError, MouseBackend};
+use enigo::{Axis, Button, Coordinate, Direction, Enigo, Key, Keyboard, Mouse, Settings};
+
+/// 基于enigo的输入后端
+pub struct EnigoBackend {
+    enigo: Enigo,
+}
+
+impl EnigoBackend {
+    /// 创建新的enigo后端
+    pub fn new() -> Result<Self, String> {
+        let enigo = Enigo::new(&Settings::default())
+            .map_err(|e| t!("backend.error.enigo_init", error = e))?;
+        Ok(Self { enigo })
+    }
+}
+
+/// 转换enigo的输入错误
+///
+/// Windows下SendInput只在输入被拦截时失败，通常是UIPI阻止了向更高权限窗口的输入，视为权限错误
+fn input_error(error: enigo::InputError) -> InputError {
+    match error {
+        #[cfg(target_os = "windows")]
+        enigo::InputError::Simulate(_) => InputError::Permission(error.to_string()),
+        _ => InputError::Failed(error.to_string()),
+    }
+}
+
+impl MouseBackend for EnigoBackend {
+    fn name(&self) -> &'static str {
+        "enigo"
+    }
+
+    fn button(&mut self, button: Button, direction: Direction) -> Result<(), InputError> {
+        self.enigo.button(button, direction).map_err(input_error)
+    }
+
+    fn scroll(&mut self, length: i32) -> Result<(), InputError> {
+        self.enigo
+            .scroll(length, Axis::Vertical)
+            .map_err(input_error)
+    }
+
+    fn key(&mut self, key: Key, direction: Direction) -> Result<(), InputError> {
+        self.enigo.key(key, direction).map_err(input_error)
+    }
+
+    fn text(&mut self, text: &str) -> Result<(), InputError> {
+        self.enigo.text(text).map_err(input_error)
+    }
+
+    fn move_to(&mut self, x: i32, y: i32) -> Result<(), InputError> {
+        self.enigo
+            .move_mouse(x, y, Coordinate::Abs)
+            .map_err(input_error)
+    }
+
+    fn location(&self) -> Result<(i32, i32), String> {
+        self.enigo.location().map_err(|e| e.to_string())
+    }
+
+    fn display_size(&self) -> Result<(i32, i32), String> {
+        self.enigo.main_display().map_err(|e| e.to_string())
+    }
+}
@@ -0,0 +1,261 @@
+//! 鼠标输入后端模块
+//!
+//! 定义输入注入后端的统一接口，并根据平台和会话类型选择具体实现
+
+pub mod dry_run;
+pub mod enigo;
+#[cfg(target_os = "linux")]
+pub mod send_event;
+#[cfg(target_os = "linux")]
+pub mod uinput;
+#[cfg(target_os = "linux")]
+pub mod xtest;
+
+use crate::config::{AppSettings, InputBackend};
+use std::sync::OnceLock;
+
+/// 输入操作的错误
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputError {
+    /// 系统以权限不足为由拦截了输入，获得授权后可以恢复
+    Permission(String),
+    /// 其他错误
+    Failed(String),
+}
+
+impl InputError {
+    /// 保留错误类别，用 `wrap` 为说明文字补充上下文
+    pub fn context(self, wrap: impl FnOnce(String) -> String) -> Self {
+        match self {
+            Self::Permission(message) => Self::Permission(wrap(message)),
+            Self::Failed(message) => Self::Failed(wrap(message)),
+        }
+    }
+}
+
+impl std::fmt::Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Permission(message) | Self::Failed(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl From<String> for InputError {
+    fn from(message: String) -> Self {
+        Self::Failed(message)
+    }
+}
+
+impl From<InputError> for String {
+    fn from(error: InputError) -> Self {
+        error.to_string()
+    }
+}
+
+/// 鼠标输入后端
+pub trait MouseBackend {
+    /// 后端名称，用于日志和诊断
+    fn name(&self) -> &'static str;
+
+    /// 按下、释放或单击鼠标按键
+    fn button(
+        &mut self,
+        button: ::enigo::Button,
+        direction: ::enigo::Direction,
+    ) -> Result<(), InputError>;
+
+    /// 垂直滚动，符号约定与enigo一致：正数向下，负数向上
+    fn scroll(&mut self, length: i32) -> Result<(), InputError>;
+
+    /// 按下、释放或敲击键盘按键
+    fn key(
+        &mut self,
+        _key: ::enigo::Key,
+        _direction: ::enigo::Direction,
+    ) -> Result<(), InputError> {
+        Err(t!("backend.error.key_unsupported", backend = self.name()).into())
+    }
+
+    /// 输入文本
+    fn text(&mut self, _text: &str) -> Result<(), InputError> {
+        Err(t!("backend.error.key_unsupported", backend = self.name()).into())
+    }
+
+    /// 将指针移动到屏幕绝对坐标
+    fn move_to(&mut self, _x: i32, _y: i32) -> Result<(), InputError> {
+        Err(t!("backend.error.move_unsupported", backend = self.name()).into())
+    }
+
+    /// 获取指针当前的屏幕坐标
+    fn location(&self) -> Result<(i32, i32), String> {
+        Err(t!(
+            "backend.error.location_unsupported",
+            backend = self.name()
+        ))
+    }
+
+    /// 获取主屏幕尺寸（宽, 高）
+    fn display_size(&self) -> Result<(i32, i32), String> {
+        Err(t!("backend.error.size_unsupported", backend = self.name()))
+    }
+}
+
+/// 当前图形会话类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SessionType {
+    X11,
+    Wayland,
+    Unknown,
+}
+
+impl SessionType {
+    /// 从 `XDG_SESSION_TYPE` 环境变量检测会话类型
+    pub fn detect() -> Self {
+        match std::env::var("XDG_SESSION_TYPE") {
+            Ok(value) => match value.to_ascii_lowercase().as_str() {
+                "x11" => Self::X11,
+                "wayland" => Self::Wayland,
+                _ => Self::Unknown,
+            },
+            Err(_) => Self::Unknown,
+        }
+    }
+}
+
+impl std::fmt::Display for SessionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let text = match self {
+            Self::X11 => "X11",
+            Self::Wayland => "Wayland",
+            Self::Unknown => t!("session.unknown"),
+        };
+        write!(f, "{}", text)
+    }
+}
+
+/// 后端选择参数
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BackendConfig {
+    /// 输入后端类型
+    pub kind: InputBackend,
+    /// XTest后端使用的X11显示
+    pub display: Option<String>,
+}
+
+/// 命令行指定的后端参数，优先于配置文件
+static CLI_OVERRIDE: OnceLock<(Option<InputBackend>, Option<String>)> = OnceLock::new();
+
+/// 记录命令行指定的后端参数，只在启动时调用一次
+pub fn set_cli_override(kind: Option<InputBackend>, display: Option<String>) {
+    let _ = CLI_OVERRIDE.set((kind, display));
+}
+
+impl BackendConfig {
+    /// 从应用设置构造，并应用命令行覆盖
+    pub fn from_settings(settings: &AppSettings) -> Self {
+        let mut config = Self {
+            kind: settings.input_backend,
+            display: settings.x11_display.clone(),
+        };
+        if let Some((kind, display)) = CLI_OVERRIDE.get() {
+            if let Some(kind) = kind {
+                config.kind = *kind;
+            }
+            if display.is_some() {
+                config.display = display.clone();
+            }
+        }
+        config
+    }
+}
+
+/// 将自动选择解析为具体后端
+pub fn resolve(kind: InputBackend) -> InputBackend {
+    match kind {
+        InputBackend::Auto if cfg!(target_os = "linux") => match SessionType::detect() {
+            SessionType::Wayland => InputBackend::Uinput,
+            _ => InputBackend::Enigo,
+        },
+        InputBackend::Auto => InputBackend::Enigo,
+        other => other,
+    }
+}
+
+/// 在不创建设备的前提下判断后端能否读取指针位置
+///
+/// uinput只能注入事件；自动模式按解析结果判断
+pub fn can_locate(kind: InputBackend) -> bool {
+    !matches!(resolve(kind), InputBackend::Uinput)
+}
+
+/// 在不创建设备的前提下检查后端是否可用
+///
+/// 仅对显式选择的后端报错，自动模式总能回退到enigo
+pub fn check(config: &BackendConfig) -> Result<(), String> {
+    match config.kind {
+        #[cfg(target_os = "linux")]
+        InputBackend::Uinput => uinput::probe(),
+        #[cfg(target_os = "linux")]
+        InputBackend::XTest => xtest::probe(config.display.as_deref()),
+        #[cfg(not(target_os = "linux"))]
+        InputBackend::Uinput | InputBackend::XTest => {
+            Err(t!("backend.error.linux_only", backend = config.kind))
+        }
+        InputBackend::Auto | InputBackend::Enigo => Ok(()),
+    }
+}
+
+/// 创建指定类型的输入后端
+pub fn create(config: &BackendConfig) -> Result<Box<dyn MouseBackend>, String> {
+    match resolve(config.kind) {
+        #[cfg(target_os = "linux")]
+        InputBackend::Uinput => match uinput::UinputBackend::new() {
+            Ok(backend) => Ok(Box::new(backend)),
+            // 自动模式下回退到enigo，显式选择时直接报错
+            Err(e) if config.kind == InputBackend::Auto => {
+                log::warn!("uinput后端不可用，回退到enigo: {}", e);
+                Ok(Box::new(enigo::EnigoBackend::new()?))
+            }
+            Err(e) => Err(e),
+        },
+        #[cfg(target_os = "linux")]
+        InputBackend::XTest => Ok(Box::new(xtest::XTestBackend::new(
+            config.display.as_deref(),
+        )?)),
+        #[cfg(not(target_os = "linux"))]
+        kind @ (InputBackend::Uinput | InputBackend::XTest) => {
+            Err(t!("backend.error.linux_only", backend = kind))
+        }
+        InputBackend::Auto | InputBackend::Enigo => Ok(Box::new(enigo::EnigoBackend::new()?)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn context_keeps_the_error_kind() {
+        let wrap = |e: String| format!("点击失败: {}", e);
+        assert_eq!(
+            InputError::Permission("blocked".to_string()).context(wrap),
+            InputError::Permission("点击失败: blocked".to_string())
+        );
+        assert_eq!(
+            InputError::from("broken".to_string()).context(wrap),
+            InputError::Failed("点击失败: broken".to_string())
+        );
+        assert_eq!(
+            String::from(InputError::Permission("blocked".to_string())),
+            "blocked"
+        );
+    }
+
+    #[test]
+    fn uinput_cannot_locate_the_pointer() {
+        assert!(!can_locate(InputBackend::Uinput));
+        assert!(can_locate(InputBackend::XTest));
+        assert!(can_locate(InputBackend::Enigo));
+    }
+}
@@ -0,0 +1,213 @@
+//! X11后台点击
+//!
+//! 用SendEvent把按键事件直接投递给指定窗口，真实指针保持不动。
+//! 这类事件带有send_event标记，部分程序（如默认配置的xterm）会忽略。
+//! X服务器只在事件无法投递（如窗口已关闭）时报错，目标程序忽略事件时没有任何反馈，
+//! 因此无法检测点击是否生效
+
+use super::xtest::display_name;
+use crate::core::window::WindowId;
+use enigo::{Button, Direction};
+use x11rb::connection::Connection;
+use x11rb::protocol::xproto::{
+    ButtonPressEvent, ConnectionExt as _, EventMask, KeyButMask, Window, BUTTON_PRESS_EVENT,
+    BUTTON_RELEASE_EVENT,
+};
+use x11rb::rust_connection::RustConnection;
+use x11rb::CURRENT_TIME;
+
+/// 基于SendEvent的窗口点击器
+pub struct SendEventBackend {
+    conn: RustConnection,
+    root: Window,
+    display: String,
+}
+
+impl SendEventBackend {
+    /// 连接到指定显示，None表示使用DISPLAY环境变量
+    ///
+    /// 只支持X11，没有可用的X11显示（如纯Wayland会话）时返回错误
+    pub fn new(display: Option<&str>) -> Result<Self, String> {
+        if display.is_none() && std::env::var_os("DISPLAY").is_none() {
+            return Err(t!("send_event.error.no_x11").to_string());
+        }
+        let name = display_name(display);
+        let (conn, screen_num) = x11rb::connect(display)
+            .map_err(|e| t!("xtest.error.connect", display = name, error = e))?;
+        let root = conn.setup().roots[screen_num].root;
+        Ok(Self {
+            conn,
+            root,
+            display: name,
+        })
+    }
+
+    /// 把 `window` 中的坐标换算到 `target` 中，同时返回 `target` 在该点的直接子窗口
+    fn translate(
+        &self,
+        window: Window,
+        target: Window,
+        x: i16,
+        y: i16,
+    ) -> Result<(i16, i16, Window), String> {
+        let reply = self
+            .conn
+            .translate_coordinates(window, target, x, y)
+            .map_err(|e| t!("send_event.error.query", error = e))?
+            .reply()
+            .map_err(|e| t!("send_event.error.query", error = e))?;
+        Ok((reply.dst_x, reply.dst_y, reply.child))
+    }
+
+    /// 窗口左上角的屏幕坐标
+    pub fn window_origin(&self, window: WindowId) -> Result<(i32, i32), String> {
+        let (x, y, _) = self.translate(window, self.root, 0, 0)?;
+        Ok((i32::from(x), i32::from(y)))
+    }
+
+    /// 窗口尺寸（宽, 高）
+    pub fn window_size(&self, window: WindowId) -> Result<(i32, i32), String> {
+        let reply = self
+            .conn
+            .get_geometry(window)
+            .map_err(|e| t!("send_event.error.query", error = e))?
+            .reply()
+            .map_err(|e| t!("send_event.error.query", error = e))?;
+        Ok((i32::from(reply.width), i32::from(reply.height)))
+    }
+
+    /// 找到窗口内坐标处最深层的子窗口，返回子窗口和其中的坐标
+    ///
+    /// 事件需要发给实际处理输入的子窗口，发给顶层窗口时多数工具包不会响应
+    fn target_at(&self, window: Window, x: i16, y: i16) -> Result<(Window, i16, i16), String> {
+        let (mut target, mut x, mut y) = (window, x, y);
+        let (_, _, mut child) = self.translate(window, window, x, y)?;
+        while child != x11rb::NONE {
+            let (child_x, child_y, next) = self.translate(target, child, x, y)?;
+            (target, x, y, child) = (child, child_x, child_y, next);
+        }
+        Ok((target, x, y))
+    }
+
+    /// 向窗口发送一个按键事件，`x`、`y` 为相对窗口左上角的坐标
+    fn send(&self, window: Window, x: i16, y: i16, detail: u8, press: bool) -> Result<(), String> {
+        let (target, event_x, event_y) = self.target_at(window, x, y)?;
+        let (root_x, root_y, _) = self.translate(window, self.root, x, y)?;
+
+        // 释放事件的状态中包含正在释放的按键
+        let (response_type, event_mask, state) = if press {
+            (
+                BUTTON_PRESS_EVENT,
+                EventMask::BUTTON_PRESS,
+                KeyButMask::default(),
+            )
+        } else {
+            (
+                BUTTON_RELEASE_EVENT,
+                EventMask::BUTTON_RELEASE,
+                button_mask(detail),
+            )
+        };
+        let event = ButtonPressEvent {
+            response_type,
+            detail,
+            sequence: 0,
+            time: CURRENT_TIME,
+            root: self.root,
+            event: target,
+            child: x11rb::NONE,
+            root_x,
+            root_y,
+            event_x,
+            event_y,
+            state,
+            same_screen: true,
+        };
+
+        // 子窗口没有选择按键事件时沿父窗口向上传递。
+        // check只能发现协议错误（如窗口已不存在），无法得知目标程序是否处理了事件
+        self.conn
+            .send_event(true, target, event_mask, event)
+            .map_err(|e| t!("send_event.error.send", error = e))?
+            .check()
+            .map_err(|e| {
+                t!(
+                    "send_event.error.rejected",
+                    display = self.display,
+                    error = e
+                )
+            })
+    }
+
+    /// 在窗口内的坐标处按下、释放或单击鼠标按键
+    pub fn button(
+        &self,
+        window: WindowId,
+        (x, y): (i32, i32),
+        button: Button,
+        direction: Direction,
+    ) -> Result<(), String> {
+        let detail = match button {
+            Button::Left => 1,
+            Button::Middle => 2,
+            Button::Right => 3,
+            other => {
+                return Err(t!(
+                    "backend.error.button_unsupported",
+                    backend = "SendEvent",
+                    button = format!("{:?}", other)
+                ))
+            }
+        };
+        self.press_release(window, (x, y), detail, direction)
+    }
+
+    /// 在窗口内的坐标处滚动，符号约定与enigo一致：正数向下，负数向上
+    pub fn scroll(&self, window: WindowId, (x, y): (i32, i32), length: i32) -> Result<(), String> {
+        let detail = wheel_button(length);
+        for _ in 0..length.unsigned_abs() {
+            self.press_release(window, (x, y), detail, Direction::Click)?;
+        }
+        Ok(())
+    }
+
+    /// 按下并释放指定的X11按键编号
+    fn press_release(
+        &self,
+        window: Window,
+        (x, y): (i32, i32),
+        detail: u8,
+        direction: Direction,
+    ) -> Result<(), String> {
+        let x = i16::try_from(x).map_err(|_| t!("xtest.error.x_out_of_range", x = x))?;
+        let y = i16::try_from(y).map_err(|_| t!("xtest.error.y_out_of_range", y = y))?;
+        if matches!(direction, Direction::Press | Direction::Click) {
+            self.send(window, x, y, detail, true)?;
+        }
+        if matches!(direction, Direction::Release | Direction::Click) {
+            self.send(window, x, y, detail, false)?;
+        }
+        Ok(())
+    }
+}
+
+/// 按键编号对应的状态掩码
+fn button_mask(detail: u8) -> KeyButMask {
+    match detail {
+        1 => KeyButMask::BUTTON1,
+        2 => KeyButMask::BUTTON2,
+        3 => KeyButMask::BUTTON3,
+        4 => KeyButMask::BUTTON4,
+        5 => KeyButMask::BUTTON5,
+        _ => KeyButMask::default(),
+    }
+}
+
+/// 滚动方向对应的X11按键编号，滚轮是按键4（上）和5（下）
+pub(crate) fn wheel_button(length: i32) -> u8 {
+    if length > 0 {
+        5
+    } else {
+        4
+    }
+}
@@ -0,0 +1,183 @@
+//! Linux uinput输入后端
+//!
+//! 通过 `/dev/uinput` 创建虚拟指针设备，由内核直接注入事件，
+//! 因此在Wayland会话中同样有效
+
+use super::{InputError, MouseBackend};
+use enigo::{Button, Direction};
+use evdev::uinput::{VirtualDevice, VirtualDeviceBuilder};
+use evdev::{AttributeSet, EventType, InputEvent, Key, RelativeAxisType};
+use std::ffi::CStr;
+use std::fs::OpenOptions;
+use std::io::ErrorKind;
+use std::path::Path;
+use std::time::Duration;
+
+/// uinput设备节点
+const UINPUT_PATH: &str = "/dev/uinput";
+
+/// 虚拟设备名称
+const DEVICE_NAME: &str = "mouse-clicker virtual pointer";
+
+/// 基于uinput的输入后端
+pub struct UinputBackend {
+    device: VirtualDevice,
+}
+
+impl UinputBackend {
+    /// 创建虚拟指针设备
+    pub fn new() -> Result<Self, String> {
+        probe()?;
+
+        let mut keys = AttributeSet::<Key>::new();
+        keys.insert(Key::BTN_LEFT);
+        keys.insert(Key::BTN_RIGHT);
+        keys.insert(Key::BTN_MIDDLE);
+
+        let mut axes = AttributeSet::<RelativeAxisType>::new();
+        // 合成器只会把同时具备X/Y相对轴的设备识别为鼠标
+        axes.insert(RelativeAxisType::REL_X);
+        axes.insert(RelativeAxisType::REL_Y);
+        axes.insert(RelativeAxisType::REL_WHEEL);
+
+        let device = VirtualDeviceBuilder::new()
+            .and_then(|builder| builder.name(DEVICE_NAME).with_keys(&keys))
+            .and_then(|builder| builder.with_relative_axes(&axes))
+            .and_then(|builder| builder.build())
+            .map_err(|e| t!("uinput.error.create", error = e))?;
+
+        // 等待合成器识别新设备，否则最初的事件会被丢弃
+        std::thread::sleep(Duration::from_millis(200));
+        log::info!("已创建uinput虚拟指针设备");
+
+        Ok(Self { device })
+    }
+
+    /// 发送一组事件
+    fn emit(&mut self, events: &[InputEvent]) -> Result<(), InputError> {
+        self.device.emit(events).map_err(|e| {
+            let message = t!("uinput.error.write", error = e);
+            if e.kind() == ErrorKind::PermissionDenied {
+                InputError::Permission(message)
+            } else {
+                InputError::Failed(message)
+            }
+        })
+    }
+}
+
+impl MouseBackend for UinputBackend {
+    fn name(&self) -> &'static str {
+        "uinput"
+    }
+
+    fn button(&mut self, button: Button, direction: Direction) -> Result<(), InputError> {
+        let key = match button {
+            Button::Left => Key::BTN_LEFT,
+            Button::Right => Key::BTN_RIGHT,
+            Button::Middle => Key::BTN_MIDDLE,
+            other => {
+                return Err(t!(
+                    "backend.error.button_unsupported",
+                    backend = "uinput",
+                    button = format!("{:?}", other)
+                )
+                .into())
+            }
+        };
+        let event = |value| InputEvent::new(EventType::KEY, key.code(), value);
+
+        match direction {
+            Direction::Press => self.emit(&[event(1)]),
+            Direction::Release => self.emit(&[event(0)]),
+            Direction::Click => {
+                self.emit(&[event(1)])?;
+                self.emit(&[event(0)])
+            }
+        }
+    }
+
+    fn scroll(&mut self, length: i32) -> Result<(), InputError> {
+        // REL_WHEEL正值表示向上滚动，与enigo约定相反
+        let event = InputEvent::new(EventType::RELATIVE, RelativeAxisType::REL_WHEEL.0, -length);
+        self.emit(&[event])
+    }
+}
+
+/// 检测当前用户能否使用uinput
+///
+/// 失败时返回可直接展示给用户的错误说明
+pub fn probe() -> Result<(), String> {
+    if !Path::new(UINPUT_PATH).exists() {
+        return Err(t!("uinput.error.missing", path = UINPUT_PATH));
+    }
+
+    match OpenOptions::new().write(true).open(UINPUT_PATH) {
+        Ok(_) => Ok(()),
+        Err(e) if e.kind() == ErrorKind::PermissionDenied => Err(permission_hint()),
+        Err(e) => Err(t!("uinput.error.open", path = UINPUT_PATH, error = e)),
+    }
+}
+
+/// 根据用户组情况生成权限错误说明
+fn permission_hint() -> String {
+    match input_group_membership() {
+        GroupMembership::Active => t!("uinput.error.wrong_group", path = UINPUT_PATH),
+        GroupMembership::PendingRelogin => t!("uinput.error.relogin", path = UINPUT_PATH),
+        GroupMembership::None => t!("uinput.error.not_in_group", path = UINPUT_PATH),
+    }
+}
+
+/// input组成员状态
+enum GroupMembership {
+    /// 当前进程已拥有该组
+    Active,
+    /// 已写入组数据库，但当前会话尚未生效
+    PendingRelogin,
+    /// 不在组中（或组不存在）
+    None,
+}
+
+/// 检查当前用户与input组的关系
+fn input_group_membership() -> GroupMembership {
+    // SAFETY: getgrnam/getpwuid返回指向静态缓冲区的指针，只在本函数内立即读取
+    unsafe {
+        let group = libc::getgrnam(c"input".as_ptr());
+        if group.is_null() {
+            return GroupMembership::None;
+        }
+        let gid = (*group).gr_gid;
+        if libc::getegid() == gid {
+            return GroupMembership::Active;
+        }
+
+        let count = libc::getgroups(0, std::ptr::null_mut());
+        if count > 0 {
+            let mut groups = vec![0 as libc::gid_t; count as usize];
+            let count = libc::getgroups(count, groups.as_mut_ptr());
+            if count > 0 && groups[..count as usize].contains(&gid) {
+                return GroupMembership::Active;
+            }
+        }
+
+        let passwd = libc::getpwuid(libc::getuid());
+        if passwd.is_null() {
+            return GroupMembership::None;
+        }
+        let user = CStr::from_ptr((*passwd).pw_name).to_owned();
+
+        // getgrnam的结果可能被getpwuid覆盖，重新查询
+        let group = libc::getgrnam(c"input".as_ptr());
+        if group.is_null() {
+            return GroupMembership::None;
+        }
+        let mut member = (*group).gr_mem;
+        while !member.is_null() && !(*member).is_null() {
+            if CStr::from_ptr(*member) == user.as_c_str() {
+                return GroupMembership::PendingRelogin;
+            }
+            member = member.add(1);
+        }
+    }
+    GroupMembership::None
+}
@@ -0,0 +1,143 @@
+//! X11 XTest输入后端
+//!
+//! 直接通过XTest扩展注入事件，可连接到任意X11显示（包括Xvfb），
+//! 不依赖libxdo或GPU
+
+use super::{InputError, MouseBackend};
+use enigo::{Button, Direction};
+use x11rb::connection::{Connection, RequestConnection};
+use x11rb::protocol::xproto::{
+    ConnectionExt as _, Window, BUTTON_PRESS_EVENT, BUTTON_RELEASE_EVENT, MOTION_NOTIFY_EVENT,
+};
+use x11rb::protocol::xtest::{self, ConnectionExt as _};
+use x11rb::rust_connection::RustConnection;
+use x11rb::CURRENT_TIME;
+
+/// 基于XTest的输入后端
+pub struct XTestBackend {
+    conn: RustConnection,
+    root: Window,
+    width: i32,
+    height: i32,
+    display: String,
+}
+
+impl XTestBackend {
+    /// 连接到指定显示，None表示使用DISPLAY环境变量
+    pub fn new(display: Option<&str>) -> Result<Self, String> {
+        let name = display_name(display);
+        let (conn, screen_num) = x11rb::connect(display)
+            .map_err(|e| t!("xtest.error.connect", display = name, error = e))?;
+
+        let has_xtest = conn
+            .extension_information(xtest::X11_EXTENSION_NAME)
+            .map_err(|e| t!("xtest.error.query_extension", error = e))?
+            .is_some();
+        if !has_xtest {
+            return Err(t!("xtest.error.unsupported", display = name));
+        }
+
+        let screen = &conn.setup().roots[screen_num];
+        let (root, width, height) = (
+            screen.root,
+            i32::from(screen.width_in_pixels),
+            i32::from(screen.height_in_pixels),
+        );
+        log::info!("已连接X11显示 {}，屏幕尺寸 {}x{}", name, width, height);
+
+        Ok(Self {
+            conn,
+            root,
+            width,
+            height,
+            display: name,
+        })
+    }
+
+    /// 发送一个伪造输入事件并等待服务器处理
+    fn fake_input(&self, type_: u8, detail: u8, x: i16, y: i16) -> Result<(), String> {
+        self.conn
+            .xtest_fake_input(type_, detail, CURRENT_TIME, self.root, x, y, 0)
+            .map_err(|e| t!("xtest.error.send", error = e))?
+            .check()
+            .map_err(|e| t!("xtest.error.rejected", display = self.display, error = e))
+    }
+
+    /// 按下并释放指定的X11按键编号
+    fn press_release(&self, detail: u8, direction: Direction) -> Result<(), String> {
+        if matches!(direction, Direction::Press | Direction::Click) {
+            self.fake_input(BUTTON_PRESS_EVENT, detail, 0, 0)?;
+        }
+        if matches!(direction, Direction::Release | Direction::Click) {
+            self.fake_input(BUTTON_RELEASE_EVENT, detail, 0, 0)?;
+        }
+        Ok(())
+    }
+}
+
+impl MouseBackend for XTestBackend {
+    fn name(&self) -> &'static str {
+        "XTest"
+    }
+
+    fn button(&mut self, button: Button, direction: Direction) -> Result<(), InputError> {
+        let detail = match button {
+            Button::Left => 1,
+            Button::Middle => 2,
+            Button::Right => 3,
+            other => {
+                return Err(t!(
+                    "backend.error.button_unsupported",
+                    backend = "XTest",
+                    button = format!("{:?}", other)
+                )
+                .into())
+            }
+        };
+        self.press_release(detail, direction)
+            .map_err(InputError::from)
+    }
+
+    fn scroll(&mut self, length: i32) -> Result<(), InputError> {
+        // X11中滚轮是按键4（上）和5（下）
+        let detail = if length > 0 { 5 } else { 4 };
+        for _ in 0..length.unsigned_abs() {
+            self.press_release(detail, Direction::Click)?;
+        }
+        Ok(())
+    }
+
+    fn move_to(&mut self, x: i32, y: i32) -> Result<(), InputError> {
+        let x = i16::try_from(x).map_err(|_| t!("xtest.error.x_out_of_range", x = x))?;
+        let y = i16::try_from(y).map_err(|_| t!("xtest.error.y_out_of_range", y = y))?;
+        self.fake_input(MOTION_NOTIFY_EVENT, 0, x, y)
+            .map_err(InputError::from)
+    }
+
+    fn location(&self) -> Result<(i32, i32), String> {
+        let reply = self
+            .conn
+            .query_pointer(self.root)
+            .map_err(|e| t!("xtest.error.query_pointer", error = e))?
+            .reply()
+            .map_err(|e| t!("xtest.error.query_pointer", error = e))?;
+        Ok((i32::from(reply.root_x), i32::from(reply.root_y)))
+    }
+
+    fn display_size(&self) -> Result<(i32, i32), String> {
+        Ok((self.width, self.height))
+    }
+}
+
+/// 检查能否连接到显示并使用XTest
+pub fn probe(display: Option<&str>) -> Result<(), String> {
+    XTestBackend::new(display).map(|_| ())
+}
+
+/// 用于提示信息的显示名称
+pub(super) fn display_name(display: Option<&str>) -> String {
+    display
+        .map(str::to_string)
+        .or_else(|| std::env::var("DISPLAY").ok())
+        .unwrap_or_else(|| t!("xtest.display_unset").to_string())
+}
@@ -2,14 +2,177 @@
 //!
 //! 实现自动点击的核心逻辑
 
-use crate::config::AppSettings;
-use crate::core::hotkey::HotkeyManager;
+use crate::config::{
+    Action, AppSettings, BurstSettings, ClickDelivery, ClickPoint, ClickTarget, ConditionMode,
+    FailSafeSettings, HistoryStore, MouseButton, PixelCondition, ProfileStore,
+    TargetWindowSettings, TemplateSettings,
+};
+use crate::core::actions::{self, ActionHost};
+use crate::core::backend::{self, BackendConfig, InputError};
+use crate::core::clock::{Clock, ManualClock, SystemClock};
+use crate::core::condition::PixelWatcher;
+use crate::core::events::{self, ClickerEvent, ErrorKind, EventBus, PauseReason, StopReason};
+use crate::core::failsafe::{FailSafe, FailSafeTrigger};
+use crate::core::hotkey::{self, HotkeyManager};
+use crate::core::monitor::{CoordinateCheck, MonitorLayout};
 use crate::core::mouse::MouseController;
+use crate::core::screen::ScreenReader;
+use crate::core::script;
+use crate::core::stats;
+use crate::core::template::TemplateLocator;
+use crate::core::window::{WindowFilter, WindowFinder};
+use std::panic::{self, AssertUnwindSafe};
 use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
-use std::sync::Arc;
-use std::thread;
+use std::sync::mpsc::Receiver;
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+use std::thread::{self, JoinHandle};
 use std::time::{Duration, Instant};
 
+/// 启用紧急停止时检查指针位置的间隔
+const POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+/// 颜色条件或目标窗口不满足时重新检查的最长间隔
+const CONDITION_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// 找不到模板图像时重新截图的间隔
+const TEMPLATE_RETRY_INTERVAL: Duration = Duration::from_millis(250);
+
+/// 快进模拟的虚拟时长上限，避免不会自行结束的任务一直占用CPU
+const FAST_FORWARD_LIMIT: Duration = Duration::from_secs(4 * 60 * 60);
+
+/// 快进模拟最多广播的高频事件数量，超出后只更新计数
+const FAST_FORWARD_EVENT_LIMIT: usize = 20_000;
+
+/// 运行标志的内部状态
+#[derive(Default)]
+struct RunState {
+    running: bool,
+    /// 最近一次停止的原因
+    stop_reason: Option<StopReason>,
+}
+
+/// 可等待的运行标志
+///
+/// 清除标志会立即唤醒正在等待间隔的连点线程，停止不必等到本次间隔结束
+#[derive(Clone, Default)]
+struct RunFlag {
+    inner: Arc<(Mutex<RunState>, Condvar)>,
+}
+
+impl RunFlag {
+    fn lock(&self) -> MutexGuard<'_, RunState> {
+        // 状态只包含简单字段，线程panic后依然可用
+        self.inner.0.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// 是否处于运行状态
+    fn is_set(&self) -> bool {
+        self.lock().running
+    }
+
+    /// 设置为运行状态
+    fn set(&self) {
+        *self.lock() = RunState {
+            running: true,
+            stop_reason: None,
+        };
+    }
+
+    /// 清除运行状态并唤醒等待者
+    ///
+    /// 只记录第一个停止原因，已停止时调用不会覆盖
+    fn clear(&self, reason: StopReason) {
+        let mut state = self.lock();
+        if state.running {
+            state.running = false;
+            state.stop_reason = Some(reason);
+        }
+        self.inner.1.notify_all();
+    }
+
+    /// 最近一次停止的原因
+    fn stop_reason(&self) -> Option<StopReason> {
+        self.lock().stop_reason.clone()
+    }
+
+    /// 在运行状态下最多等待 `timeout`
+    ///
+    /// 标志被清除时立即返回false；虚拟时钟直接推进时间
+    fn wait(&self, timeout: Duration, clock: &dyn Clock) -> bool {
+        if clock.is_virtual() {
+            clock.sleep(timeout);
+            return self.is_set();
+        }
+        let guard = self.lock();
+        let (guard, _) = self
+            .inner
+            .1
+            .wait_timeout_while(guard, timeout, |state| state.running)
+            .unwrap_or_else(|e| e.into_inner());
+        guard.running
+    }
+}
+
+/// 连发中一次点击之后的动作
+enum BurstStep {
+    /// 按组内间隔继续点击
+    Next,
+    /// 本组已完成，休息后开始下一组
+    Rest,
+    /// 所有组均已完成
+    Finished,
+}
+
+/// 连发进度，由连点线程更新
+#[derive(Default)]
+struct BurstProgress {
+    /// 本次运行是否使用连发
+    active: AtomicBool,
+    /// 当前组序号，从1开始
+    burst: AtomicU32,
+    /// 当前组已完成的点击次数
+    clicks: AtomicU32,
+}
+
+impl BurstProgress {
+    /// 新的运行开始时重置进度
+    fn reset(&self, active: bool) {
+        self.active.store(active, Ordering::Relaxed);
+        self.burst.store(1, Ordering::Relaxed);
+        self.clicks.store(0, Ordering::Relaxed);
+    }
+
+    /// 当前组序号和组内点击次数，未使用连发时为None
+    fn current(&self) -> Option<(u32, u32)> {
+        self.active.load(Ordering::Relaxed).then(|| {
+            (
+                self.burst.load(Ordering::Relaxed),
+                self.clicks.load(Ordering::Relaxed),
+            )
+        })
+    }
+
+    /// 记录一次成功的点击，返回接下来的动作
+    fn record_click(&self, settings: &BurstSettings) -> BurstStep {
+        let clicks = self.clicks.fetch_add(1, Ordering::Relaxed) + 1;
+        if clicks < settings.clicks_per_burst {
+            return BurstStep::Next;
+        }
+        let burst = self.burst.load(Ordering::Relaxed);
+        if settings.bursts.is_some_and(|total| burst >= total) {
+            BurstStep::Finished
+        } else {
+            BurstStep::Rest
+        }
+    }
+
+    /// 休息结束，开始下一组
+    fn next_burst(&self) {
+        self.burst.fetch_add(1, Ordering::Relaxed);
+        self.clicks.store(0, Ordering::Relaxed);
+    }
+}
+
 /// 连点器状态
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ClickerState {
@@ -30,6 +193,16 @@ pub struct ClickerStatus {
     pub click_count: u32,
     pub target_count: Option<u32>,
     pub runtime_seconds: u64,
+    /// 最近一次停止的原因
+    pub last_stop_reason: Option<StopReason>,
+    /// 连发的当前组序号，从1开始（None表示未使用连发）
+    pub current_burst: Option<u32>,
+    /// 连发当前组已完成的点击次数
+    pub clicks_in_burst: u32,
+    /// 连发每组的点击次数
+    pub clicks_per_burst: u32,
+    /// 连发的总组数（None表示不限）
+    pub bursts: Option<u32>,
 }
 
 impl Default for ClickerStatus {
@@ -39,17 +212,356 @@ impl Default for ClickerStatus {
             click_count: 0,
             target_count: None,
             runtime_seconds: 0,
+            last_stop_reason: None,
+            current_burst: None,
+            clicks_in_burst: 0,
+            clicks_per_burst: 0,
+            bursts: None,
         }
     }
 }
 
-/// 连点器管理器
-pub struct ClickerManager {
+/// 连点器运行状态，由 [`ClickerHandle`] 在线程间共享
+struct ClickerCore {
     settings: AppSettings,
-    hotkey_manager: HotkeyManager,
-    is_running: Arc<AtomicBool>,
+    is_running: RunFlag,
     click_count: Arc<AtomicU32>,
+    /// 本次运行的连发设置
+    burst: Option<BurstSettings>,
+    /// 连发进度
+    burst_progress: Arc<BurstProgress>,
     start_time: Option<Instant>,
+    /// 模拟运行，只记录输入而不执行
+    dry_run: bool,
+    /// 模拟运行时使用虚拟时钟快进，不实际等待
+    fast_forward: bool,
+    /// 计时和等待使用的时钟，创建时注入，在多次运行之间保持不变
+    clock: Arc<dyn Clock>,
+    /// 本次运行使用的时钟，快进模拟时为独立的虚拟时钟
+    run_clock: Arc<dyn Clock>,
+    /// 连点线程
+    worker: Option<JoinHandle<()>>,
+    /// 事件总线
+    events: EventBus,
+}
+
+impl ClickerCore {
+    /// 启动连点器
+    fn start(&mut self) -> Result<(), String> {
+        if self.is_running.is_set() {
+            return Ok(());
+        }
+
+        // 提前检查输入后端，避免启动后才发现无法点击；模拟运行不使用输入后端
+        let backend_config = BackendConfig::from_settings(&self.settings);
+        if !self.dry_run {
+            backend::check(&backend_config)?;
+        }
+        // 紧急停止需要读取指针位置，后端无法读取时本次运行不检查紧急停止
+        let mut failsafe = self.settings.failsafe.clone();
+        if failsafe.enabled && !self.dry_run && !backend::can_locate(backend_config.kind) {
+            log::warn!(
+                "输入后端{}无法读取指针位置，本次运行不检查紧急停止",
+                backend::resolve(backend_config.kind)
+            );
+            failsafe.enabled = false;
+        }
+        // 后台点击只支持X11；脚本和操作序列不使用后台点击
+        if self.settings.click_delivery == ClickDelivery::Window
+            && !self.settings.script.enabled
+            && !self.settings.sequence.enabled
+        {
+            MouseController::check_window_delivery(backend_config.display.as_deref())?;
+        }
+
+        // 提前编译脚本，语法错误直接报告给用户
+        let script = if self.settings.script.enabled {
+            let source = script::load_source(&self.settings.script)?;
+            script::check(&source)?;
+            Some(source)
+        } else {
+            None
+        };
+        let sequence = if self.settings.sequence.enabled {
+            if self.settings.has_sequence_conflict() {
+                return Err(t!("settings.error.sequence_conflict").to_string());
+            }
+            actions::check(&self.settings.sequence.steps)?;
+            Some(self.settings.sequence.steps.clone())
+        } else {
+            None
+        };
+
+        // 检查保存的坐标是否仍位于已连接的显示器上，能枚举显示器却失败时在启动后告知订阅者
+        let (monitors, unchecked) =
+            match MonitorLayout::check(backend_config.display.as_deref(), &self.settings)? {
+                CoordinateCheck::Checked(layout) => (layout, None),
+                CoordinateCheck::Unsupported => (MonitorLayout::default(), None),
+                CoordinateCheck::Unchecked(reason) => {
+                    log::warn!("无法枚举显示器，坐标未经检查: {}", reason);
+                    (MonitorLayout::default(), Some(reason))
+                }
+            };
+
+        // 上一个线程由调用方在锁外回收，见 [`ClickerHandle::lock_idle`]
+        debug_assert!(self.worker.is_none(), "上一个连点线程尚未回收");
+
+        // 每次快进都从新的虚拟时钟开始
+        self.run_clock = if self.dry_run && self.fast_forward {
+            Arc::new(ManualClock::default())
+        } else {
+            self.clock.clone()
+        };
+        let time_limit = self
+            .run_clock
+            .is_virtual()
+            .then(|| self.run_clock.now() + FAST_FORWARD_LIMIT);
+        // 虚拟时钟下连点线程不会真正等待，限制高频事件的数量以免订阅者积压
+        let events = if self.run_clock.is_virtual() {
+            self.events.with_quota(FAST_FORWARD_EVENT_LIMIT)
+        } else {
+            self.events.clone()
+        };
+
+        // 脚本和操作序列自行控制节奏，不使用连发
+        let burst = (self.settings.burst.enabled && script.is_none() && sequence.is_none())
+            .then(|| self.settings.burst.clone());
+
+        self.is_running.set();
+        self.click_count.store(0, Ordering::Relaxed);
+        self.burst_progress.reset(burst.is_some());
+        self.burst = burst.clone();
+        self.start_time = Some(self.run_clock.now());
+
+        let job = WorkerJob {
+            backend_config,
+            interval: Duration::from_millis(self.settings.interval_ms),
+            target_count: self.settings.click_count,
+            mouse_button: self.settings.mouse_button,
+            click_target: self.settings.click_target.clone(),
+            monitors,
+            click_delivery: self.settings.click_delivery,
+            pixel_condition: self.settings.pixel_condition,
+            template: self.settings.template.clone(),
+            target_window: self.settings.target_window.clone(),
+            failsafe,
+            script,
+            sequence,
+            burst,
+            burst_progress: self.burst_progress.clone(),
+            dry_run: self.dry_run,
+            clock: self.run_clock.clone(),
+            time_limit,
+            is_running: self.is_running.clone(),
+            click_count: self.click_count.clone(),
+            events,
+        };
+
+        self.events.emit(ClickerEvent::Started {
+            profile: self.settings.profile.clone(),
+            target_count: self.settings.click_count,
+            dry_run: self.dry_run,
+        });
+        if let Some(reason) = unchecked {
+            self.events.emit(ClickerEvent::CoordinatesUnchecked(reason));
+        }
+
+        // 在新线程中执行连点逻辑
+        self.worker = Some(thread::spawn(move || {
+            let is_running = job.is_running.clone();
+            let events = job.events.clone();
+            // MouseController在析构时释放按下的按键，panic展开时同样生效
+            if panic::catch_unwind(AssertUnwindSafe(|| run_worker(job))).is_err() {
+                events.emit(ClickerEvent::Error(ErrorKind::Panic));
+                is_running.clear(StopReason::Error(ErrorKind::Panic));
+            }
+
+            let dropped = events.dropped();
+            if dropped > 0 {
+                events.emit(ClickerEvent::EventsDropped(dropped));
+            }
+
+            // 无论以何种方式结束，运行标志此时都已被清除
+            let reason = is_running.stop_reason().unwrap_or(StopReason::User);
+            events.emit(ClickerEvent::Stopped(reason));
+        }));
+
+        Ok(())
+    }
+
+    /// 以指定原因停止连点器
+    ///
+    /// 返回需要等待退出的连点线程，调用者应在释放锁之后再等待，
+    /// 避免等待期间托盘等其它线程读取状态时被阻塞
+    fn stop_with(&mut self, reason: StopReason) -> Option<JoinHandle<()>> {
+        self.is_running.clear(reason);
+        self.start_time = None;
+        self.worker.take()
+    }
+
+    /// 切换运行状态，停止时返回需要等待退出的连点线程
+    fn toggle(&mut self) -> Result<Option<JoinHandle<()>>, String> {
+        if self.is_running.is_set() {
+            Ok(self.stop_with(StopReason::User))
+        } else {
+            self.start().map(|_| None)
+        }
+    }
+
+    /// 获取当前状态
+    fn get_status(&self) -> ClickerStatus {
+        let state = if self.is_running.is_set() {
+            ClickerState::Running
+        } else {
+            ClickerState::Stopped
+        };
+
+        let runtime_seconds = self
+            .start_time
+            .map(|start| {
+                self.run_clock
+                    .now()
+                    .saturating_duration_since(start)
+                    .as_secs()
+            })
+            .unwrap_or(0);
+
+        let burst = self.burst_progress.current();
+        ClickerStatus {
+            state,
+            click_count: self.click_count.load(Ordering::Relaxed),
+            target_count: self.settings.click_count,
+            runtime_seconds,
+            last_stop_reason: self.is_running.stop_reason(),
+            current_burst: burst.map(|(burst, _)| burst),
+            clicks_in_burst: burst.map_or(0, |(_, clicks)| clicks),
+            clicks_per_burst: self.burst.as_ref().map_or(0, |b| b.clicks_per_burst),
+            bursts: self.burst.as_ref().and_then(|b| b.bursts),
+        }
+    }
+}
+
+/// 连点器句柄
+///
+/// 可克隆并在线程间共享，托盘和热键线程通过它控制连点器，不依赖界面刷新
+#[derive(Clone)]
+pub struct ClickerHandle {
+    core: Arc<Mutex<ClickerCore>>,
+}
+
+impl ClickerHandle {
+    /// 创建连点器，计时和等待使用 `clock`
+    ///
+    /// 注入的时钟在多次运行之间保持不变；快进模拟的运行另行使用独立的虚拟时钟
+    pub fn new(settings: AppSettings, events: EventBus, clock: Arc<dyn Clock>) -> Self {
+        Self {
+            core: Arc::new(Mutex::new(ClickerCore {
+                settings,
+                is_running: RunFlag::default(),
+                click_count: Arc::new(AtomicU32::new(0)),
+                burst: None,
+                burst_progress: Arc::default(),
+                start_time: None,
+                dry_run: false,
+                fast_forward: false,
+                run_clock: clock.clone(),
+                clock,
+                worker: None,
+                events,
+            })),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, ClickerCore> {
+        self.core.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// 获取锁，已停止时先回收上一个连点线程
+    ///
+    /// 上一个线程可能刚自行结束（如达到目标次数），回收后再启动，保证任何时刻最多只有一个
+    /// 连点线程在修改计数。等待线程退出时不持有锁，以免阻塞状态查询和停止
+    fn lock_idle(&self) -> MutexGuard<'_, ClickerCore> {
+        loop {
+            let mut core = self.lock();
+            if core.is_running.is_set() {
+                return core;
+            }
+            match core.worker.take() {
+                Some(worker) => {
+                    drop(core);
+                    join_worker(Some(worker));
+                }
+                None => return core,
+            }
+        }
+    }
+
+    /// 订阅连点器事件
+    pub fn subscribe(&self) -> Receiver<ClickerEvent> {
+        self.lock().events.subscribe()
+    }
+
+    /// 以指定原因停止连点器
+    ///
+    /// 等待连点线程退出，返回时所有按下的按键均已释放
+    pub fn stop_with(&self, reason: StopReason) {
+        let worker = self.lock().stop_with(reason);
+        join_worker(worker);
+    }
+
+    /// 切换运行状态
+    pub fn toggle(&self) -> Result<(), String> {
+        let worker = self.lock_idle().toggle()?;
+        join_worker(worker);
+        Ok(())
+    }
+
+    /// 切换运行状态，失败时以错误事件通知订阅者
+    ///
+    /// 供热键和托盘等没有界面可以直接显示错误的入口使用
+    pub fn toggle_and_report(&self) {
+        let mut core = self.lock_idle();
+        match core.toggle() {
+            Ok(worker) => {
+                drop(core);
+                join_worker(worker);
+            }
+            Err(e) => core.events.emit(ClickerEvent::Error(ErrorKind::Backend(e))),
+        }
+    }
+
+    /// 获取当前状态
+    pub fn get_status(&self) -> ClickerStatus {
+        self.lock().get_status()
+    }
+
+    /// 获取当前设置
+    pub fn settings(&self) -> AppSettings {
+        self.lock().settings.clone()
+    }
+
+    /// 切换配置方案
+    ///
+    /// 先保存当前方案再加载目标方案；热键是全局设置，切换时保持不变。
+    /// 新设置在下次启动时生效
+    pub fn switch_profile(&self, store: &ProfileStore, name: &str) -> Result<(), String> {
+        let mut core = self.lock();
+        store.save(&core.settings)?;
+        let mut settings = store.load(name)?;
+        settings.hotkey = core.settings.hotkey;
+        core.settings = settings;
+        core.events
+            .emit(ClickerEvent::ProfileSwitched(name.to_string()));
+        Ok(())
+    }
+}
+
+/// 连点器管理器
+///
+/// 负责注册热键，并在后台线程中响应热键
+pub struct ClickerManager {
+    hotkey_manager: HotkeyManager,
+    handle: ClickerHandle,
 }
 
 impl ClickerManager {
@@ -59,146 +571,1039 @@ impl ClickerManager {
         // 注册热键
         hotkey_manager.set_hotkey(settings.hotkey)?;
 
+        let events = EventBus::default();
+        events::spawn_logger(&events);
+        match HistoryStore::new() {
+            Ok(store) => stats::spawn_recorder(&events, store),
+            Err(e) => log::warn!("无法记录历史: {}", e),
+        }
+
+        let handle = ClickerHandle::new(settings, events, Arc::new(SystemClock));
+
+        // 热键线程只持有弱引用，管理器释放后自动退出
+        let core = Arc::downgrade(&handle.core);
+        hotkey::spawn_listener(move || {
+            let Some(core) = core.upgrade() else {
+                return false;
+            };
+            log::info!("热键触发，切换连点器状态");
+            ClickerHandle { core }.toggle_and_report();
+            true
+        });
+
         Ok(Self {
-            settings,
             hotkey_manager,
-            is_running: Arc::new(AtomicBool::new(false)),
-            click_count: Arc::new(AtomicU32::new(0)),
-            start_time: None,
+            handle,
         })
     }
 
-    /// 启动连点器
-    pub fn start(&mut self) -> Result<(), String> {
-        if self.is_running.load(Ordering::Relaxed) {
-            return Ok(());
+    /// 获取可在线程间共享的句柄
+    pub fn handle(&self) -> ClickerHandle {
+        self.handle.clone()
+    }
+
+    /// 订阅连点器事件
+    pub fn subscribe(&self) -> Receiver<ClickerEvent> {
+        self.handle.subscribe()
+    }
+
+    /// 以指定原因停止连点器
+    pub fn stop_with(&mut self, reason: StopReason) {
+        self.handle.stop_with(reason);
+    }
+
+    /// 更新设置
+    pub fn update_settings(&mut self, new_settings: AppSettings) -> Result<(), String> {
+        let mut core = self.handle.lock();
+        // 如果热键改变了，重新注册
+        if core.settings.hotkey != new_settings.hotkey {
+            self.hotkey_manager.set_hotkey(new_settings.hotkey)?;
         }
 
-        self.is_running.store(true, Ordering::Relaxed);
-        self.click_count.store(0, Ordering::Relaxed);
-        self.start_time = Some(Instant::now());
+        core.settings = new_settings;
+        log::info!("连点器设置已更新");
+        Ok(())
+    }
 
-        let is_running = self.is_running.clone();
-        let click_count = self.click_count.clone();
-        let interval = self.settings.interval_ms;
-        let target_count = self.settings.click_count;
-        let mouse_button = self.settings.mouse_button;
+    /// 设置是否模拟运行，下次启动时生效
+    ///
+    /// 模拟运行时计时、条件和操作序列照常执行，但所有输入只以
+    /// [`ClickerEvent::Simulated`] 事件广播，不会实际点击或按键
+    pub fn set_dry_run(&mut self, dry_run: bool) {
+        self.handle.lock().dry_run = dry_run;
+    }
 
-        // 在新线程中执行连点逻辑
-        thread::spawn(move || {
-            let mut mouse = match MouseController::new() {
-                Ok(m) => m,
+    /// 设置模拟运行时是否快进，下次启动时生效
+    ///
+    /// 快进时使用手动推进的虚拟时钟，所有等待立即完成，记录的时间和运行时间
+    /// 按虚拟时钟计算；虚拟时长达到上限时自动停止
+    pub fn set_fast_forward(&mut self, fast_forward: bool) {
+        self.handle.lock().fast_forward = fast_forward;
+    }
+
+    /// 获取当前状态
+    pub fn get_status(&self) -> ClickerStatus {
+        self.handle.get_status()
+    }
+
+    /// 切换运行状态
+    pub fn toggle(&mut self) -> Result<(), String> {
+        self.handle.toggle()
+    }
+}
+
+/// 等待连点线程退出
+fn join_worker(worker: Option<JoinHandle<()>>) {
+    if let Some(worker) = worker {
+        if worker.join().is_err() {
+            log::error!("连点线程异常退出");
+        }
+    }
+}
+
+/// 连点线程参数
+struct WorkerJob {
+    backend_config: BackendConfig,
+    interval: Duration,
+    target_count: Option<u32>,
+    mouse_button: MouseButton,
+    click_target: Option<ClickTarget>,
+    /// 启动时的显示器布局，用于换算相对显示器的点击位置
+    monitors: MonitorLayout,
+    click_delivery: ClickDelivery,
+    pixel_condition: PixelCondition,
+    template: TemplateSettings,
+    target_window: TargetWindowSettings,
+    failsafe: FailSafeSettings,
+    /// 脚本源码，设置后按脚本执行
+    script: Option<String>,
+    /// 操作序列，设置后每轮按顺序执行
+    sequence: Option<Vec<Action>>,
+    /// 连发设置，设置后按连发节奏点击，不再使用 `interval`
+    burst: Option<BurstSettings>,
+    burst_progress: Arc<BurstProgress>,
+    /// 模拟运行
+    dry_run: bool,
+    /// 计时和等待使用的时钟
+    clock: Arc<dyn Clock>,
+    /// 快进模拟的截止时刻
+    time_limit: Option<Instant>,
+    is_running: RunFlag,
+    click_count: Arc<AtomicU32>,
+    events: EventBus,
+}
+
+/// 连点线程主循环
+fn run_worker(job: WorkerJob) {
+    let WorkerJob {
+        backend_config,
+        interval,
+        target_count,
+        mouse_button,
+        click_target,
+        monitors,
+        click_delivery,
+        pixel_condition,
+        template,
+        target_window,
+        failsafe,
+        script,
+        sequence,
+        burst,
+        burst_progress,
+        dry_run,
+        clock,
+        time_limit,
+        is_running,
+        click_count,
+        events,
+    } = job;
+
+    let mouse = if dry_run {
+        Ok(MouseController::dry_run(
+            &backend_config,
+            events.clone(),
+            clock.clone(),
+        ))
+    } else {
+        MouseController::new(&backend_config)
+    };
+    let mut mouse = match mouse {
+        Ok(m) => m,
+        Err(e) => {
+            let kind = ErrorKind::Backend(e);
+            events.emit(ClickerEvent::Error(kind.clone()));
+            is_running.clear(StopReason::Error(kind));
+            return;
+        }
+    };
+
+    let window_filter = match WindowFilter::new(&target_window, backend_config.display.as_deref()) {
+        Ok(filter) => filter,
+        Err(e) => {
+            let kind = ErrorKind::Window(e);
+            events.emit(ClickerEvent::Error(kind.clone()));
+            is_running.clear(StopReason::Error(kind));
+            return;
+        }
+    };
+
+    // 模拟运行不移动真实指针，不需要紧急停止
+    let failsafe = if dry_run {
+        None
+    } else {
+        match FailSafe::new(&failsafe, &mouse, &monitors) {
+            Ok(failsafe) => failsafe,
+            Err(e) => {
+                let kind = ErrorKind::FailSafe(e);
+                events.emit(ClickerEvent::Error(kind.clone()));
+                is_running.clear(StopReason::Error(kind));
+                return;
+            }
+        }
+    };
+
+    if script.is_some() || sequence.is_some() {
+        let mut host = WorkerHost {
+            mouse,
+            screen: None,
+            display: backend_config.display,
+            failsafe,
+            window_filter,
+            paused: false,
+            mouse_button,
+            target_count,
+            start_time: clock.now(),
+            time_limit,
+            clock,
+            is_running: is_running.clone(),
+            click_count,
+            events: events.clone(),
+        };
+        let result = match script {
+            Some(source) => script::run(&source, host)
+                .map(|_| StopReason::ScriptFinished)
+                .map_err(ErrorKind::Script),
+            // 序列只会因停止或出错而结束
+            None => run_sequence(&mut host, &sequence.unwrap_or_default(), interval)
+                .map(|_| StopReason::User)
+                .map_err(ErrorKind::Click),
+        };
+        match result {
+            // 停止后以错误结束，停止原因已经记录
+            _ if !is_running.is_set() => {}
+            Ok(reason) => is_running.clear(reason),
+            Err(kind) => {
+                events.emit(ClickerEvent::Error(kind.clone()));
+                is_running.clear(StopReason::Error(kind));
+            }
+        }
+        return;
+    }
+
+    let mut watcher = match PixelWatcher::new(&pixel_condition, backend_config.display.as_deref()) {
+        Ok(watcher) => watcher,
+        Err(e) => {
+            let kind = ErrorKind::Screen(e);
+            events.emit(ClickerEvent::Error(kind.clone()));
+            is_running.clear(StopReason::Error(kind));
+            return;
+        }
+    };
+
+    let locator = match TemplateLocator::new(&template, backend_config.display.as_deref()) {
+        Ok(locator) => locator,
+        Err(e) => {
+            let kind = ErrorKind::Template(e);
+            events.emit(ClickerEvent::Error(kind.clone()));
+            is_running.clear(StopReason::Error(kind));
+            return;
+        }
+    };
+
+    // 后台点击和相对窗口的点击位置需要按目标窗口的规则查找窗口
+    let needs_window = click_delivery == ClickDelivery::Window
+        || click_target
+            .as_ref()
+            .is_some_and(|target| target.needs_window());
+    let window_finder = if needs_window {
+        match WindowFinder::new(&target_window, backend_config.display.as_deref()) {
+            Ok(finder) => Some(finder),
+            Err(e) => {
+                let kind = ErrorKind::Window(e);
+                events.emit(ClickerEvent::Error(kind.clone()));
+                is_running.clear(StopReason::Error(kind));
+                return;
+            }
+        }
+    } else {
+        None
+    };
+
+    let mut failsafe = failsafe;
+    let mut paused = false;
+
+    // 连发时以组内间隔作为点击间隔，一组结束后另行休息
+    let interval = burst.as_ref().map_or(interval, |burst| {
+        Duration::from_millis(burst.inner_interval_ms)
+    });
+
+    // 首次启动时等待一个间隔时间再开始点击
+    log::debug!("连点器启动，等待 {:?} 后开始第一次点击", interval);
+    let mut waited = wait_interval(
+        interval,
+        &is_running,
+        &mouse,
+        failsafe.as_ref(),
+        clock.as_ref(),
+    );
+
+    while waited.is_ok() && is_running.is_set() {
+        let current_count = click_count.load(Ordering::Relaxed);
+
+        if time_limit.is_some_and(|limit| clock.now() >= limit) {
+            is_running.clear(StopReason::SimulationLimit);
+            break;
+        }
+
+        // 检查是否达到目标点击次数
+        if let Some(target) = target_count {
+            if current_count >= target {
+                is_running.clear(StopReason::TargetReached);
+                break;
+            }
+        }
+
+        // 目标窗口不在前台时跳过点击
+        if let Some(filter) = &window_filter {
+            match filter.is_focused() {
+                Ok(true) => {}
+                Ok(false) => {
+                    if !paused {
+                        paused = true;
+                        events.emit(ClickerEvent::Paused(PauseReason::WindowNotFocused));
+                    }
+                    waited = wait_interval(
+                        interval.min(CONDITION_POLL_INTERVAL),
+                        &is_running,
+                        &mouse,
+                        failsafe.as_ref(),
+                        clock.as_ref(),
+                    );
+                    continue;
+                }
                 Err(e) => {
-                    log::error!("创建鼠标控制器失败: {}", e);
-                    return;
+                    let kind = ErrorKind::Window(e);
+                    events.emit(ClickerEvent::Error(kind.clone()));
+                    is_running.clear(StopReason::Error(kind));
+                    break;
                 }
-            };
-
-            // 首次启动时等待一个间隔时间再开始点击
-            log::debug!("连点器启动，等待 {}ms 后开始第一次点击", interval);
-            thread::sleep(Duration::from_millis(interval));
+            }
+        }
 
-            while is_running.load(Ordering::Relaxed) {
-                let current_count = click_count.load(Ordering::Relaxed);
+        // 检查像素颜色条件
+        if let Some(pixel_watcher) = &watcher {
+            let matched = match pixel_watcher.matches() {
+                Ok(matched) => matched,
+                Err(e) => {
+                    let kind = ErrorKind::Screen(e);
+                    events.emit(ClickerEvent::Error(kind.clone()));
+                    is_running.clear(StopReason::Error(kind));
+                    break;
+                }
+            };
+            match (pixel_watcher.mode(), matched) {
+                // 开始点击后不再检查
+                (ConditionMode::WaitForMatch, true) => watcher = None,
+                (ConditionMode::StopOnMismatch, false) => {
+                    is_running.clear(StopReason::ColorMismatch);
+                    break;
+                }
+                (_, false) => {
+                    // 只在首次不匹配时通知，下一次成功点击时恢复
+                    if !paused {
+                        paused = true;
+                        events.emit(ClickerEvent::Paused(PauseReason::WaitingForColor));
+                    }
+                    waited = wait_interval(
+                        interval.min(CONDITION_POLL_INTERVAL),
+                        &is_running,
+                        &mouse,
+                        failsafe.as_ref(),
+                        clock.as_ref(),
+                    );
+                    continue;
+                }
+                (_, true) => {}
+            }
+        }
 
-                // 检查是否达到目标点击次数
-                if let Some(target) = target_count {
-                    if current_count >= target {
-                        is_running.store(false, Ordering::Relaxed);
-                        break;
+        // 查找目标窗口及其客户区，窗口不存在时等待其出现
+        let mut window = None;
+        if let Some(finder) = &window_finder {
+            let found = finder.find().and_then(|found| match found {
+                Some(found) => Ok(Some((found.id, finder.client_area(found.id)?))),
+                None => Ok(None),
+            });
+            match found {
+                Ok(Some(found)) => window = Some(found),
+                Ok(None) => {
+                    if !paused {
+                        paused = true;
+                        events.emit(ClickerEvent::Paused(PauseReason::WindowNotFound));
                     }
+                    waited = wait_interval(
+                        interval.min(CONDITION_POLL_INTERVAL),
+                        &is_running,
+                        &mouse,
+                        failsafe.as_ref(),
+                        clock.as_ref(),
+                    );
+                    continue;
                 }
+                Err(e) => {
+                    let kind = ErrorKind::Window(e);
+                    events.emit(ClickerEvent::Error(kind.clone()));
+                    is_running.clear(StopReason::Error(kind));
+                    break;
+                }
+            }
+        }
 
-                // 执行点击
-                match mouse.click(mouse_button) {
-                    Ok(_) => {
-                        // 只有在点击成功时才增加计数器
-                        // u32 溢出时会自动回绕到0
-                        click_count.fetch_add(1, Ordering::Relaxed);
-                        log::debug!(
-                            "执行点击: {:?}, 当前计数: {}",
-                            mouse_button,
-                            current_count + 1
+        // 按窗口当前的位置换算点击位置，查找模板图像时以模板位置为准
+        let area = window.map(|(_, area)| area);
+        let mut target = match &click_target {
+            Some(click_target) => match monitors.resolve(click_target, area) {
+                Some(point) => Some(point),
+                None => {
+                    let kind =
+                        ErrorKind::Screen(t!("monitor.error.unresolved", point = click_target));
+                    events.emit(ClickerEvent::Error(kind.clone()));
+                    is_running.clear(StopReason::Error(kind));
+                    break;
+                }
+            },
+            None => None,
+        };
+        if let Some(locator) = &locator {
+            let deadline = clock.now() + locator.timeout();
+            target = loop {
+                match locator.locate() {
+                    Ok(Some(point)) => break Some(point),
+                    Ok(None) if clock.now() < deadline => {
+                        waited = wait_interval(
+                            TEMPLATE_RETRY_INTERVAL,
+                            &is_running,
+                            &mouse,
+                            failsafe.as_ref(),
+                            clock.as_ref(),
                         );
+                        if waited.is_err() || !is_running.is_set() {
+                            break None;
+                        }
+                    }
+                    Ok(None) => {
+                        is_running.clear(StopReason::TemplateNotFound);
+                        break None;
                     }
                     Err(e) => {
-                        log::error!("点击操作失败: {}", e);
-                        // 如果是权限问题，继续尝试而不是停止
-                        if e.contains("权限")
-                            || e.contains("permission")
-                            || e.contains("accessibility")
-                        {
-                            log::warn!("检测到权限问题，请在系统设置中授予辅助功能权限");
-                            // 继续运行，但不增加计数器
-                        } else {
-                            // 其他错误则停止运行
-                            is_running.store(false, Ordering::Relaxed);
+                        let kind = ErrorKind::Template(e);
+                        events.emit(ClickerEvent::Error(kind.clone()));
+                        is_running.clear(StopReason::Error(kind));
+                        break None;
+                    }
+                }
+            };
+            if target.is_none() {
+                break;
+            }
+        }
+
+        // 执行点击，成功时得到点击位置的屏幕坐标
+        let result = match (click_delivery, window, target) {
+            // 后台点击使用窗口内坐标
+            (ClickDelivery::Window, Some((window, area)), target) => {
+                let position = target.map(|point| ClickPoint {
+                    x: point.x - area.x,
+                    y: point.y - area.y,
+                });
+                mouse.click_window(window, position, mouse_button).map(Some)
+            }
+            // 移动到点击位置后执行点击
+            (_, _, Some(point)) => mouse.move_to(point.x, point.y).and_then(|_| {
+                if let Some(failsafe) = &mut failsafe {
+                    failsafe.set_anchor(point.x, point.y);
+                }
+                mouse.click(mouse_button).map(|_| Some((point.x, point.y)))
+            }),
+            (_, _, None) => mouse.click(mouse_button).map(|_| mouse.location().ok()),
+        };
+        let mut rest = None;
+        match result {
+            Ok(position) => {
+                if std::mem::take(&mut paused) {
+                    events.emit(ClickerEvent::Resumed);
+                }
+                // 只有在点击成功时才增加计数器
+                // u32 溢出时会自动回绕到0
+                let count = click_count.fetch_add(1, Ordering::Relaxed).wrapping_add(1);
+                events.emit(ClickerEvent::Clicked {
+                    count,
+                    button: mouse_button,
+                    position,
+                    at: clock.now(),
+                });
+
+                if let Some(burst) = &burst {
+                    match burst_progress.record_click(burst) {
+                        BurstStep::Next => {}
+                        BurstStep::Rest => rest = Some(Duration::from_millis(burst.rest_ms)),
+                        BurstStep::Finished => {
+                            is_running.clear(StopReason::BurstsCompleted);
                             break;
                         }
                     }
                 }
+            }
+            // 权限问题可能在授权后恢复，继续尝试而不是停止
+            Err(InputError::Permission(e)) => {
+                events.emit(ClickerEvent::ClickFailed);
+                // 继续运行，但不增加计数器；只在首次失败时通知
+                if !paused {
+                    paused = true;
+                    events.emit(ClickerEvent::Error(ErrorKind::Permission(e)));
+                    events.emit(ClickerEvent::Paused(PauseReason::PermissionDenied));
+                }
+            }
+            // 其他错误则停止运行
+            Err(InputError::Failed(e)) => {
+                events.emit(ClickerEvent::ClickFailed);
+                let kind = ErrorKind::Click(e);
+                events.emit(ClickerEvent::Error(kind.clone()));
+                is_running.clear(StopReason::Error(kind));
+                break;
+            }
+        }
+
+        // 等待间隔时间，连发时一组结束后休息，休息期间状态仍显示刚完成的一组
+        waited = wait_interval(
+            rest.unwrap_or(interval),
+            &is_running,
+            &mouse,
+            failsafe.as_ref(),
+            clock.as_ref(),
+        );
+        // 休息被停止或紧急停止打断时不再开始下一组
+        if rest.is_some() && waited.is_ok() && is_running.is_set() {
+            burst_progress.next_burst();
+        }
+    }
+
+    if let Err(trigger) = waited {
+        mouse.release_all();
+        is_running.clear(StopReason::FailSafe(trigger));
+    }
+}
+
+/// 循环执行操作序列，每轮之间等待点击间隔，直到连点器停止
+fn run_sequence(host: &mut WorkerHost, steps: &[Action], interval: Duration) -> Result<(), String> {
+    let events = host.events.clone();
+    while host.is_running() {
+        actions::run_steps(host, steps, |index| {
+            events.emit(ClickerEvent::StepStarted(index))
+        })?;
+        host.sleep(interval)?;
+    }
+    Ok(())
+}
+
+/// 连点线程中脚本和操作序列调用的操作
+struct WorkerHost {
+    mouse: MouseController,
+    /// 读取像素的屏幕读取器，首次读取时创建
+    screen: Option<ScreenReader>,
+    display: Option<String>,
+    failsafe: Option<FailSafe>,
+    /// 目标窗口不在前台时暂停输入
+    window_filter: Option<WindowFilter>,
+    /// 是否因目标窗口不在前台而暂停
+    paused: bool,
+    mouse_button: MouseButton,
+    target_count: Option<u32>,
+    start_time: Instant,
+    /// 快进模拟的截止时刻
+    time_limit: Option<Instant>,
+    clock: Arc<dyn Clock>,
+    is_running: RunFlag,
+    click_count: Arc<AtomicU32>,
+    events: EventBus,
+}
+
+impl WorkerHost {
+    /// 目标窗口不在前台时暂停，直到窗口回到前台或连点器停止
+    fn wait_for_window(&mut self) -> Result<(), String> {
+        loop {
+            let focused = match self.window_filter.as_ref().map(WindowFilter::is_focused) {
+                None | Some(Ok(true)) => true,
+                Some(Ok(false)) => false,
+                Some(Err(e)) => {
+                    let kind = ErrorKind::Window(e);
+                    self.events.emit(ClickerEvent::Error(kind.clone()));
+                    self.is_running.clear(StopReason::Error(kind));
+                    return Err(t!("script.error.stopped").to_string());
+                }
+            };
+            if focused {
+                if std::mem::take(&mut self.paused) {
+                    self.events.emit(ClickerEvent::Resumed);
+                }
+                return Ok(());
+            }
+            if !self.paused {
+                self.paused = true;
+                self.events
+                    .emit(ClickerEvent::Paused(PauseReason::WindowNotFocused));
+            }
+            self.sleep(CONDITION_POLL_INTERVAL)?;
+        }
+    }
 
-                // 等待间隔时间
-                thread::sleep(Duration::from_millis(interval));
+    /// 移动指针，并以新位置作为紧急停止的参照
+    fn move_anchored(&mut self, point: ClickPoint) -> Result<(), InputError> {
+        self.mouse.move_to(point.x, point.y)?;
+        if let Some(failsafe) = &mut self.failsafe {
+            failsafe.set_anchor(point.x, point.y);
+        }
+        Ok(())
+    }
+}
+
+impl ActionHost for WorkerHost {
+    fn click(
+        &mut self,
+        button: Option<MouseButton>,
+        point: Option<ClickPoint>,
+    ) -> Result<(), String> {
+        self.wait_for_window()?;
+        let button = button.unwrap_or(self.mouse_button);
+        let result = match point {
+            Some(point) => self
+                .move_anchored(point)
+                .and_then(|_| self.mouse.click(button))
+                .map(|_| Some((point.x, point.y))),
+            None => self.mouse.click(button).map(|_| self.mouse.location().ok()),
+        };
+        let position = match result {
+            Ok(position) => position,
+            Err(e) => {
+                self.events.emit(ClickerEvent::ClickFailed);
+                return Err(e.into());
             }
+        };
+
+        let count = self
+            .click_count
+            .fetch_add(1, Ordering::Relaxed)
+            .wrapping_add(1);
+        self.events.emit(ClickerEvent::Clicked {
+            count,
+            button,
+            position,
+            at: self.clock.now(),
         });
+        // 达到目标次数后脚本在下一步操作前终止
+        if self.target_count.is_some_and(|target| count >= target) {
+            self.is_running.clear(StopReason::TargetReached);
+        }
+        Ok(())
+    }
 
-        log::info!("连点器已启动");
+    fn move_to(&mut self, point: ClickPoint) -> Result<(), String> {
+        self.wait_for_window()?;
+        self.move_anchored(point)?;
         Ok(())
     }
 
-    /// 停止连点器
-    pub fn stop(&mut self) {
-        self.is_running.store(false, Ordering::Relaxed);
-        self.start_time = None;
-        log::info!("连点器已停止");
+    fn scroll(&mut self, length: i32) -> Result<(), String> {
+        self.wait_for_window()?;
+        self.mouse.scroll(length)?;
+        Ok(())
     }
 
-    /// 检查热键是否被按下
-    pub fn check_hotkey_pressed(&self) -> bool {
-        self.hotkey_manager.check_hotkey_pressed()
+    fn key(&mut self, key: enigo::Key, direction: enigo::Direction) -> Result<(), String> {
+        self.wait_for_window()?;
+        self.mouse.key(key, direction)?;
+        Ok(())
     }
 
-    /// 更新设置
-    pub fn update_settings(&mut self, new_settings: AppSettings) -> Result<(), String> {
-        // 如果热键改变了，重新注册
-        if self.settings.hotkey != new_settings.hotkey {
-            self.hotkey_manager.set_hotkey(new_settings.hotkey)?;
-        }
+    fn type_text(&mut self, text: &str) -> Result<(), String> {
+        self.wait_for_window()?;
+        self.mouse.type_text(text)?;
+        Ok(())
+    }
 
-        self.settings = new_settings;
-        log::info!("连点器设置已更新");
+    fn drag(&mut self, from: ClickPoint, to: ClickPoint) -> Result<(), String> {
+        self.wait_for_window()?;
+        self.mouse.drag(from, to)?;
+        if let Some(failsafe) = &mut self.failsafe {
+            failsafe.set_anchor(to.x, to.y);
+        }
         Ok(())
     }
 
-    /// 获取当前状态
-    pub fn get_status(&self) -> ClickerStatus {
-        let state = if self.is_running.load(Ordering::Relaxed) {
-            ClickerState::Running
+    fn sleep(&mut self, duration: Duration) -> Result<(), String> {
+        if let Err(trigger) = wait_interval(
+            duration,
+            &self.is_running,
+            &self.mouse,
+            self.failsafe.as_ref(),
+            self.clock.as_ref(),
+        ) {
+            self.mouse.release_all();
+            self.is_running.clear(StopReason::FailSafe(trigger));
+        }
+        if self
+            .time_limit
+            .is_some_and(|limit| self.clock.now() >= limit)
+        {
+            self.is_running.clear(StopReason::SimulationLimit);
+        }
+        if self.is_running.is_set() {
+            Ok(())
         } else {
-            ClickerState::Stopped
-        };
+            Err(t!("script.error.stopped").to_string())
+        }
+    }
 
-        let runtime_seconds = self
-            .start_time
-            .map(|start| start.elapsed().as_secs())
-            .unwrap_or(0);
+    fn pixel(&mut self, point: ClickPoint) -> Result<[u8; 3], String> {
+        if self.screen.is_none() {
+            self.screen = Some(ScreenReader::new(self.display.as_deref())?);
+        }
+        let screen = self.screen.as_ref().expect("屏幕读取器已创建");
+        screen
+            .capture(point.x, point.y, 1, 1)?
+            .pixel(0, 0)
+            .ok_or_else(|| t!("script.error.off_screen", point = point))
+    }
 
+    fn cursor(&mut self) -> Result<ClickPoint, String> {
+        let (x, y) = self.mouse.location()?;
+        Ok(ClickPoint { x, y })
+    }
+
+    fn status(&self) -> ClickerStatus {
         ClickerStatus {
-            state,
+            state: if self.is_running.is_set() {
+                ClickerState::Running
+            } else {
+                ClickerState::Stopped
+            },
             click_count: self.click_count.load(Ordering::Relaxed),
-            target_count: self.settings.click_count,
-            runtime_seconds,
+            target_count: self.target_count,
+            runtime_seconds: self
+                .clock
+                .now()
+                .saturating_duration_since(self.start_time)
+                .as_secs(),
+            last_stop_reason: self.is_running.stop_reason(),
+            // 脚本和操作序列不使用连发
+            current_burst: None,
+            clicks_in_burst: 0,
+            clicks_per_burst: 0,
+            bursts: None,
         }
     }
 
-    /// 切换运行状态（用于热键控制）
-    pub fn toggle(&mut self) -> Result<(), String> {
-        if self.is_running.load(Ordering::Relaxed) {
-            self.stop();
+    fn is_running(&self) -> bool {
+        self.is_running.is_set()
+    }
+
+    fn log(&mut self, message: String) {
+        self.events.emit(ClickerEvent::ScriptLog(message));
+    }
+}
+
+impl Drop for ClickerManager {
+    fn drop(&mut self) {
+        self.handle.stop_with(StopReason::Exit);
+    }
+}
+
+/// 等待指定时长，停止时立即返回
+///
+/// 启用紧急停止时分段等待并检查指针位置，触发时返回触发原因
+fn wait_interval(
+    duration: Duration,
+    is_running: &RunFlag,
+    mouse: &MouseController,
+    failsafe: Option<&FailSafe>,
+    clock: &dyn Clock,
+) -> Result<(), FailSafeTrigger> {
+    let deadline = clock.now() + duration;
+    loop {
+        if let Some(trigger) = failsafe.and_then(|f| f.check(mouse)) {
+            return Err(trigger);
+        }
+
+        let remaining = deadline.saturating_duration_since(clock.now());
+        if remaining.is_zero() {
+            return Ok(());
+        }
+        let slice = if failsafe.is_some() {
+            POLL_INTERVAL.min(remaining)
         } else {
-            self.start()?;
+            remaining
+        };
+        if !is_running.wait(slice, clock) {
+            return Ok(());
         }
-        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Action;
+    use crate::core::backend::dry_run::SimulatedInput;
+
+    /// 创建使用指定时钟、只模拟输入的连点器
+    fn dry_run_handle(settings: AppSettings, clock: Arc<dyn Clock>) -> ClickerHandle {
+        let handle = ClickerHandle::new(settings, EventBus::default(), clock);
+        handle.lock().dry_run = true;
+        handle
+    }
+
+    /// 每秒点击一次的设置
+    fn settings_with_target(click_count: Option<u32>) -> AppSettings {
+        AppSettings {
+            interval_ms: 1000,
+            click_count,
+            ..AppSettings::default()
+        }
+    }
+
+    /// 接收事件直到本次运行停止，返回其余事件和停止原因
+    fn collect_run(events: &Receiver<ClickerEvent>) -> (Vec<ClickerEvent>, StopReason) {
+        let mut received = Vec::new();
+        loop {
+            match events.recv_timeout(Duration::from_secs(30)) {
+                Ok(ClickerEvent::Stopped(reason)) => return (received, reason),
+                Ok(event) => received.push(event),
+                Err(e) => panic!("运行未按时结束: {}", e),
+            }
+        }
+    }
+
+    #[test]
+    fn manual_clock_simulates_an_hour_long_run() {
+        let clock = Arc::new(ManualClock::default());
+        let handle = dry_run_handle(settings_with_target(Some(3600)), clock.clone());
+        let events = handle.subscribe();
+        let started = clock.now();
+
+        handle.toggle().unwrap();
+        let (received, reason) = collect_run(&events);
+        assert_eq!(reason, StopReason::TargetReached);
+        let clicks = received
+            .iter()
+            .filter(|event| matches!(event, ClickerEvent::Clicked { .. }))
+            .count();
+        assert_eq!(clicks, 3600);
+
+        // 第一次点击前和每次点击后各等待一个间隔
+        let status = handle.get_status();
+        assert_eq!(status.state, ClickerState::Stopped);
+        assert_eq!(status.click_count, 3600);
+        assert_eq!(status.runtime_seconds, 3601);
+        assert_eq!(status.last_stop_reason, Some(StopReason::TargetReached));
+        assert_eq!(clock.now() - started, Duration::from_secs(3601));
+    }
+
+    #[test]
+    fn injected_clock_is_kept_across_runs() {
+        let clock = Arc::new(ManualClock::default());
+        let handle = dry_run_handle(settings_with_target(Some(10)), clock.clone());
+        let events = handle.subscribe();
+        let started = clock.now();
+
+        for _ in 0..2 {
+            handle.toggle().unwrap();
+            assert_eq!(collect_run(&events).1, StopReason::TargetReached);
+            assert_eq!(handle.get_status().runtime_seconds, 11);
+        }
+        assert_eq!(clock.now() - started, Duration::from_secs(22));
+    }
+
+    #[test]
+    fn fast_forward_caps_frequent_events() {
+        let mut settings = settings_with_target(None);
+        settings.interval_ms = 100;
+        let handle = dry_run_handle(settings, Arc::new(SystemClock));
+        handle.lock().fast_forward = true;
+        let events = handle.subscribe();
+
+        handle.toggle().unwrap();
+        let (received, reason) = collect_run(&events);
+        assert_eq!(reason, StopReason::SimulationLimit);
+
+        let frequent = received.iter().filter(|event| event.is_frequent()).count();
+        assert_eq!(frequent, FAST_FORWARD_EVENT_LIMIT);
+        match received.last() {
+            Some(ClickerEvent::EventsDropped(dropped)) => assert!(*dropped > 0),
+            other => panic!("停止前应报告丢弃的事件: {:?}", other),
+        }
+        // 运行时间按快进的虚拟时钟计算
+        assert!(handle.get_status().runtime_seconds >= FAST_FORWARD_LIMIT.as_secs());
+    }
+
+    /// 等待下一个点击事件
+    fn wait_for_click(events: &Receiver<ClickerEvent>) {
+        loop {
+            match events.recv_timeout(Duration::from_secs(30)) {
+                Ok(ClickerEvent::Clicked { .. }) => return,
+                Ok(ClickerEvent::Stopped(reason)) => panic!("运行意外结束: {:?}", reason),
+                Ok(_) => {}
+                Err(e) => panic!("没有收到点击事件: {}", e),
+            }
+        }
+    }
+
+    #[test]
+    fn second_start_while_running_keeps_the_worker() {
+        let handle = dry_run_handle(settings_with_target(None), Arc::new(SystemClock));
+        let events = handle.subscribe();
+
+        handle.toggle().unwrap();
+        wait_for_click(&events);
+        let worker = |handle: &ClickerHandle| {
+            handle
+                .lock()
+                .worker
+                .as_ref()
+                .map(|worker| worker.thread().id())
+        };
+        let first = worker(&handle);
+        assert!(first.is_some());
+
+        handle.lock().start().unwrap();
+        assert_eq!(worker(&handle), first);
+        assert_eq!(handle.get_status().state, ClickerState::Running);
+
+        handle.stop_with(StopReason::User);
+        assert_eq!(collect_run(&events).1, StopReason::User);
+    }
+
+    #[test]
+    fn stop_interrupts_a_long_interval() {
+        // 虚拟时钟的等待立即返回，这里需要真实等待才能验证停止会打断间隔
+        let mut settings = settings_with_target(None);
+        settings.interval_ms = 60 * 60 * 1000;
+        let handle = dry_run_handle(settings, Arc::new(SystemClock));
+        let events = handle.subscribe();
+
+        handle.toggle().unwrap();
+        // 第一次点击前先等待一个间隔，等线程进入等待后再停止
+        std::thread::sleep(Duration::from_millis(100));
+        let stopping = Instant::now();
+        handle.stop_with(StopReason::User);
+        assert!(stopping.elapsed() < Duration::from_secs(5));
+        assert!(handle.lock().worker.is_none());
+
+        let (received, reason) = collect_run(&events);
+        assert_eq!(reason, StopReason::User);
+        assert!(!received
+            .iter()
+            .any(|event| matches!(event, ClickerEvent::Clicked { .. })));
+    }
+
+    #[test]
+    fn stopping_releases_keys_held_by_the_sequence() {
+        let mut settings = settings_with_target(None);
+        settings.sequence.enabled = true;
+        settings.sequence.steps = vec![
+            Action::KeyPress {
+                key: "shift".to_string(),
+            },
+            Action::Wait { ms: 60 * 60 * 1000 },
+        ];
+        let handle = dry_run_handle(settings, Arc::new(SystemClock));
+        let events = handle.subscribe();
+
+        handle.toggle().unwrap();
+        let pressed = SimulatedInput::Key {
+            key: enigo::Key::Shift,
+            direction: enigo::Direction::Press,
+        };
+        loop {
+            match events.recv_timeout(Duration::from_secs(30)) {
+                Ok(ClickerEvent::Simulated { input, .. }) if input == pressed => break,
+                Ok(ClickerEvent::Stopped(reason)) => panic!("运行意外结束: {:?}", reason),
+                Ok(_) => {}
+                Err(e) => panic!("序列没有按下按键: {}", e),
+            }
+        }
+        handle.stop_with(StopReason::User);
+
+        let (received, reason) = collect_run(&events);
+        assert_eq!(reason, StopReason::User);
+        assert!(received.iter().any(|event| matches!(
+            event,
+            ClickerEvent::Simulated {
+                input: SimulatedInput::Key {
+                    key: enigo::Key::Shift,
+                    direction: enigo::Direction::Release,
+                },
+                ..
+            }
+        )));
+    }
+
+    fn burst_settings(clicks_per_burst: u32, bursts: Option<u32>) -> BurstSettings {
+        BurstSettings {
+            enabled: true,
+            clicks_per_burst,
+            bursts,
+            ..BurstSettings::default()
+        }
+    }
+
+    #[test]
+    fn burst_progress_rests_between_bursts_and_finishes() {
+        let settings = burst_settings(2, Some(2));
+        let progress = BurstProgress::default();
+        progress.reset(true);
+
+        assert!(matches!(progress.record_click(&settings), BurstStep::Next));
+        assert_eq!(progress.current(), Some((1, 1)));
+        assert!(matches!(progress.record_click(&settings), BurstStep::Rest));
+        assert_eq!(progress.current(), Some((1, 2)));
+
+        progress.next_burst();
+        assert_eq!(progress.current(), Some((2, 0)));
+        assert!(matches!(progress.record_click(&settings), BurstStep::Next));
+        assert!(matches!(
+            progress.record_click(&settings),
+            BurstStep::Finished
+        ));
+    }
+
+    #[test]
+    fn burst_progress_without_limit_never_finishes() {
+        let settings = burst_settings(1, None);
+        let progress = BurstProgress::default();
+        progress.reset(true);
+
+        for burst in 1..=100 {
+            assert_eq!(progress.current(), Some((burst, 0)));
+            assert!(matches!(progress.record_click(&settings), BurstStep::Rest));
+            progress.next_burst();
+        }
+    }
+
+    #[test]
+    fn burst_progress_inactive_reports_nothing() {
+        let progress = BurstProgress::default();
+        progress.reset(false);
+        assert_eq!(progress.current(), None);
     }
 }
@@ -0,0 +1,81 @@
+//! 时钟模块
+//!
+//! 连点器通过时钟读取时间和等待。平时使用系统时钟；快进模拟时使用手动推进的
+//! 虚拟时钟，等待不占用真实时间，一小时的任务几毫秒即可模拟完
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// 时钟
+pub trait Clock: Send + Sync {
+    /// 当前时刻
+    fn now(&self) -> Instant;
+
+    /// 等待指定时长
+    fn sleep(&self, duration: Duration);
+
+    /// 是否为虚拟时钟
+    ///
+    /// 虚拟时钟的等待立即返回，调用者不应再用条件变量等真实时间的方式等待
+    fn is_virtual(&self) -> bool;
+}
+
+/// 系统时钟
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        std::thread::sleep(duration);
+    }
+
+    fn is_virtual(&self) -> bool {
+        false
+    }
+}
+
+/// 手动推进的虚拟时钟
+///
+/// 时间只在调用 [`advance`](Self::advance) 或 [`sleep`](Clock::sleep) 时前进
+#[derive(Debug)]
+pub struct ManualClock {
+    /// 创建时的真实时刻，虚拟时刻从这里开始计算
+    origin: Instant,
+    /// 已推进的时长
+    offset: Mutex<Duration>,
+}
+
+impl Default for ManualClock {
+    fn default() -> Self {
+        Self {
+            origin: Instant::now(),
+            offset: Mutex::new(Duration::ZERO),
+        }
+    }
+}
+
+impl ManualClock {
+    /// 把时间向前推进 `duration`
+    pub fn advance(&self, duration: Duration) {
+        let mut offset = self.offset.lock().unwrap_or_else(|e| e.into_inner());
+        *offset += duration;
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Instant {
+        self.origin + *self.offset.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn sleep(&self, duration: Duration) {
+        self.advance(duration);
+    }
+
+    fn is_virtual(&self) -> bool {
+        true
+    }
+}
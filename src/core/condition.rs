@@ -0,0 +1,124 @@
+//! 点击条件模块
+//!
+//! 在连点循环中读取屏幕像素，根据颜色决定点击、等待还是停止
+
+use crate::config::{ConditionMode, PixelCondition};
+use crate::core::screen::ScreenReader;
+use crate::utils::Result;
+
+/// 像素颜色监视器
+pub struct PixelWatcher {
+    reader: ScreenReader,
+    condition: PixelCondition,
+}
+
+impl PixelWatcher {
+    /// 创建监视器，条件未启用时返回None
+    pub fn new(condition: &PixelCondition, display: Option<&str>) -> Result<Option<Self>> {
+        if !condition.enabled {
+            return Ok(None);
+        }
+        Ok(Some(Self {
+            reader: ScreenReader::new(display)?,
+            condition: *condition,
+        }))
+    }
+
+    /// 条件的作用方式
+    pub fn mode(&self) -> ConditionMode {
+        self.condition.mode
+    }
+
+    /// 读取采样区域的平均颜色
+    pub fn sample(&self) -> Result<[u8; 3]> {
+        let radius = self.condition.radius as i32;
+        let size = self.condition.radius * 2 + 1;
+        let image = self.reader.capture(
+            self.condition.point.x - radius,
+            self.condition.point.y - radius,
+            size,
+            size,
+        )?;
+        Ok(average_color(&image.pixels))
+    }
+
+    /// 检查采样颜色是否与目标颜色匹配
+    pub fn matches(&self) -> Result<bool> {
+        let color = self.sample()?;
+        Ok(color_matches(
+            color,
+            self.condition.color,
+            self.condition.tolerance,
+        ))
+    }
+}
+
+/// 计算平均颜色
+fn average_color(pixels: &[[u8; 3]]) -> [u8; 3] {
+    if pixels.is_empty() {
+        return [0; 3];
+    }
+    let mut sum = [0u32; 3];
+    for pixel in pixels {
+        for (total, value) in sum.iter_mut().zip(pixel) {
+            *total += u32::from(*value);
+        }
+    }
+    sum.map(|total| (total / pixels.len() as u32) as u8)
+}
+
+/// 每个颜色分量的差值都不超过容差时视为匹配
+pub fn color_matches(color: [u8; 3], target: [u8; 3], tolerance: u8) -> bool {
+    color
+        .iter()
+        .zip(target)
+        .all(|(a, b)| a.abs_diff(b) <= tolerance)
+}
+
+/// 以 `#RRGGBB` 格式显示颜色
+pub fn format_color([r, g, b]: [u8; 3]) -> String {
+    format!("#{:02X}{:02X}{:02X}", r, g, b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tolerance_is_inclusive_per_channel() {
+        let target = [100, 150, 200];
+        assert!(color_matches(target, target, 0));
+        assert!(color_matches([110, 140, 210], target, 10));
+        assert!(!color_matches([111, 150, 200], target, 10));
+        assert!(!color_matches([100, 150, 189], target, 10));
+    }
+
+    #[test]
+    fn channels_are_not_summed() {
+        // 每个分量都在容差内，即使总差值超过容差
+        assert!(color_matches([5, 5, 5], [0, 0, 0], 5));
+        // 单个分量超出容差即不匹配
+        assert!(!color_matches([0, 0, 6], [0, 0, 0], 5));
+    }
+
+    #[test]
+    fn extreme_values_do_not_overflow() {
+        assert!(color_matches([0, 0, 0], [255, 255, 255], 255));
+        assert!(!color_matches([0, 0, 0], [255, 255, 255], 254));
+    }
+
+    #[test]
+    fn average_color_rounds_down() {
+        assert_eq!(average_color(&[]), [0, 0, 0]);
+        assert_eq!(average_color(&[[10, 20, 30], [11, 21, 31]]), [10, 20, 30]);
+        assert_eq!(
+            average_color(&[[255; 3], [255; 3], [0; 3]]),
+            [170, 170, 170]
+        );
+    }
+
+    #[test]
+    fn colors_are_formatted_as_hex() {
+        assert_eq!(format_color([0x12, 0xAB, 0x00]), "#12AB00");
+    }
+}
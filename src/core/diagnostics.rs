@@ -0,0 +1,421 @@
+//! 启动诊断模块
+//!
+//! 检查显示服务器、输入注入、紧急停止、热键、配置目录和权限，
+//! 为“计数增加但没有实际点击”之类的问题给出可操作的修复建议
+
+use crate::config::{AppSettings, InputBackend, SettingsManager};
+use crate::core::backend::{self, BackendConfig, SessionType};
+use crate::core::failsafe::FailSafe;
+use crate::core::hotkey::HotkeyManager;
+use crate::core::monitor::MonitorLayout;
+use crate::core::mouse::MouseController;
+
+/// 单项检查结果
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckStatus {
+    /// 正常
+    Pass,
+    /// 可能影响使用
+    Warning,
+    /// 无法正常点击
+    Fail,
+}
+
+impl std::fmt::Display for CheckStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let text = match self {
+            Self::Pass => t!("diagnostics.status.pass"),
+            Self::Warning => t!("diagnostics.status.warning"),
+            Self::Fail => t!("diagnostics.status.fail"),
+        };
+        write!(f, "{}", text)
+    }
+}
+
+/// 单项检查
+#[derive(Debug, Clone)]
+pub struct DiagnosticCheck {
+    /// 检查项名称
+    pub name: &'static str,
+    /// 检查结果
+    pub status: CheckStatus,
+    /// 检查详情
+    pub detail: String,
+    /// 修复建议
+    pub fix: Option<String>,
+}
+
+impl DiagnosticCheck {
+    fn pass(name: &'static str, detail: impl Into<String>) -> Self {
+        Self {
+            name,
+            status: CheckStatus::Pass,
+            detail: detail.into(),
+            fix: None,
+        }
+    }
+
+    fn warning(name: &'static str, detail: impl Into<String>, fix: impl Into<String>) -> Self {
+        Self {
+            name,
+            status: CheckStatus::Warning,
+            detail: detail.into(),
+            fix: Some(fix.into()),
+        }
+    }
+
+    fn fail(name: &'static str, detail: impl Into<String>, fix: impl Into<String>) -> Self {
+        Self {
+            name,
+            status: CheckStatus::Fail,
+            detail: detail.into(),
+            fix: Some(fix.into()),
+        }
+    }
+}
+
+/// 热键检查方式
+pub enum HotkeyProbe {
+    /// 临时注册一次配置的热键
+    Register,
+    /// 热键已由运行中的连点器注册，直接使用其结果
+    Registered(Result<(), String>),
+}
+
+/// 诊断报告
+#[derive(Debug, Clone, Default)]
+pub struct DiagnosticReport {
+    pub checks: Vec<DiagnosticCheck>,
+}
+
+impl DiagnosticReport {
+    /// 执行全部检查
+    pub fn run(settings: &AppSettings, hotkey: HotkeyProbe) -> Self {
+        let config = BackendConfig::from_settings(settings);
+        let checks = vec![
+            check_display_server(&config),
+            check_input_injection(&config),
+            check_monitors(settings, &config),
+            check_failsafe(settings, &config),
+            check_hotkey(settings, hotkey),
+            check_config_dir(),
+            check_elevation(),
+        ];
+        Self { checks }
+    }
+
+    /// 是否存在失败项
+    pub fn has_failures(&self) -> bool {
+        self.checks.iter().any(|c| c.status == CheckStatus::Fail)
+    }
+
+    /// 生成纯文本报告
+    pub fn to_text(&self) -> String {
+        let mut text = String::new();
+        for check in &self.checks {
+            text.push_str(&format!(
+                "[{}] {}: {}\n",
+                check.status, check.name, check.detail
+            ));
+            if let Some(fix) = &check.fix {
+                text.push_str(&format!("    {}\n", t!("diagnostics.fix", fix = fix)));
+            }
+        }
+        text
+    }
+}
+
+/// 检查显示服务器类型与后端是否匹配
+fn check_display_server(config: &BackendConfig) -> DiagnosticCheck {
+    let name = t!("diagnostics.display.name");
+
+    if MouseController::is_windows() {
+        return DiagnosticCheck::pass(name, "Windows");
+    }
+    if MouseController::is_macos() {
+        return DiagnosticCheck::pass(name, "macOS");
+    }
+
+    let session = SessionType::detect();
+    let has_x11 = std::env::var_os("DISPLAY").is_some() || config.display.is_some();
+    let has_wayland = std::env::var_os("WAYLAND_DISPLAY").is_some();
+    let resolved = backend::resolve(config.kind);
+
+    match session {
+        SessionType::Wayland if resolved != InputBackend::Uinput => DiagnosticCheck::warning(
+            name,
+            t!("diagnostics.display.wayland_mismatch", backend = resolved),
+            t!("diagnostics.display.wayland_mismatch_fix"),
+        ),
+        _ if !has_x11 && !has_wayland => DiagnosticCheck::fail(
+            name,
+            t!("diagnostics.display.no_display", session = session),
+            t!("diagnostics.display.no_display_fix"),
+        ),
+        _ => DiagnosticCheck::pass(name, t!("diagnostics.display.session", session = session)),
+    }
+}
+
+/// 检查能否创建输入后端
+fn check_input_injection(config: &BackendConfig) -> DiagnosticCheck {
+    let name = t!("diagnostics.injection.name");
+
+    if let Err(e) = backend::check(config) {
+        return DiagnosticCheck::fail(name, e, injection_fix());
+    }
+
+    match MouseController::new(config) {
+        Ok(mouse) => match mouse.location() {
+            Ok((x, y)) => DiagnosticCheck::pass(
+                name,
+                t!(
+                    "diagnostics.injection.ok",
+                    backend = mouse.backend_name(),
+                    x = x,
+                    y = y
+                ),
+            ),
+            Err(_) => DiagnosticCheck::pass(
+                name,
+                t!(
+                    "diagnostics.injection.ok_no_location",
+                    backend = mouse.backend_name()
+                ),
+            ),
+        },
+        Err(e) => DiagnosticCheck::fail(name, e, injection_fix()),
+    }
+}
+
+/// 输入注入失败时的平台相关修复建议
+fn injection_fix() -> &'static str {
+    if MouseController::is_macos() {
+        t!("diagnostics.injection.fix_macos")
+    } else if MouseController::is_windows() {
+        t!("diagnostics.injection.fix_windows")
+    } else {
+        t!("diagnostics.injection.fix_linux")
+    }
+}
+
+/// 列出显示器，并检查保存的坐标是否仍位于已连接的显示器上
+fn check_monitors(settings: &AppSettings, config: &BackendConfig) -> DiagnosticCheck {
+    let name = t!("diagnostics.monitors.name");
+
+    let layout = match MonitorLayout::query(config.display.as_deref()) {
+        Ok(layout) => layout,
+        Err(e) => {
+            return DiagnosticCheck::warning(name, e, t!("diagnostics.monitors.fix_query"));
+        }
+    };
+    let monitors = layout
+        .monitors()
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("; ");
+
+    match layout.check_settings(settings) {
+        Ok(()) => DiagnosticCheck::pass(name, monitors),
+        Err(e) => DiagnosticCheck::fail(
+            name,
+            format!("{}\n{}", monitors, e),
+            t!("diagnostics.monitors.fix_points"),
+        ),
+    }
+}
+
+/// 检查启用的紧急停止能否在当前后端上工作
+fn check_failsafe(settings: &AppSettings, config: &BackendConfig) -> DiagnosticCheck {
+    let name = t!("diagnostics.failsafe.name");
+
+    if !settings.failsafe.enabled {
+        return DiagnosticCheck::pass(name, t!("diagnostics.failsafe.disabled"));
+    }
+    if !backend::can_locate(config.kind) {
+        return DiagnosticCheck::warning(
+            name,
+            t!(
+                "diagnostics.failsafe.no_location",
+                backend = backend::resolve(config.kind)
+            ),
+            t!("diagnostics.failsafe.fix"),
+        );
+    }
+    let mouse = match MouseController::new(config) {
+        Ok(mouse) => mouse,
+        Err(e) => {
+            return DiagnosticCheck::warning(
+                name,
+                t!("diagnostics.failsafe.unchecked", error = e),
+                injection_fix(),
+            );
+        }
+    };
+    let layout = MonitorLayout::query(config.display.as_deref()).unwrap_or_default();
+
+    match FailSafe::new(&settings.failsafe, &mouse, &layout) {
+        Ok(Some(failsafe)) => {
+            let bounds = failsafe.screen();
+            DiagnosticCheck::pass(
+                name,
+                t!(
+                    "diagnostics.failsafe.ok",
+                    bounds = format!(
+                        "{}x{}{:+}{:+}",
+                        bounds.width, bounds.height, bounds.x, bounds.y
+                    )
+                ),
+            )
+        }
+        Ok(None) => DiagnosticCheck::pass(name, t!("diagnostics.failsafe.disabled")),
+        Err(e) => DiagnosticCheck::fail(
+            name,
+            t!("diagnostics.failsafe.unsupported", error = e),
+            t!("diagnostics.failsafe.fix"),
+        ),
+    }
+}
+
+/// 检查热键能否注册
+fn check_hotkey(settings: &AppSettings, probe: HotkeyProbe) -> DiagnosticCheck {
+    let name = t!("diagnostics.hotkey.name");
+
+    let result = match probe {
+        HotkeyProbe::Registered(result) => result,
+        HotkeyProbe::Register => {
+            HotkeyManager::new().and_then(|mut manager| manager.set_hotkey(settings.hotkey))
+        }
+    };
+
+    match result {
+        Ok(()) => {
+            DiagnosticCheck::pass(name, t!("diagnostics.hotkey.ok", hotkey = settings.hotkey))
+        }
+        Err(e) => DiagnosticCheck::fail(name, e, t!("diagnostics.hotkey.fix")),
+    }
+}
+
+/// 检查配置目录是否可写
+fn check_config_dir() -> DiagnosticCheck {
+    let name = t!("diagnostics.config_dir.name");
+
+    let dir = match SettingsManager::config_dir() {
+        Ok(dir) => dir,
+        Err(e) => {
+            return DiagnosticCheck::fail(name, e, t!("diagnostics.config_dir.fix_missing"));
+        }
+    };
+
+    let probe = dir.join(".write-test");
+    match std::fs::write(&probe, b"ok") {
+        Ok(()) => {
+            let _ = std::fs::remove_file(&probe);
+            DiagnosticCheck::pass(name, t!("diagnostics.config_dir.ok", path = dir.display()))
+        }
+        Err(e) => DiagnosticCheck::fail(
+            name,
+            t!(
+                "diagnostics.config_dir.not_writable",
+                path = dir.display(),
+                error = e
+            ),
+            t!("diagnostics.config_dir.fix_permissions"),
+        ),
+    }
+}
+
+/// 检查管理员权限
+fn check_elevation() -> DiagnosticCheck {
+    let name = t!("diagnostics.elevation.name");
+
+    if !MouseController::is_windows() {
+        return DiagnosticCheck::pass(name, t!("diagnostics.elevation.not_needed"));
+    }
+
+    if MouseController::is_admin() {
+        DiagnosticCheck::pass(name, t!("diagnostics.elevation.ok"))
+    } else {
+        DiagnosticCheck::warning(
+            name,
+            t!("diagnostics.elevation.missing"),
+            t!("diagnostics.elevation.fix"),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report(checks: Vec<DiagnosticCheck>) -> DiagnosticReport {
+        DiagnosticReport { checks }
+    }
+
+    #[test]
+    fn only_failed_checks_count_as_failures() {
+        let passing = report(vec![
+            DiagnosticCheck::pass("显示", "X11"),
+            DiagnosticCheck::warning("权限", "未以管理员身份运行", "以管理员身份运行"),
+        ]);
+        assert!(!passing.has_failures());
+
+        let mut failing = passing.clone();
+        failing
+            .checks
+            .push(DiagnosticCheck::fail("热键", "已被占用", "更换热键"));
+        assert!(failing.has_failures());
+        assert!(!DiagnosticReport::default().has_failures());
+    }
+
+    #[test]
+    fn text_report_lists_each_check_with_its_fix() {
+        let text = report(vec![
+            DiagnosticCheck::pass("显示", "X11"),
+            DiagnosticCheck::fail("热键", "已被占用", "更换热键"),
+        ])
+        .to_text();
+
+        let expected = format!(
+            "[{}] 显示: X11\n[{}] 热键: 已被占用\n    {}\n",
+            CheckStatus::Pass,
+            CheckStatus::Fail,
+            t!("diagnostics.fix", fix = "更换热键")
+        );
+        assert_eq!(text, expected);
+    }
+
+    #[test]
+    fn hotkey_check_reuses_the_registered_result() {
+        let settings = AppSettings::default();
+
+        let check = check_hotkey(&settings, HotkeyProbe::Registered(Ok(())));
+        assert_eq!(check.status, CheckStatus::Pass);
+
+        let check = check_hotkey(
+            &settings,
+            HotkeyProbe::Registered(Err("已被占用".to_string())),
+        );
+        assert_eq!(check.status, CheckStatus::Fail);
+        assert_eq!(check.detail, "已被占用");
+        assert_eq!(check.fix.as_deref(), Some(t!("diagnostics.hotkey.fix")));
+    }
+
+    #[test]
+    fn failsafe_check_warns_when_the_backend_cannot_read_the_pointer() {
+        let mut settings = AppSettings::default();
+        let config = BackendConfig {
+            kind: InputBackend::Uinput,
+            ..BackendConfig::default()
+        };
+
+        settings.failsafe.enabled = false;
+        let check = check_failsafe(&settings, &config);
+        assert_eq!(check.status, CheckStatus::Pass);
+
+        settings.failsafe.enabled = true;
+        let check = check_failsafe(&settings, &config);
+        assert_eq!(check.status, CheckStatus::Warning);
+        assert_eq!(check.fix.as_deref(), Some(t!("diagnostics.failsafe.fix")));
+    }
+}
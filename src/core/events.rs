@@ -0,0 +1,344 @@
+//! 连点器事件模块
+//!
+//! 连点器通过事件总线广播状态变化，界面、日志和外部集成各自订阅
+
+use crate::config::{MouseButton, StopKind};
+use crate::core::backend::dry_run::{format_elapsed, SimulatedInput};
+use crate::core::failsafe::FailSafeTrigger;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// 错误类型
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// 无法创建输入后端
+    Backend(String),
+    /// 缺少权限，点击被系统拦截
+    Permission(String),
+    /// 点击操作失败
+    Click(String),
+    /// 无法读取屏幕像素
+    Screen(String),
+    /// 图像模板匹配失败
+    Template(String),
+    /// 无法读取前台窗口
+    Window(String),
+    /// 脚本编译或运行出错
+    Script(String),
+    /// 紧急停止已启用但当前后端无法支持
+    FailSafe(String),
+    /// 连点线程异常退出
+    Panic,
+}
+
+impl std::fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Backend(e) => write!(f, "{}", t!("error.backend", error = e)),
+            Self::Permission(e) => write!(f, "{}", t!("error.permission", error = e)),
+            Self::Click(e) => write!(f, "{}", t!("error.click", error = e)),
+            Self::Screen(e) => write!(f, "{}", t!("error.screen", error = e)),
+            Self::Template(e) => write!(f, "{}", t!("error.template", error = e)),
+            Self::Window(e) => write!(f, "{}", t!("error.window", error = e)),
+            Self::Script(e) => write!(f, "{}", t!("error.script", error = e)),
+            Self::FailSafe(e) => write!(f, "{}", t!("error.failsafe", error = e)),
+            Self::Panic => write!(f, "{}", t!("error.panic")),
+        }
+    }
+}
+
+/// 停止原因
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StopReason {
+    /// 用户通过按钮或热键停止
+    User,
+    /// 达到目标点击次数
+    TargetReached,
+    /// 触发紧急停止
+    FailSafe(FailSafeTrigger),
+    /// 像素颜色不再匹配
+    ColorMismatch,
+    /// 超时仍未找到模板图像
+    TemplateNotFound,
+    /// 脚本执行完毕
+    ScriptFinished,
+    /// 快进模拟达到虚拟时长上限
+    SimulationLimit,
+    /// 连发的所有组均已完成
+    BurstsCompleted,
+    /// 发生错误
+    Error(ErrorKind),
+    /// 应用退出
+    Exit,
+}
+
+impl StopReason {
+    /// 写入历史记录的类别
+    pub fn kind(&self) -> StopKind {
+        match self {
+            Self::User => StopKind::User,
+            Self::TargetReached => StopKind::TargetReached,
+            Self::FailSafe(_) => StopKind::FailSafe,
+            Self::ColorMismatch => StopKind::ColorMismatch,
+            Self::TemplateNotFound => StopKind::TemplateNotFound,
+            Self::ScriptFinished => StopKind::ScriptFinished,
+            Self::SimulationLimit => StopKind::SimulationLimit,
+            Self::BurstsCompleted => StopKind::BurstsCompleted,
+            Self::Error(_) => StopKind::Error,
+            Self::Exit => StopKind::Exit,
+        }
+    }
+}
+
+impl std::fmt::Display for StopReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::User => write!(f, "{}", t!("stop_reason.user")),
+            Self::TargetReached => write!(f, "{}", t!("stop_reason.target_reached")),
+            Self::FailSafe(trigger) => {
+                write!(f, "{}", t!("stop_reason.failsafe", trigger = trigger))
+            }
+            Self::ColorMismatch => write!(f, "{}", t!("stop_reason.color_mismatch")),
+            Self::TemplateNotFound => write!(f, "{}", t!("stop_reason.template_not_found")),
+            Self::ScriptFinished => write!(f, "{}", t!("stop_reason.script_finished")),
+            Self::SimulationLimit => write!(f, "{}", t!("stop_reason.simulation_limit")),
+            Self::BurstsCompleted => write!(f, "{}", t!("stop_reason.bursts_completed")),
+            Self::Error(kind) => write!(f, "{}", kind),
+            Self::Exit => write!(f, "{}", t!("stop_reason.exit")),
+        }
+    }
+}
+
+/// 暂停原因
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PauseReason {
+    /// 点击被系统以权限不足为由拦截，连点器保持运行并持续重试
+    PermissionDenied,
+    /// 像素颜色不匹配，等待颜色出现
+    WaitingForColor,
+    /// 目标窗口不在前台
+    WindowNotFocused,
+    /// 后台点击的目标窗口不存在
+    WindowNotFound,
+}
+
+impl std::fmt::Display for PauseReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::PermissionDenied => write!(f, "{}", t!("pause_reason.permission_denied")),
+            Self::WaitingForColor => write!(f, "{}", t!("pause_reason.waiting_for_color")),
+            Self::WindowNotFocused => write!(f, "{}", t!("pause_reason.window_not_focused")),
+            Self::WindowNotFound => write!(f, "{}", t!("pause_reason.window_not_found")),
+        }
+    }
+}
+
+/// 连点器事件
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ClickerEvent {
+    /// 连点器已启动
+    Started {
+        profile: String,
+        target_count: Option<u32>,
+        /// 是否为模拟运行
+        dry_run: bool,
+    },
+    /// 完成一次点击
+    Clicked {
+        /// 本次运行的累计次数
+        count: u32,
+        button: MouseButton,
+        /// 点击位置的屏幕坐标，后端无法读取指针位置时为None
+        position: Option<(i32, i32)>,
+        /// 点击完成的时刻，取自连点器的时钟
+        at: Instant,
+    },
+    /// 一次点击未能执行
+    ClickFailed,
+    /// 连点器仍在运行，但暂时无法点击
+    Paused(PauseReason),
+    /// 从暂停中恢复
+    Resumed,
+    /// 连点器已停止
+    Stopped(StopReason),
+    /// 发生错误
+    Error(ErrorKind),
+    /// 已切换配置方案
+    ProfileSwitched(String),
+    /// 脚本输出的一行文本
+    ScriptLog(String),
+    /// 开始执行操作序列中的一步，值为步骤序号（从0开始）
+    StepStarted(usize),
+    /// 模拟运行时本应执行的一次输入
+    Simulated {
+        /// 自本次运行开始经过的时间
+        elapsed: Duration,
+        input: SimulatedInput,
+    },
+    /// 超出配额被丢弃的高频事件数量，在停止事件之前发送
+    EventsDropped(usize),
+    /// 无法枚举显示器，启动前未检查保存的坐标，值为原因
+    CoordinatesUnchecked(String),
+}
+
+impl ClickerEvent {
+    /// 是否为每次点击或输入都会产生的高频事件
+    pub fn is_frequent(&self) -> bool {
+        matches!(
+            self,
+            Self::Clicked { .. }
+                | Self::ClickFailed
+                | Self::StepStarted(_)
+                | Self::Simulated { .. }
+        )
+    }
+}
+
+impl std::fmt::Display for ClickerEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Started {
+                profile,
+                target_count,
+                dry_run,
+            } => {
+                let text = match target_count {
+                    Some(target) => t!(
+                        "event.started_with_target",
+                        profile = profile,
+                        target = target
+                    ),
+                    None => t!("event.started", profile = profile),
+                };
+                if *dry_run {
+                    write!(f, "{}", t!("event.dry_run", event = text))
+                } else {
+                    write!(f, "{}", text)
+                }
+            }
+            Self::Clicked { count, .. } => write!(f, "{}", t!("event.clicked", count = count)),
+            Self::ClickFailed => write!(f, "{}", t!("event.click_failed")),
+            Self::Paused(reason) => write!(f, "{}", t!("event.paused", reason = reason)),
+            Self::Resumed => write!(f, "{}", t!("event.resumed")),
+            Self::Stopped(reason) => write!(f, "{}", t!("event.stopped", reason = reason)),
+            Self::Error(kind) => write!(f, "{}", kind),
+            Self::ProfileSwitched(name) => {
+                write!(f, "{}", t!("event.profile_switched", name = name))
+            }
+            Self::ScriptLog(message) => {
+                write!(f, "{}", t!("event.script_log", message = message))
+            }
+            Self::StepStarted(index) => {
+                write!(f, "{}", t!("event.step_started", step = index + 1))
+            }
+            Self::Simulated { elapsed, input } => write!(
+                f,
+                "{}",
+                t!(
+                    "event.simulated",
+                    elapsed = format_elapsed(*elapsed),
+                    input = input
+                )
+            ),
+            Self::EventsDropped(count) => {
+                write!(f, "{}", t!("event.events_dropped", count = count))
+            }
+            Self::CoordinatesUnchecked(reason) => {
+                write!(f, "{}", t!("event.coordinates_unchecked", reason = reason))
+            }
+        }
+    }
+}
+
+/// 事件总线
+///
+/// 每个订阅者拥有独立的通道，订阅者丢弃接收端后自动退订
+#[derive(Clone, Default)]
+pub struct EventBus {
+    subscribers: Arc<Mutex<Vec<Sender<ClickerEvent>>>>,
+    /// 高频事件配额，None表示不限
+    quota: Option<Arc<EventQuota>>,
+}
+
+/// 高频事件配额
+struct EventQuota {
+    /// 剩余可广播的数量
+    remaining: AtomicUsize,
+    /// 超出配额被丢弃的数量
+    dropped: AtomicUsize,
+}
+
+impl EventBus {
+    /// 创建与本总线共享订阅者、但最多广播 `limit` 个高频事件的总线
+    ///
+    /// 快进模拟时连点线程产生事件的速度远超订阅者的处理速度，
+    /// 超出配额的高频事件直接丢弃，其余事件不受影响
+    pub fn with_quota(&self, limit: usize) -> Self {
+        Self {
+            subscribers: self.subscribers.clone(),
+            quota: Some(Arc::new(EventQuota {
+                remaining: AtomicUsize::new(limit),
+                dropped: AtomicUsize::new(0),
+            })),
+        }
+    }
+
+    /// 超出配额被丢弃的高频事件数量
+    pub fn dropped(&self) -> usize {
+        self.quota
+            .as_ref()
+            .map_or(0, |quota| quota.dropped.load(Ordering::Relaxed))
+    }
+
+    /// 订阅事件
+    pub fn subscribe(&self) -> Receiver<ClickerEvent> {
+        let (sender, receiver) = mpsc::channel();
+        self.subscribers
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push(sender);
+        receiver
+    }
+
+    /// 广播事件
+    pub fn emit(&self, event: ClickerEvent) {
+        if let Some(quota) = self.quota.as_ref().filter(|_| event.is_frequent()) {
+            let taken = quota
+                .remaining
+                .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |n| n.checked_sub(1))
+                .is_ok();
+            if !taken {
+                quota.dropped.fetch_add(1, Ordering::Relaxed);
+                return;
+            }
+        }
+        self.subscribers
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .retain(|sender| sender.send(event.clone()).is_ok());
+    }
+}
+
+/// 启动日志订阅者，把事件写入日志
+///
+/// 事件总线的所有发送端被丢弃后线程自动退出
+pub fn spawn_logger(bus: &EventBus) {
+    let receiver = bus.subscribe();
+    thread::spawn(move || {
+        for event in receiver {
+            match &event {
+                ClickerEvent::Clicked { .. }
+                | ClickerEvent::ClickFailed
+                | ClickerEvent::StepStarted(_) => {
+                    log::debug!("{}", event)
+                }
+                ClickerEvent::Paused(_) => log::warn!("{}", event),
+                ClickerEvent::Error(_) => log::error!("{}", event),
+                _ => log::info!("{}", event),
+            }
+        }
+    });
+}
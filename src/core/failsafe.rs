@@ -0,0 +1,225 @@
+//! 紧急停止模块
+//!
+//! 热键失效时，通过把指针甩到屏幕角落或手动移动鼠标来中止连点
+
+use crate::config::{FailSafeSettings, ScreenCorner, ScreenRegion};
+use crate::core::monitor::MonitorLayout;
+use crate::core::mouse::MouseController;
+use crate::utils::Result;
+
+/// 紧急停止的触发原因
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FailSafeTrigger {
+    /// 指针进入了配置的屏幕角落
+    Corner(ScreenCorner),
+    /// 用户手动移动了指针
+    ManualMove { distance: u32 },
+}
+
+impl std::fmt::Display for FailSafeTrigger {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Corner(corner) => write!(f, "{}", t!("failsafe.corner", corner = corner)),
+            Self::ManualMove { distance } => {
+                write!(f, "{}", t!("failsafe.manual_move", distance = distance))
+            }
+        }
+    }
+}
+
+/// 紧急停止检测器
+pub struct FailSafe {
+    settings: FailSafeSettings,
+    /// 虚拟屏幕范围，角落按其四角判断
+    screen: ScreenRegion,
+    /// 连点器留下指针的位置
+    anchor: (i32, i32),
+}
+
+impl FailSafe {
+    /// 创建检测器，未启用时返回None
+    ///
+    /// 屏幕范围取所有显示器的外接矩形，无法枚举显示器时使用后端报告的主屏幕尺寸，
+    /// 读取不到屏幕尺寸时返回错误。后端无法读取指针位置时（如uinput）记录警告并返回None
+    pub fn new(
+        settings: &FailSafeSettings,
+        mouse: &MouseController,
+        monitors: &MonitorLayout,
+    ) -> Result<Option<Self>> {
+        if !settings.enabled || (settings.corner.is_none() && settings.max_drift.is_none()) {
+            return Ok(None);
+        }
+
+        let screen = match monitors.bounds() {
+            Some(bounds) => bounds,
+            None => {
+                let (width, height) = mouse.display_size()?;
+                ScreenRegion {
+                    x: 0,
+                    y: 0,
+                    width: width.max(0) as u32,
+                    height: height.max(0) as u32,
+                }
+            }
+        };
+        let anchor = match mouse.location() {
+            Ok(anchor) => anchor,
+            Err(e) => {
+                log::warn!("无法读取指针位置，紧急停止不生效: {}", e);
+                return Ok(None);
+            }
+        };
+
+        Ok(Some(Self {
+            settings: settings.clone(),
+            screen,
+            anchor,
+        }))
+    }
+
+    /// 检测的屏幕范围
+    pub fn screen(&self) -> ScreenRegion {
+        self.screen
+    }
+
+    /// 连点器主动移动指针后更新基准位置，避免被当作手动移动
+    pub fn set_anchor(&mut self, x: i32, y: i32) {
+        self.anchor = (x, y);
+    }
+
+    /// 检查是否需要紧急停止
+    pub fn check(&self, mouse: &MouseController) -> Option<FailSafeTrigger> {
+        let (x, y) = mouse.location().ok()?;
+
+        if let Some(corner) = self.settings.corner {
+            if self.in_corner(corner, x, y) {
+                return Some(FailSafeTrigger::Corner(corner));
+            }
+        }
+
+        if let Some(max_drift) = self.settings.max_drift {
+            let (ax, ay) = self.anchor;
+            let distance = f64::from(x - ax).hypot(f64::from(y - ay)) as u32;
+            if distance > max_drift {
+                return Some(FailSafeTrigger::ManualMove { distance });
+            }
+        }
+
+        None
+    }
+
+    /// 判断坐标是否位于指定角落
+    fn in_corner(&self, corner: ScreenCorner, x: i32, y: i32) -> bool {
+        let margin = i64::from(self.settings.corner_margin);
+        let ScreenRegion {
+            x: left_edge,
+            y: top_edge,
+            width,
+            height,
+        } = self.screen;
+        let (x, y) = (i64::from(x), i64::from(y));
+        let (left_edge, top_edge) = (i64::from(left_edge), i64::from(top_edge));
+        let left = x < left_edge + margin;
+        let right = x >= left_edge + i64::from(width) - margin;
+        let top = y < top_edge + margin;
+        let bottom = y >= top_edge + i64::from(height) - margin;
+
+        match corner {
+            ScreenCorner::TopLeft => top && left,
+            ScreenCorner::TopRight => top && right,
+            ScreenCorner::BottomLeft => bottom && left,
+            ScreenCorner::BottomRight => bottom && right,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn failsafe(screen: ScreenRegion, corner_margin: u32) -> FailSafe {
+        FailSafe {
+            settings: FailSafeSettings {
+                corner_margin,
+                ..FailSafeSettings::default()
+            },
+            screen,
+            anchor: (0, 0),
+        }
+    }
+
+    fn region(x: i32, y: i32, width: u32, height: u32) -> ScreenRegion {
+        ScreenRegion {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    #[test]
+    fn backend_without_location_disables_the_failsafe() {
+        use crate::core::backend::BackendConfig;
+        use crate::core::clock::ManualClock;
+        use crate::core::events::EventBus;
+        use crate::core::monitor::Monitor;
+        use std::sync::Arc;
+
+        // 模拟运行的后端在指针移动前无法报告位置
+        let mouse = MouseController::dry_run(
+            &BackendConfig::default(),
+            EventBus::default(),
+            Arc::new(ManualClock::default()),
+        );
+        let monitors = MonitorLayout::from_monitors(vec![Monitor {
+            name: "DP-1".to_string(),
+            bounds: region(0, 0, 1920, 1080),
+            scale: 1.0,
+            primary: true,
+        }]);
+        let settings = FailSafeSettings {
+            enabled: true,
+            ..FailSafeSettings::default()
+        };
+
+        assert!(mouse.location().is_err());
+        assert!(FailSafe::new(&settings, &mouse, &monitors)
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn corner_margin_is_exclusive() {
+        let failsafe = failsafe(region(0, 0, 1920, 1080), 5);
+        assert!(failsafe.in_corner(ScreenCorner::TopLeft, 0, 0));
+        assert!(failsafe.in_corner(ScreenCorner::TopLeft, 4, 4));
+        assert!(!failsafe.in_corner(ScreenCorner::TopLeft, 5, 4));
+        assert!(!failsafe.in_corner(ScreenCorner::TopLeft, 4, 5));
+    }
+
+    #[test]
+    fn corners_reach_the_last_pixel() {
+        let failsafe = failsafe(region(0, 0, 1920, 1080), 5);
+        assert!(failsafe.in_corner(ScreenCorner::TopRight, 1919, 0));
+        assert!(failsafe.in_corner(ScreenCorner::TopRight, 1915, 4));
+        assert!(!failsafe.in_corner(ScreenCorner::TopRight, 1914, 0));
+        assert!(failsafe.in_corner(ScreenCorner::BottomRight, 1919, 1079));
+        assert!(failsafe.in_corner(ScreenCorner::BottomLeft, 0, 1075));
+        assert!(!failsafe.in_corner(ScreenCorner::BottomLeft, 0, 1074));
+        // 同一位置只属于对应的角落
+        assert!(!failsafe.in_corner(ScreenCorner::BottomLeft, 1919, 1079));
+        assert!(!failsafe.in_corner(ScreenCorner::TopLeft, 1919, 1079));
+    }
+
+    #[test]
+    fn corners_follow_the_whole_desktop() {
+        // 左侧显示器位于主显示器左边，虚拟屏幕原点为负
+        let failsafe = failsafe(region(-1280, 0, 3200, 1080), 5);
+        assert!(failsafe.in_corner(ScreenCorner::TopLeft, -1280, 0));
+        assert!(failsafe.in_corner(ScreenCorner::TopLeft, -1276, 4));
+        // 主显示器的左上角位于虚拟屏幕中间，不再是角落
+        assert!(!failsafe.in_corner(ScreenCorner::TopLeft, 0, 0));
+        assert!(failsafe.in_corner(ScreenCorner::TopRight, 1919, 0));
+        assert!(!failsafe.in_corner(ScreenCorner::TopRight, 600, 0));
+    }
+}
@@ -5,8 +5,9 @@
 use crate::config::FunctionKey;
 use global_hotkey::{
     hotkey::{Code, HotKey, Modifiers},
-    GlobalHotKeyManager,
+    GlobalHotKeyEvent, GlobalHotKeyManager, HotKeyState,
 };
+use std::thread;
 
 /// 热键管理器
 pub struct HotkeyManager {
@@ -17,8 +18,7 @@ pub struct HotkeyManager {
 impl HotkeyManager {
     /// 创建新的热键管理器
     pub fn new() -> Result<Self, String> {
-        let manager =
-            GlobalHotKeyManager::new().map_err(|e| format!("初始化热键管理器失败: {}", e))?;
+        let manager = GlobalHotKeyManager::new().map_err(|e| t!("hotkey.error.init", error = e))?;
 
         Ok(Self {
             manager,
@@ -39,22 +39,13 @@ impl HotkeyManager {
 
         self.manager
             .register(hotkey)
-            .map_err(|e| format!("注册热键{:?} 失败: {}", key, e))?;
+            .map_err(|e| t!("hotkey.error.register", key = key, error = e))?;
 
         self.current_hotkey = Some(hotkey);
         log::info!("成功注册热键: {:?}", key);
         Ok(())
     }
 
-    /// 检查热键是否被按下
-    pub fn check_hotkey_pressed(&self) -> bool {
-        if let Ok(event) = global_hotkey::GlobalHotKeyEvent::receiver().try_recv() {
-            log::debug!("热键触发: {:?}", event);
-            return event.state == global_hotkey::HotKeyState::Pressed;
-        }
-        false
-    }
-
     /// 将FunctionKey转换为global_hotkey的Code
     fn function_key_to_code(&self, key: FunctionKey) -> Result<Code, String> {
         let code = match key {
@@ -82,3 +73,20 @@ impl Drop for HotkeyManager {
         }
     }
 }
+
+/// 在后台线程监听热键，每次按下时调用 `on_pressed`
+///
+/// 不依赖界面刷新，窗口隐藏到托盘时热键依然有效；回调返回false时停止监听
+pub fn spawn_listener<F>(mut on_pressed: F)
+where
+    F: FnMut() -> bool + Send + 'static,
+{
+    thread::spawn(move || {
+        for event in GlobalHotKeyEvent::receiver().iter() {
+            log::debug!("热键触发: {:?}", event);
+            if event.state == HotKeyState::Pressed && !on_pressed() {
+                break;
+            }
+        }
+    });
+}
@@ -0,0 +1,141 @@
+//! 键盘按键模块
+//!
+//! 按名称解析键盘按键。名称不区分大小写，单个字符表示输入该字符的按键
+
+use crate::utils::Result;
+use enigo::Key;
+
+/// 按键选择器中列出的按键名称，字符键直接输入字符即可
+pub const KEY_NAMES: &[&str] = &[
+    "enter",
+    "tab",
+    "space",
+    "esc",
+    "backspace",
+    "delete",
+    "home",
+    "end",
+    "pageup",
+    "pagedown",
+    "up",
+    "down",
+    "left",
+    "right",
+    "shift",
+    "ctrl",
+    "alt",
+    "meta",
+    "capslock",
+    "f1",
+    "f2",
+    "f3",
+    "f4",
+    "f5",
+    "f6",
+    "f7",
+    "f8",
+    "f9",
+    "f10",
+    "f11",
+    "f12",
+];
+
+/// 按名称解析按键，如 `enter`、`ctrl`、`f5`、`a`
+pub fn parse_key(name: &str) -> Result<Key> {
+    let lower = name.trim().to_lowercase();
+    let key = match lower.as_str() {
+        "enter" | "return" => Key::Return,
+        "tab" => Key::Tab,
+        "space" => Key::Space,
+        "esc" | "escape" => Key::Escape,
+        "backspace" => Key::Backspace,
+        "delete" | "del" => Key::Delete,
+        "home" => Key::Home,
+        "end" => Key::End,
+        "pageup" => Key::PageUp,
+        "pagedown" => Key::PageDown,
+        "up" => Key::UpArrow,
+        "down" => Key::DownArrow,
+        "left" => Key::LeftArrow,
+        "right" => Key::RightArrow,
+        "shift" => Key::Shift,
+        "ctrl" | "control" => Key::Control,
+        "alt" => Key::Alt,
+        "meta" | "super" | "win" | "cmd" => Key::Meta,
+        "capslock" => Key::CapsLock,
+        "f1" => Key::F1,
+        "f2" => Key::F2,
+        "f3" => Key::F3,
+        "f4" => Key::F4,
+        "f5" => Key::F5,
+        "f6" => Key::F6,
+        "f7" => Key::F7,
+        "f8" => Key::F8,
+        "f9" => Key::F9,
+        "f10" => Key::F10,
+        "f11" => Key::F11,
+        "f12" => Key::F12,
+        _ => {
+            // 单个字符按原样输入，保留大小写
+            let mut chars = name.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => Key::Unicode(c),
+                _ => return Err(t!("keyboard.error.unknown_key", key = name)),
+            }
+        }
+    };
+    Ok(key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn single_characters_keep_their_case() {
+        assert_eq!(parse_key("a"), Ok(Key::Unicode('a')));
+        assert_eq!(parse_key("A"), Ok(Key::Unicode('A')));
+        assert_eq!(parse_key("7"), Ok(Key::Unicode('7')));
+        assert_eq!(parse_key("中"), Ok(Key::Unicode('中')));
+    }
+
+    #[test]
+    fn named_keys_ignore_case_and_whitespace() {
+        assert_eq!(parse_key("enter"), Ok(Key::Return));
+        assert_eq!(parse_key("Return"), Ok(Key::Return));
+        assert_eq!(parse_key(" ESC "), Ok(Key::Escape));
+        assert_eq!(parse_key("PageDown"), Ok(Key::PageDown));
+        assert_eq!(parse_key("f12"), Ok(Key::F12));
+        assert_eq!(parse_key("F1"), Ok(Key::F1));
+    }
+
+    #[test]
+    fn modifiers_accept_common_aliases() {
+        assert_eq!(parse_key("shift"), Ok(Key::Shift));
+        assert_eq!(parse_key("Ctrl"), Ok(Key::Control));
+        assert_eq!(parse_key("control"), Ok(Key::Control));
+        assert_eq!(parse_key("alt"), Ok(Key::Alt));
+        for alias in ["meta", "super", "win", "cmd"] {
+            assert_eq!(parse_key(alias), Ok(Key::Meta), "{}", alias);
+        }
+    }
+
+    #[test]
+    fn listed_key_names_all_parse() {
+        for name in KEY_NAMES {
+            assert!(parse_key(name).is_ok(), "{}", name);
+        }
+    }
+
+    #[test]
+    fn unknown_names_are_rejected() {
+        for name in ["", "  ", "f13", "hyper", "ctrl+c", "ab"] {
+            assert_eq!(
+                parse_key(name),
+                Err(t!("keyboard.error.unknown_key", key = name)),
+                "{:?}",
+                name
+            );
+        }
+    }
+}
@@ -2,8 +2,25 @@
 //!
 //! 包含鼠标操作、热键监听和连点逻辑等核心功能
 
+pub mod actions;
+pub mod backend;
 pub mod clicker;
+pub mod clock;
+pub mod condition;
+pub mod diagnostics;
+pub mod events;
+pub mod failsafe;
 pub mod hotkey;
+pub mod keyboard;
+pub mod monitor;
 pub mod mouse;
+pub mod screen;
+pub mod script;
+pub mod stats;
+pub mod template;
+pub mod window;
 
+#[cfg(feature = "tray")]
+pub use clicker::ClickerHandle;
 pub use clicker::{ClickerManager, ClickerState, ClickerStatus};
+pub use events::{ClickerEvent, ErrorKind, StopReason};
@@ -0,0 +1,623 @@
+//! 显示器模块
+//!
+//! 枚举已连接的显示器及其缩放比例。输入后端、截图和窗口位置都使用物理像素，
+//! 逻辑坐标为物理像素按所在显示器的缩放比例换算后的值。
+//! 枚举显示器目前仅支持X11（RandR），各显示器的缩放比例按RandR报告的物理尺寸计算，
+//! 未报告物理尺寸时使用Xft.dpi
+
+use crate::config::{Action, AppSettings, ClickPoint, ClickTarget, PointAnchor, ScreenRegion};
+use crate::utils::Result;
+
+/// 显示器
+#[derive(Debug, Clone, PartialEq)]
+pub struct Monitor {
+    /// 显示器名称，如 `HDMI-1`
+    pub name: String,
+    /// 显示器在虚拟屏幕中的位置和尺寸（物理像素）
+    pub bounds: ScreenRegion,
+    /// 缩放比例，1表示一个逻辑像素对应一个物理像素
+    pub scale: f32,
+    /// 是否为主显示器
+    pub primary: bool,
+}
+
+impl Monitor {
+    /// 判断屏幕坐标是否位于该显示器上
+    pub fn contains(&self, point: ClickPoint) -> bool {
+        let ScreenRegion {
+            x,
+            y,
+            width,
+            height,
+        } = self.bounds;
+        point.x >= x
+            && point.y >= y
+            && i64::from(point.x) < i64::from(x) + i64::from(width)
+            && i64::from(point.y) < i64::from(y) + i64::from(height)
+    }
+}
+
+impl std::fmt::Display for Monitor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let ScreenRegion {
+            x,
+            y,
+            width,
+            height,
+        } = self.bounds;
+        write!(
+            f,
+            "{} {}x{}{:+}{:+} ×{}",
+            self.name, width, height, x, y, self.scale
+        )?;
+        if self.primary {
+            write!(f, " {}", t!("monitor.primary"))?;
+        }
+        Ok(())
+    }
+}
+
+/// 标准DPI，对应缩放比例1
+const BASE_DPI: f32 = 96.0;
+
+/// 由物理尺寸推算的缩放比例上限，避免投影仪等报告的错误尺寸产生过大的比例
+const MAX_SCALE: f32 = 4.0;
+
+/// 由显示器的像素宽度和物理宽度（毫米）推算缩放比例，取最接近的0.25倍数，不小于1
+///
+/// 物理宽度为0（未知）时返回None
+pub fn scale_from_size(pixels: u32, millimeters: u32) -> Option<f32> {
+    if pixels == 0 || millimeters == 0 {
+        return None;
+    }
+    let dpi = pixels as f32 * 25.4 / millimeters as f32;
+    Some(((dpi / BASE_DPI * 4.0).round() / 4.0).clamp(1.0, MAX_SCALE))
+}
+
+/// 启动前坐标检查的结果
+#[derive(Debug)]
+pub enum CoordinateCheck {
+    /// 设置中的坐标都位于已连接的显示器上
+    Checked(MonitorLayout),
+    /// 无法枚举显示器，坐标未经检查，值为原因
+    Unchecked(String),
+    /// 当前平台不支持枚举显示器，不检查坐标
+    Unsupported,
+}
+
+/// 逻辑坐标
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LogicalPoint {
+    pub x: f32,
+    pub y: f32,
+}
+
+/// 显示器布局
+#[derive(Debug, Clone, Default)]
+pub struct MonitorLayout {
+    monitors: Vec<Monitor>,
+}
+
+impl MonitorLayout {
+    /// 由给定的显示器构造布局，供测试使用
+    #[cfg(test)]
+    pub(crate) fn from_monitors(monitors: Vec<Monitor>) -> Self {
+        Self { monitors }
+    }
+
+    /// 读取当前连接的显示器，`display` 为X11显示，None表示使用DISPLAY环境变量
+    #[cfg(target_os = "linux")]
+    pub fn query(display: Option<&str>) -> Result<Self> {
+        Ok(Self {
+            monitors: x11::monitors(display)?,
+        })
+    }
+
+    /// 读取当前连接的显示器
+    #[cfg(not(target_os = "linux"))]
+    pub fn query(_display: Option<&str>) -> Result<Self> {
+        Err(t!("monitor.error.unsupported").to_string())
+    }
+
+    /// 枚举显示器并检查设置中的坐标
+    ///
+    /// 坐标不在任何显示器上时返回错误；无法枚举显示器时，相对显示器的点击位置无法换算，
+    /// 同样返回错误，其余情况返回 [`CoordinateCheck::Unchecked`]，
+    /// 平台本身不支持枚举时返回 [`CoordinateCheck::Unsupported`]
+    pub fn check(display: Option<&str>, settings: &AppSettings) -> Result<CoordinateCheck> {
+        match Self::query(display) {
+            Ok(layout) => {
+                layout.check_settings(settings)?;
+                Ok(CoordinateCheck::Checked(layout))
+            }
+            Err(e)
+                if settings
+                    .click_target
+                    .as_ref()
+                    .is_some_and(|target| target.anchor == PointAnchor::Monitor) =>
+            {
+                Err(e)
+            }
+            Err(_) if !cfg!(target_os = "linux") => Ok(CoordinateCheck::Unsupported),
+            Err(e) => Ok(CoordinateCheck::Unchecked(e)),
+        }
+    }
+
+    /// 所有显示器
+    pub fn monitors(&self) -> &[Monitor] {
+        &self.monitors
+    }
+
+    /// 所有显示器的外接矩形，即虚拟屏幕的范围；没有显示器时返回None
+    pub fn bounds(&self) -> Option<ScreenRegion> {
+        let edges = self.monitors.iter().map(|monitor| {
+            let ScreenRegion {
+                x,
+                y,
+                width,
+                height,
+            } = monitor.bounds;
+            (
+                i64::from(x),
+                i64::from(y),
+                i64::from(x) + i64::from(width),
+                i64::from(y) + i64::from(height),
+            )
+        });
+        let (left, top, right, bottom) =
+            edges.reduce(|a, b| (a.0.min(b.0), a.1.min(b.1), a.2.max(b.2), a.3.max(b.3)))?;
+        Some(ScreenRegion {
+            x: left as i32,
+            y: top as i32,
+            width: (right - left) as u32,
+            height: (bottom - top) as u32,
+        })
+    }
+
+    /// 坐标所在的显示器
+    pub fn monitor_at(&self, point: ClickPoint) -> Option<&Monitor> {
+        self.monitors.iter().find(|monitor| monitor.contains(point))
+    }
+
+    /// 按名称查找显示器
+    pub fn find(&self, name: &str) -> Option<&Monitor> {
+        self.monitors.iter().find(|monitor| monitor.name == name)
+    }
+
+    /// 把屏幕坐标换算为逻辑坐标，坐标不在任何显示器上时返回None
+    ///
+    /// 按坐标所在显示器的缩放比例换算，每个显示器的逻辑原点为其物理位置除以缩放比例
+    pub fn to_logical(&self, point: ClickPoint) -> Option<LogicalPoint> {
+        let monitor = self.monitor_at(point)?;
+        Some(LogicalPoint {
+            x: point.x as f32 / monitor.scale,
+            y: point.y as f32 / monitor.scale,
+        })
+    }
+
+    /// 换算为屏幕坐标，`window` 为目标窗口客户区
+    ///
+    /// 相对显示器的位置以逻辑像素保存，按显示器当前的缩放比例换算；
+    /// 显示器或窗口不存在时返回None
+    pub fn resolve(
+        &self,
+        target: &ClickTarget,
+        window: Option<ScreenRegion>,
+    ) -> Option<ClickPoint> {
+        if target.anchor != PointAnchor::Monitor {
+            return target.resolve(window);
+        }
+        let monitor = self.find(target.monitor.as_deref()?)?;
+        Some(ClickPoint {
+            x: monitor.bounds.x + (target.x * monitor.scale).round() as i32,
+            y: monitor.bounds.y + (target.y * monitor.scale).round() as i32,
+        })
+    }
+
+    /// 由屏幕坐标创建点击位置，`window` 为目标窗口客户区
+    pub fn target_from_screen(
+        &self,
+        anchor: PointAnchor,
+        point: ClickPoint,
+        window: Option<ScreenRegion>,
+    ) -> Option<ClickTarget> {
+        if anchor != PointAnchor::Monitor {
+            return ClickTarget::from_screen(anchor, point, window);
+        }
+        let monitor = self.monitor_at(point)?;
+        Some(ClickTarget {
+            anchor,
+            x: (point.x - monitor.bounds.x) as f32 / monitor.scale,
+            y: (point.y - monitor.bounds.y) as f32 / monitor.scale,
+            monitor: Some(monitor.name.clone()),
+        })
+    }
+
+    /// 检查设置中的屏幕坐标是否仍位于已连接的显示器上
+    pub fn check_settings(&self, settings: &AppSettings) -> Result<()> {
+        if let Some(target) = &settings.click_target {
+            match target.anchor {
+                PointAnchor::Screen => {
+                    let point = target.resolve(None);
+                    if point.and_then(|point| self.monitor_at(point)).is_none() {
+                        return Err(self.off_screen_error(&target.to_string()));
+                    }
+                }
+                PointAnchor::Monitor => {
+                    let name = target.monitor.as_deref().unwrap_or_default();
+                    if self.find(name).is_none() {
+                        return Err(t!(
+                            "monitor.error.disconnected",
+                            name = name,
+                            monitors = self.names()
+                        ));
+                    }
+                }
+                // 相对窗口的位置在点击时换算
+                PointAnchor::Window | PointAnchor::WindowPercent => {}
+            }
+        }
+
+        let condition = &settings.pixel_condition;
+        if condition.enabled && self.monitor_at(condition.point).is_none() {
+            return Err(self.off_screen_error(&condition.point.to_string()));
+        }
+
+        // 搜索区域的左上角和右下角都应在显示器上
+        let template = &settings.template;
+        if let Some(region) = template.region.filter(|_| template.enabled) {
+            let corners = [
+                ClickPoint {
+                    x: region.x,
+                    y: region.y,
+                },
+                ClickPoint {
+                    x: region.x + region.width.saturating_sub(1) as i32,
+                    y: region.y + region.height.saturating_sub(1) as i32,
+                },
+            ];
+            if corners
+                .iter()
+                .any(|&point| self.monitor_at(point).is_none())
+            {
+                let region = format!(
+                    "{}x{}{:+}{:+}",
+                    region.width, region.height, region.x, region.y
+                );
+                return Err(self.off_screen_error(&region));
+            }
+        }
+
+        if settings.sequence.enabled {
+            for (index, action) in settings.sequence.steps.iter().enumerate() {
+                let points = match action {
+                    Action::Click {
+                        point: Some(point), ..
+                    }
+                    | Action::Move { point } => vec![*point],
+                    Action::Drag { from, to } => vec![*from, *to],
+                    _ => Vec::new(),
+                };
+                if let Some(point) = points
+                    .iter()
+                    .find(|&&point| self.monitor_at(point).is_none())
+                {
+                    return Err(t!(
+                        "sequence.error.step",
+                        step = index + 1,
+                        error = self.off_screen_error(&point.to_string())
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// 坐标不在任何显示器上的错误
+    fn off_screen_error(&self, point: &str) -> String {
+        t!(
+            "monitor.error.off_screen",
+            point = point,
+            monitors = self.names()
+        )
+    }
+
+    /// 所有显示器的名称，用于错误提示
+    fn names(&self) -> String {
+        self.monitors
+            .iter()
+            .map(|monitor| monitor.name.as_str())
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod x11 {
+    use super::{scale_from_size, Monitor, BASE_DPI};
+    use crate::config::ScreenRegion;
+    use crate::utils::Result;
+    use x11rb::connection::Connection;
+    use x11rb::protocol::randr::ConnectionExt as _;
+    use x11rb::protocol::xproto::{AtomEnum, ConnectionExt as _, Window};
+    use x11rb::rust_connection::RustConnection;
+
+    /// 读取资源数据库的最大长度（32位单位）
+    const MAX_RESOURCE_LENGTH: u32 = 1 << 16;
+
+    pub(super) fn monitors(display: Option<&str>) -> Result<Vec<Monitor>> {
+        let (conn, screen_num) =
+            x11rb::connect(display).map_err(|e| t!("screen.error.connect", error = e))?;
+        let screen = &conn.setup().roots[screen_num];
+        // 显示器未报告物理尺寸时使用的缩放比例
+        let fallback_scale = xft_scale(&conn, screen.root);
+
+        // 没有RandR扩展时请求会失败，按单个显示器处理
+        let infos = conn
+            .randr_get_monitors(screen.root, true)
+            .ok()
+            .and_then(|cookie| cookie.reply().ok())
+            .map(|reply| reply.monitors)
+            .unwrap_or_default();
+
+        let mut monitors = Vec::with_capacity(infos.len());
+        for info in infos {
+            let name = conn
+                .get_atom_name(info.name)
+                .map_err(|e| t!("monitor.error.query", error = e))?
+                .reply()
+                .map_err(|e| t!("monitor.error.query", error = e))?;
+            monitors.push(Monitor {
+                name: String::from_utf8_lossy(&name.name).into_owned(),
+                bounds: ScreenRegion {
+                    x: i32::from(info.x),
+                    y: i32::from(info.y),
+                    width: u32::from(info.width),
+                    height: u32::from(info.height),
+                },
+                scale: scale_from_size(u32::from(info.width), info.width_in_millimeters)
+                    .unwrap_or(fallback_scale),
+                primary: info.primary,
+            });
+        }
+
+        // 部分虚拟显示（如Xvfb）不报告显示器，把整个根窗口当作一个显示器
+        if monitors.is_empty() {
+            monitors.push(Monitor {
+                name: t!("monitor.screen").to_string(),
+                bounds: ScreenRegion {
+                    x: 0,
+                    y: 0,
+                    width: u32::from(screen.width_in_pixels),
+                    height: u32::from(screen.height_in_pixels),
+                },
+                scale: scale_from_size(
+                    u32::from(screen.width_in_pixels),
+                    u32::from(screen.width_in_millimeters),
+                )
+                .unwrap_or(fallback_scale),
+                primary: true,
+            });
+        }
+        Ok(monitors)
+    }
+
+    /// 从资源数据库的Xft.dpi读取缩放比例，未设置时为1
+    fn xft_scale(conn: &RustConnection, root: Window) -> f32 {
+        let resources = conn
+            .get_property(
+                false,
+                root,
+                AtomEnum::RESOURCE_MANAGER,
+                AtomEnum::STRING,
+                0,
+                MAX_RESOURCE_LENGTH,
+            )
+            .ok()
+            .and_then(|cookie| cookie.reply().ok())
+            .map(|reply| String::from_utf8_lossy(&reply.value).into_owned())
+            .unwrap_or_default();
+
+        resources
+            .lines()
+            .find_map(|line| line.strip_prefix("Xft.dpi:"))
+            .and_then(|dpi| dpi.trim().parse::<f32>().ok())
+            .filter(|dpi| *dpi > 0.0)
+            .map_or(1.0, |dpi| dpi / BASE_DPI)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 左侧为2倍缩放的4K显示器，右侧为1倍缩放的1080p显示器，底边对齐
+    fn mixed_layout() -> MonitorLayout {
+        MonitorLayout {
+            monitors: vec![
+                Monitor {
+                    name: "DP-1".to_string(),
+                    bounds: ScreenRegion {
+                        x: 0,
+                        y: 0,
+                        width: 3840,
+                        height: 2160,
+                    },
+                    scale: 2.0,
+                    primary: true,
+                },
+                Monitor {
+                    name: "HDMI-1".to_string(),
+                    bounds: ScreenRegion {
+                        x: 3840,
+                        y: 1080,
+                        width: 1920,
+                        height: 1080,
+                    },
+                    scale: 1.0,
+                    primary: false,
+                },
+            ],
+        }
+    }
+
+    fn round_trip(layout: &MonitorLayout, point: ClickPoint) -> (ClickTarget, ClickPoint) {
+        let target = layout
+            .target_from_screen(PointAnchor::Monitor, point, None)
+            .unwrap();
+        let resolved = layout.resolve(&target, None).unwrap();
+        (target, resolved)
+    }
+
+    #[test]
+    fn monitor_targets_round_trip_on_each_monitor() {
+        let layout = mixed_layout();
+        for point in [
+            ClickPoint { x: 0, y: 0 },
+            ClickPoint { x: 1001, y: 501 },
+            ClickPoint { x: 3839, y: 2159 },
+            ClickPoint { x: 3840, y: 1080 },
+            ClickPoint { x: 4500, y: 1500 },
+            ClickPoint { x: 5759, y: 2159 },
+        ] {
+            let (_, resolved) = round_trip(&layout, point);
+            assert_eq!(resolved, point);
+        }
+    }
+
+    #[test]
+    fn monitor_targets_use_the_scale_of_their_monitor() {
+        let layout = mixed_layout();
+        let (target, _) = round_trip(&layout, ClickPoint { x: 1000, y: 500 });
+        assert_eq!(target.monitor.as_deref(), Some("DP-1"));
+        assert_eq!((target.x, target.y), (500.0, 250.0));
+
+        let (target, _) = round_trip(&layout, ClickPoint { x: 4340, y: 1580 });
+        assert_eq!(target.monitor.as_deref(), Some("HDMI-1"));
+        assert_eq!((target.x, target.y), (500.0, 500.0));
+    }
+
+    #[test]
+    fn points_off_every_monitor_are_rejected() {
+        let layout = mixed_layout();
+        // 右侧显示器上方的空白区域
+        let gap = ClickPoint { x: 4000, y: 500 };
+        assert!(layout.monitor_at(gap).is_none());
+        assert!(layout
+            .target_from_screen(PointAnchor::Monitor, gap, None)
+            .is_none());
+        assert!(layout.to_logical(gap).is_none());
+    }
+
+    #[test]
+    fn disconnected_monitor_cannot_be_resolved() {
+        let layout = mixed_layout();
+        let target = ClickTarget {
+            anchor: PointAnchor::Monitor,
+            x: 10.0,
+            y: 10.0,
+            monitor: Some("DP-2".to_string()),
+        };
+        assert!(layout.resolve(&target, None).is_none());
+
+        let settings = AppSettings {
+            click_target: Some(target),
+            ..AppSettings::default()
+        };
+        assert!(layout.check_settings(&settings).is_err());
+    }
+
+    #[test]
+    fn sequence_points_must_be_on_a_monitor() {
+        let layout = mixed_layout();
+        let gap = ClickPoint { x: 4000, y: 500 };
+        let on_screen = ClickPoint { x: 100, y: 100 };
+        let mut settings = AppSettings::default();
+        settings.sequence.steps = vec![
+            Action::Move { point: on_screen },
+            Action::Click {
+                button: crate::config::MouseButton::Left,
+                point: None,
+            },
+            Action::Drag {
+                from: on_screen,
+                to: gap,
+            },
+        ];
+
+        // 未启用的序列不检查
+        assert!(layout.check_settings(&settings).is_ok());
+
+        settings.sequence.enabled = true;
+        let error = layout.check_settings(&settings).unwrap_err();
+        assert_eq!(
+            error,
+            t!(
+                "sequence.error.step",
+                step = 3,
+                error = layout.off_screen_error(&gap.to_string())
+            )
+        );
+
+        settings.sequence.steps[2] = Action::Click {
+            button: crate::config::MouseButton::Right,
+            point: Some(on_screen),
+        };
+        assert!(layout.check_settings(&settings).is_ok());
+    }
+
+    #[test]
+    fn template_region_must_be_on_a_monitor() {
+        let layout = mixed_layout();
+        let mut settings = AppSettings::default();
+        settings.template.enabled = true;
+
+        // 跨两个显示器、两角都在显示器上的区域
+        settings.template.region = Some(ScreenRegion {
+            x: 3000,
+            y: 1500,
+            width: 2000,
+            height: 660,
+        });
+        assert!(layout.check_settings(&settings).is_ok());
+
+        // 右下角超出右侧显示器
+        settings.template.region = Some(ScreenRegion {
+            x: 5000,
+            y: 2000,
+            width: 761,
+            height: 100,
+        });
+        assert!(layout.check_settings(&settings).is_err());
+
+        settings.template.enabled = false;
+        assert!(layout.check_settings(&settings).is_ok());
+    }
+
+    #[test]
+    fn bounds_cover_every_monitor() {
+        assert_eq!(
+            mixed_layout().bounds(),
+            Some(ScreenRegion {
+                x: 0,
+                y: 0,
+                width: 5760,
+                height: 2160,
+            })
+        );
+        assert_eq!(MonitorLayout::default().bounds(), None);
+    }
+
+    #[test]
+    fn scale_follows_physical_size() {
+        // 27英寸4K约163 DPI
+        assert_eq!(scale_from_size(3840, 597), Some(1.75));
+        // 13.3英寸2560宽约218 DPI
+        assert_eq!(scale_from_size(2560, 298), Some(2.25));
+        // 24英寸1080p低于96 DPI，按1倍处理
+        assert_eq!(scale_from_size(1920, 531), Some(1.0));
+        assert_eq!(scale_from_size(1920, 0), None);
+        // 报告的尺寸明显错误时限制上限
+        assert_eq!(scale_from_size(1920, 10), Some(MAX_SCALE));
+    }
+}
@@ -2,21 +2,114 @@
 //!
 //! 提供统一鼠标点击、长按和滚轮操作接口
 
-use crate::config::MouseButton;
-use enigo::{Enigo, Mouse, Settings};
+use crate::config::{ClickPoint, MouseButton};
+use crate::core::backend::dry_run::{DryRunBackend, DryRunRecorder, SimulatedInput};
+#[cfg(target_os = "linux")]
+use crate::core::backend::send_event::SendEventBackend;
+use crate::core::backend::{self, BackendConfig, InputError, MouseBackend};
+use crate::core::clock::{Clock, SystemClock};
+use crate::core::events::EventBus;
+use crate::core::window::WindowId;
+use std::sync::Arc;
 use std::time::Duration;
 
+/// 长按时按键保持按下的时长
+const LONG_PRESS_DURATION: Duration = Duration::from_millis(100);
+
+/// 滚轮按钮每次滚动的格数
+const SCROLL_LINES: i32 = 3;
+
+/// 滚轮按钮对应的滚动量，符号约定与enigo一致：正数向下，负数向上
+fn scroll_length(button: MouseButton) -> i32 {
+    match button {
+        MouseButton::ScrollUp => -SCROLL_LINES,
+        _ => SCROLL_LINES,
+    }
+}
+
+/// 拖动时的分段数，部分程序只在指针逐步移动时识别拖动
+const DRAG_STEPS: i32 = 10;
+
+/// 拖动时每段之间的间隔
+const DRAG_STEP_DELAY: Duration = Duration::from_millis(10);
+
 /// 鼠标控制器
 pub struct MouseController {
-    enigo: Enigo,
+    backend: Box<dyn MouseBackend>,
+    /// 当前处于按下状态的按键
+    pressed: Vec<enigo::Button>,
+    /// 当前处于按下状态的键盘按键
+    pressed_keys: Vec<enigo::Key>,
+    /// 模拟运行时的记录器，后台点击同样只记录不发送
+    recorder: Option<DryRunRecorder>,
+    /// 长按和拖动时等待使用的时钟
+    clock: Arc<dyn Clock>,
+    /// 后台点击使用的X11显示
+    #[cfg(target_os = "linux")]
+    display: Option<String>,
+    /// 后台点击器，首次后台点击时创建
+    #[cfg(target_os = "linux")]
+    window_sender: Option<SendEventBackend>,
 }
 
 impl MouseController {
-    /// 创建新的鼠标控制器
-    pub fn new() -> Result<Self, String> {
-        let enigo =
-            Enigo::new(&Settings::default()).map_err(|e| format!("初始化鼠标控制器失败: {}", e))?;
-        Ok(Self { enigo })
+    /// 使用指定的输入后端创建鼠标控制器
+    pub fn new(config: &BackendConfig) -> Result<Self, String> {
+        let backend = backend::create(config)?;
+        log::info!("使用输入后端: {}", backend.name());
+        Ok(Self {
+            backend,
+            pressed: Vec::new(),
+            pressed_keys: Vec::new(),
+            recorder: None,
+            clock: Arc::new(SystemClock),
+            #[cfg(target_os = "linux")]
+            display: config.display.clone(),
+            #[cfg(target_os = "linux")]
+            window_sender: None,
+        })
+    }
+
+    /// 创建模拟运行的鼠标控制器，所有输入以事件形式广播而不实际执行
+    ///
+    /// 后台点击仍会读取目标窗口的尺寸和位置。记录的时间和长按、拖动的等待都使用 `clock`
+    #[cfg_attr(not(target_os = "linux"), allow(unused_variables))]
+    pub fn dry_run(config: &BackendConfig, events: EventBus, clock: Arc<dyn Clock>) -> Self {
+        let recorder = DryRunRecorder::new(events, clock.clone());
+        log::info!("模拟运行，不会执行任何输入");
+        Self {
+            backend: Box::new(DryRunBackend::new(recorder.clone())),
+            pressed: Vec::new(),
+            pressed_keys: Vec::new(),
+            recorder: Some(recorder),
+            clock,
+            #[cfg(target_os = "linux")]
+            display: config.display.clone(),
+            #[cfg(target_os = "linux")]
+            window_sender: None,
+        }
+    }
+
+    /// 当前使用的输入后端名称
+    pub fn backend_name(&self) -> &'static str {
+        self.backend.name()
+    }
+
+    /// 将指针移动到屏幕绝对坐标
+    pub fn move_to(&mut self, x: i32, y: i32) -> Result<(), InputError> {
+        self.backend
+            .move_to(x, y)
+            .map_err(|e| e.context(|e| t!("mouse.error.move", error = e)))
+    }
+
+    /// 获取指针当前的屏幕坐标
+    pub fn location(&self) -> Result<(i32, i32), String> {
+        self.backend.location()
+    }
+
+    /// 获取主屏幕尺寸（宽, 高）
+    pub fn display_size(&self) -> Result<(i32, i32), String> {
+        self.backend.display_size()
     }
 
     #[cfg(target_os = "macos")]
@@ -39,6 +132,16 @@ impl MouseController {
         false
     }
 
+    #[cfg(target_os = "linux")]
+    pub fn is_linux() -> bool {
+        true
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    pub fn is_linux() -> bool {
+        false
+    }
+
     /// 检查是否以管理员权限运行 (仅Windows)
     #[cfg(target_os = "windows")]
     pub fn is_admin() -> bool {
@@ -62,7 +165,7 @@ impl MouseController {
         use windows::Win32::UI::Shell::{ShellExecuteExW, SHELLEXECUTEINFOW};
         use windows::Win32::UI::WindowsAndMessaging::SW_SHOWNORMAL;
 
-        let exe = env::current_exe().map_err(|e| format!("获取当前程序路径失败: {}", e))?;
+        let exe = env::current_exe().map_err(|e| t!("mouse.error.current_exe", error = e))?;
         let exe_w: Vec<u16> = exe.as_os_str().encode_wide().chain(once(0)).collect();
 
         let mut sei = SHELLEXECUTEINFOW {
@@ -75,7 +178,7 @@ impl MouseController {
 
         unsafe {
             ShellExecuteExW(&mut sei as *mut _)
-                .map_err(|e| format!("以管理员权限重启失败: {}", e))?;
+                .map_err(|e| t!("mouse.error.restart_admin", error = e))?;
 
             log::info!("正在以管理员权限重启应用程序...");
             process::exit(0);
@@ -84,7 +187,7 @@ impl MouseController {
 
     #[cfg(not(target_os = "windows"))]
     pub fn restart_as_admin() -> Result<(), String> {
-        Err("此功能仅在Windows上可用".to_string())
+        Err(t!("mouse.error.windows_only").to_string())
     }
 
     /// 打开macOS系统设置到隐私页面
@@ -101,7 +204,7 @@ impl MouseController {
                 Ok(())
             }
             Err(e) => {
-                let error_msg = format!("无法打开系统设置: {}", e);
+                let error_msg = t!("mouse.error.open_settings", error = e);
                 log::error!("{}", error_msg);
                 Err(error_msg)
             }
@@ -119,7 +222,7 @@ impl MouseController {
                 Ok(())
             }
             Err(e) => {
-                let error_msg = format!("无法打开系统设置: {}", e);
+                let error_msg = t!("mouse.error.open_settings", error = e);
                 log::error!("{}", error_msg);
                 Err(error_msg)
             }
@@ -128,46 +231,340 @@ impl MouseController {
 
     #[cfg(not(any(target_os = "macos", target_os = "windows")))]
     pub fn open_privacy_settings() -> Result<(), String> {
-        Err("此功能仅在macOS和Windows上可用".to_string())
+        Err(t!("mouse.error.macos_windows_only").to_string())
+    }
+
+    /// 按下按键并记录，直到对应的 [`release`](Self::release) 为止
+    pub fn press(&mut self, button: enigo::Button) -> Result<(), InputError> {
+        self.backend.button(button, enigo::Direction::Press)?;
+        if !self.pressed.contains(&button) {
+            self.pressed.push(button);
+        }
+        Ok(())
+    }
+
+    /// 释放按键
+    pub fn release(&mut self, button: enigo::Button) -> Result<(), InputError> {
+        self.backend.button(button, enigo::Direction::Release)?;
+        self.pressed.retain(|b| *b != button);
+        Ok(())
+    }
+
+    /// 释放所有仍处于按下状态的按键
+    pub fn release_all(&mut self) {
+        for button in std::mem::take(&mut self.pressed) {
+            if let Err(e) = self.backend.button(button, enigo::Direction::Release) {
+                log::warn!("释放{:?}键失败: {}", button, e);
+            } else {
+                log::debug!("已释放{:?}键", button);
+            }
+        }
+        for key in std::mem::take(&mut self.pressed_keys) {
+            if let Err(e) = self.backend.key(key, enigo::Direction::Release) {
+                log::warn!("释放{:?}键失败: {}", key, e);
+            } else {
+                log::debug!("已释放{:?}键", key);
+            }
+        }
+    }
+
+    /// 按下、释放或敲击键盘按键，按下的按键在释放前被记录
+    pub fn key(&mut self, key: enigo::Key, direction: enigo::Direction) -> Result<(), InputError> {
+        self.backend.key(key, direction).map_err(|e| {
+            e.context(|e| t!("mouse.error.key", key = format!("{:?}", key), error = e))
+        })?;
+        match direction {
+            enigo::Direction::Press => {
+                if !self.pressed_keys.contains(&key) {
+                    self.pressed_keys.push(key);
+                }
+            }
+            enigo::Direction::Release => self.pressed_keys.retain(|k| *k != key),
+            enigo::Direction::Click => {}
+        }
+        Ok(())
+    }
+
+    /// 按住左键从 `from` 分段移动到 `to` 后松开
+    pub fn drag(&mut self, from: ClickPoint, to: ClickPoint) -> Result<(), InputError> {
+        self.move_to(from.x, from.y)?;
+        self.press(enigo::Button::Left)
+            .map_err(|e| e.context(|e| t!("mouse.error.left_press", error = e)))?;
+        for step in 1..=DRAG_STEPS {
+            self.clock.sleep(DRAG_STEP_DELAY);
+            self.move_to(
+                from.x + (to.x - from.x) * step / DRAG_STEPS,
+                from.y + (to.y - from.y) * step / DRAG_STEPS,
+            )?;
+        }
+        self.release(enigo::Button::Left)
+            .map_err(|e| e.context(|e| t!("mouse.error.left_release", error = e)))
+    }
+
+    /// 输入文本
+    pub fn type_text(&mut self, text: &str) -> Result<(), InputError> {
+        self.backend
+            .text(text)
+            .map_err(|e| e.context(|e| t!("mouse.error.type_text", error = e)))
+    }
+
+    /// 垂直滚动，正数向下，负数向上
+    pub fn scroll(&mut self, length: i32) -> Result<(), InputError> {
+        self.backend
+            .scroll(length)
+            .map_err(|e| e.context(|e| t!("mouse.error.scroll", error = e)))
     }
 
     /// 执行鼠标点击操作
-    pub fn click(&mut self, button: MouseButton) -> Result<(), String> {
+    pub fn click(&mut self, button: MouseButton) -> Result<(), InputError> {
         match button {
             MouseButton::Left => self
-                .enigo
+                .backend
                 .button(enigo::Button::Left, enigo::Direction::Click)
-                .map_err(|e| format!("左键点击失败: {}", e)),
+                .map_err(|e| e.context(|e| t!("mouse.error.left_click", error = e))),
             MouseButton::Right => self
-                .enigo
+                .backend
                 .button(enigo::Button::Right, enigo::Direction::Click)
-                .map_err(|e| format!("右键点击失败: {}", e)),
+                .map_err(|e| e.context(|e| t!("mouse.error.right_click", error = e))),
             MouseButton::LeftLongPress => {
-                self.enigo
-                    .button(enigo::Button::Left, enigo::Direction::Press)
-                    .map_err(|e| format!("左键按下失败: {}", e))?;
-                std::thread::sleep(Duration::from_millis(100));
-                self.enigo
-                    .button(enigo::Button::Left, enigo::Direction::Release)
-                    .map_err(|e| format!("左键释放失败: {}", e))
+                self.press(enigo::Button::Left)
+                    .map_err(|e| e.context(|e| t!("mouse.error.left_press", error = e)))?;
+                self.clock.sleep(LONG_PRESS_DURATION);
+                self.release(enigo::Button::Left)
+                    .map_err(|e| e.context(|e| t!("mouse.error.left_release", error = e)))
             }
             MouseButton::RightLongPress => {
-                self.enigo
-                    .button(enigo::Button::Right, enigo::Direction::Press)
-                    .map_err(|e| format!("右键按下失败: {}", e))?;
-                std::thread::sleep(Duration::from_millis(100));
-                self.enigo
-                    .button(enigo::Button::Right, enigo::Direction::Release)
-                    .map_err(|e| format!("右键释放失败: {}", e))
+                self.press(enigo::Button::Right)
+                    .map_err(|e| e.context(|e| t!("mouse.error.right_press", error = e)))?;
+                self.clock.sleep(LONG_PRESS_DURATION);
+                self.release(enigo::Button::Right)
+                    .map_err(|e| e.context(|e| t!("mouse.error.right_release", error = e)))
             }
             MouseButton::ScrollUp => self
-                .enigo
-                .scroll(3, enigo::Axis::Vertical)
-                .map_err(|e| format!("向上滚动失败: {}", e)),
+                .backend
+                .scroll(scroll_length(button))
+                .map_err(|e| e.context(|e| t!("mouse.error.scroll_up", error = e))),
             MouseButton::ScrollDown => self
-                .enigo
-                .scroll(-3, enigo::Axis::Vertical)
-                .map_err(|e| format!("向下滚动失败: {}", e)),
+                .backend
+                .scroll(scroll_length(button))
+                .map_err(|e| e.context(|e| t!("mouse.error.scroll_down", error = e))),
         }
     }
 }
+
+impl MouseController {
+    /// 后台点击器，首次调用时连接显示
+    #[cfg(target_os = "linux")]
+    fn window_sender(&mut self) -> Result<&SendEventBackend, String> {
+        if self.window_sender.is_none() {
+            self.window_sender = Some(SendEventBackend::new(self.display.as_deref())?);
+        }
+        Ok(self.window_sender.as_ref().expect("后台点击器已创建"))
+    }
+
+    /// 向窗口发送点击，真实指针保持不动
+    ///
+    /// `position` 为相对窗口左上角的坐标，None表示窗口中心。
+    /// 返回点击位置的屏幕坐标
+    #[cfg(target_os = "linux")]
+    pub fn click_window(
+        &mut self,
+        window: WindowId,
+        position: Option<ClickPoint>,
+        button: MouseButton,
+    ) -> Result<(i32, i32), InputError> {
+        let recorder = self.recorder.clone();
+        let clock = self.clock.clone();
+        let sender = self.window_sender()?;
+        let (x, y) = match position {
+            Some(point) => (point.x, point.y),
+            None => {
+                let (width, height) = sender.window_size(window)?;
+                (width / 2, height / 2)
+            }
+        };
+
+        let long_press = |button| {
+            sender.button(window, (x, y), button, enigo::Direction::Press)?;
+            clock.sleep(LONG_PRESS_DURATION);
+            sender.button(window, (x, y), button, enigo::Direction::Release)
+        };
+        let result = if let Some(recorder) = &recorder {
+            recorder.record(SimulatedInput::WindowClick {
+                window,
+                position: (x, y),
+                button,
+            });
+            // 保持与实际点击相同的耗时
+            if matches!(
+                button,
+                MouseButton::LeftLongPress | MouseButton::RightLongPress
+            ) {
+                clock.sleep(LONG_PRESS_DURATION);
+            }
+            Ok(())
+        } else {
+            match button {
+                MouseButton::Left => {
+                    sender.button(window, (x, y), enigo::Button::Left, enigo::Direction::Click)
+                }
+                MouseButton::Right => sender.button(
+                    window,
+                    (x, y),
+                    enigo::Button::Right,
+                    enigo::Direction::Click,
+                ),
+                MouseButton::LeftLongPress => long_press(enigo::Button::Left),
+                MouseButton::RightLongPress => long_press(enigo::Button::Right),
+                MouseButton::ScrollUp | MouseButton::ScrollDown => {
+                    sender.scroll(window, (x, y), scroll_length(button))
+                }
+            }
+        };
+        result.map_err(|e| t!("mouse.error.window_click", error = e))?;
+
+        let (origin_x, origin_y) = sender.window_origin(window)?;
+        Ok((origin_x + x, origin_y + y))
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    pub fn click_window(
+        &mut self,
+        _window: WindowId,
+        _position: Option<ClickPoint>,
+        _button: MouseButton,
+    ) -> Result<(i32, i32), InputError> {
+        Err(t!("mouse.error.background_unsupported").to_string().into())
+    }
+
+    /// 检查能否使用后台点击，`display` 为X11显示，None表示使用DISPLAY环境变量
+    ///
+    /// 只能确认可以连接X11显示，无法确认目标程序是否接受合成事件
+    #[cfg(target_os = "linux")]
+    pub fn check_window_delivery(display: Option<&str>) -> Result<(), String> {
+        SendEventBackend::new(display).map(|_| ())
+    }
+
+    /// 检查能否使用后台点击，当前平台不支持
+    #[cfg(not(target_os = "linux"))]
+    pub fn check_window_delivery(_display: Option<&str>) -> Result<(), String> {
+        Err(t!("mouse.error.background_unsupported").to_string())
+    }
+}
+
+impl Drop for MouseController {
+    fn drop(&mut self) {
+        self.release_all();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::clock::ManualClock;
+    use crate::core::events::ClickerEvent;
+    use std::sync::mpsc::Receiver;
+
+    fn dry_run_mouse() -> (MouseController, Receiver<ClickerEvent>) {
+        let events = EventBus::default();
+        let receiver = events.subscribe();
+        let mouse = MouseController::dry_run(
+            &BackendConfig::default(),
+            events,
+            Arc::new(ManualClock::default()),
+        );
+        (mouse, receiver)
+    }
+
+    /// 取出目前为止记录的所有模拟输入
+    fn recorded(events: &Receiver<ClickerEvent>) -> Vec<SimulatedInput> {
+        events
+            .try_iter()
+            .filter_map(|event| match event {
+                ClickerEvent::Simulated { input, .. } => Some(input),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn scroll_buttons_follow_the_positive_is_down_convention() {
+        let (mut mouse, events) = dry_run_mouse();
+        mouse.click(MouseButton::ScrollUp).unwrap();
+        mouse.click(MouseButton::ScrollDown).unwrap();
+        assert_eq!(
+            recorded(&events),
+            vec![SimulatedInput::Scroll(-3), SimulatedInput::Scroll(3)]
+        );
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn background_scroll_uses_the_same_wheel_buttons() {
+        use crate::core::backend::send_event;
+
+        // X11中滚轮按键4向上，5向下
+        assert_eq!(
+            send_event::wheel_button(scroll_length(MouseButton::ScrollUp)),
+            4
+        );
+        assert_eq!(
+            send_event::wheel_button(scroll_length(MouseButton::ScrollDown)),
+            5
+        );
+    }
+
+    #[test]
+    fn held_buttons_and_keys_are_released_on_drop() {
+        let (mut mouse, events) = dry_run_mouse();
+        mouse.press(enigo::Button::Left).unwrap();
+        mouse
+            .key(enigo::Key::Shift, enigo::Direction::Press)
+            .unwrap();
+        drop(mouse);
+
+        assert_eq!(
+            recorded(&events),
+            vec![
+                SimulatedInput::Button {
+                    button: enigo::Button::Left,
+                    direction: enigo::Direction::Press,
+                },
+                SimulatedInput::Key {
+                    key: enigo::Key::Shift,
+                    direction: enigo::Direction::Press,
+                },
+                SimulatedInput::Button {
+                    button: enigo::Button::Left,
+                    direction: enigo::Direction::Release,
+                },
+                SimulatedInput::Key {
+                    key: enigo::Key::Shift,
+                    direction: enigo::Direction::Release,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn released_buttons_are_not_released_again() {
+        let (mut mouse, events) = dry_run_mouse();
+        mouse.click(MouseButton::LeftLongPress).unwrap();
+        mouse.release_all();
+        drop(mouse);
+
+        let releases = recorded(&events)
+            .into_iter()
+            .filter(|input| {
+                matches!(
+                    input,
+                    SimulatedInput::Button {
+                        direction: enigo::Direction::Release,
+                        ..
+                    }
+                )
+            })
+            .count();
+        assert_eq!(releases, 1);
+    }
+}
@@ -0,0 +1,255 @@
+//! 屏幕读取模块
+//!
+//! 读取指针位置和屏幕像素，供坐标选取的放大镜使用。
+//! 像素读取目前仅支持X11，其他平台只能读取指针位置
+
+use crate::utils::Result;
+
+/// 屏幕区域的像素
+#[derive(Debug, Clone)]
+pub struct ScreenImage {
+    pub width: u32,
+    pub height: u32,
+    /// 按行排列的RGB像素，超出屏幕的部分为黑色
+    pub pixels: Vec<[u8; 3]>,
+}
+
+impl ScreenImage {
+    /// 获取指定位置的像素
+    pub fn pixel(&self, x: u32, y: u32) -> Option<[u8; 3]> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        self.pixels.get((y * self.width + x) as usize).copied()
+    }
+}
+
+/// 屏幕读取器
+pub struct ScreenReader {
+    #[cfg(target_os = "linux")]
+    x11: x11::X11Screen,
+    #[cfg(not(target_os = "linux"))]
+    enigo: crate::core::backend::enigo::EnigoBackend,
+}
+
+impl ScreenReader {
+    /// 创建读取器，Linux上连接到指定的X11显示，None表示使用DISPLAY环境变量
+    #[cfg(target_os = "linux")]
+    pub fn new(display: Option<&str>) -> Result<Self> {
+        Ok(Self {
+            x11: x11::X11Screen::new(display)?,
+        })
+    }
+
+    /// 创建读取器
+    #[cfg(not(target_os = "linux"))]
+    pub fn new(_display: Option<&str>) -> Result<Self> {
+        Ok(Self {
+            enigo: crate::core::backend::enigo::EnigoBackend::new()?,
+        })
+    }
+
+    /// 获取指针当前的屏幕坐标
+    pub fn cursor_position(&self) -> Result<(i32, i32)> {
+        #[cfg(target_os = "linux")]
+        return self.x11.cursor_position();
+        #[cfg(not(target_os = "linux"))]
+        {
+            use crate::core::backend::MouseBackend;
+            self.enigo.location()
+        }
+    }
+
+    /// 获取屏幕尺寸（宽, 高）
+    pub fn screen_size(&self) -> Result<(u32, u32)> {
+        #[cfg(target_os = "linux")]
+        return Ok(self.x11.size());
+        #[cfg(not(target_os = "linux"))]
+        {
+            use crate::core::backend::MouseBackend;
+            let (width, height) = self.enigo.display_size()?;
+            Ok((width.max(0) as u32, height.max(0) as u32))
+        }
+    }
+
+    /// 读取以 (x, y) 为左上角的屏幕区域
+    pub fn capture(&self, x: i32, y: i32, width: u32, height: u32) -> Result<ScreenImage> {
+        #[cfg(target_os = "linux")]
+        return self.x11.capture(x, y, width, height);
+        #[cfg(not(target_os = "linux"))]
+        {
+            let _ = (x, y, width, height);
+            Err(t!("screen.error.capture_unsupported").to_string())
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod x11 {
+    use super::ScreenImage;
+    use crate::utils::Result;
+    use x11rb::connection::Connection;
+    use x11rb::protocol::xproto::{ConnectionExt as _, ImageFormat, ImageOrder, Window};
+    use x11rb::rust_connection::RustConnection;
+
+    /// 根窗口视觉的颜色掩码
+    struct ColorMasks {
+        red: u32,
+        green: u32,
+        blue: u32,
+    }
+
+    impl ColorMasks {
+        /// 从像素值中取出RGB分量
+        fn rgb(&self, value: u32) -> [u8; 3] {
+            [
+                channel(value, self.red),
+                channel(value, self.green),
+                channel(value, self.blue),
+            ]
+        }
+    }
+
+    /// 按掩码取出一个颜色分量并缩放到8位
+    fn channel(value: u32, mask: u32) -> u8 {
+        if mask == 0 {
+            return 0;
+        }
+        let bits = mask.count_ones();
+        let raw = (value & mask) >> mask.trailing_zeros();
+        if bits >= 8 {
+            (raw >> (bits - 8)) as u8
+        } else {
+            (raw * 255 / ((1 << bits) - 1)) as u8
+        }
+    }
+
+    /// X11屏幕
+    pub(super) struct X11Screen {
+        conn: RustConnection,
+        root: Window,
+        width: i32,
+        height: i32,
+        depth: u8,
+        bits_per_pixel: u8,
+        scanline_pad: u8,
+        masks: ColorMasks,
+    }
+
+    impl X11Screen {
+        pub(super) fn new(display: Option<&str>) -> Result<Self> {
+            let (conn, screen_num) =
+                x11rb::connect(display).map_err(|e| t!("screen.error.connect", error = e))?;
+
+            let setup = conn.setup();
+            let screen = &setup.roots[screen_num];
+            let visual = screen
+                .allowed_depths
+                .iter()
+                .flat_map(|depth| &depth.visuals)
+                .find(|visual| visual.visual_id == screen.root_visual)
+                .ok_or_else(|| t!("screen.error.visual").to_string())?;
+            let masks = ColorMasks {
+                red: visual.red_mask,
+                green: visual.green_mask,
+                blue: visual.blue_mask,
+            };
+            let format = setup
+                .pixmap_formats
+                .iter()
+                .find(|format| format.depth == screen.root_depth)
+                .ok_or_else(|| t!("screen.error.visual").to_string())?;
+            // 只处理常见的32位小端格式
+            if format.bits_per_pixel != 32 || setup.image_byte_order != ImageOrder::LSB_FIRST {
+                return Err(t!(
+                    "screen.error.pixel_format",
+                    bits = format.bits_per_pixel
+                ));
+            }
+
+            Ok(Self {
+                root: screen.root,
+                width: i32::from(screen.width_in_pixels),
+                height: i32::from(screen.height_in_pixels),
+                depth: screen.root_depth,
+                bits_per_pixel: format.bits_per_pixel,
+                scanline_pad: format.scanline_pad,
+                masks,
+                conn,
+            })
+        }
+
+        pub(super) fn size(&self) -> (u32, u32) {
+            (self.width as u32, self.height as u32)
+        }
+
+        pub(super) fn cursor_position(&self) -> Result<(i32, i32)> {
+            let reply = self
+                .conn
+                .query_pointer(self.root)
+                .map_err(|e| t!("xtest.error.query_pointer", error = e))?
+                .reply()
+                .map_err(|e| t!("xtest.error.query_pointer", error = e))?;
+            Ok((i32::from(reply.root_x), i32::from(reply.root_y)))
+        }
+
+        pub(super) fn capture(
+            &self,
+            x: i32,
+            y: i32,
+            width: u32,
+            height: u32,
+        ) -> Result<ScreenImage> {
+            let mut image = ScreenImage {
+                width,
+                height,
+                pixels: vec![[0; 3]; (width * height) as usize],
+            };
+
+            // GetImage要求区域完全位于屏幕内，只读取相交的部分
+            let left = x.max(0);
+            let top = y.max(0);
+            let right = (x + width as i32).min(self.width);
+            let bottom = (y + height as i32).min(self.height);
+            if left >= right || top >= bottom {
+                return Ok(image);
+            }
+            let (read_width, read_height) = ((right - left) as u16, (bottom - top) as u16);
+
+            let reply = self
+                .conn
+                .get_image(
+                    ImageFormat::Z_PIXMAP,
+                    self.root,
+                    left as i16,
+                    top as i16,
+                    read_width,
+                    read_height,
+                    !0,
+                )
+                .map_err(|e| t!("screen.error.capture", error = e))?
+                .reply()
+                .map_err(|e| t!("screen.error.capture", error = e))?;
+            if reply.depth != self.depth {
+                return Err(t!("screen.error.pixel_format", bits = reply.depth));
+            }
+
+            let pad = usize::from(self.scanline_pad);
+            let row_bits = usize::from(read_width) * usize::from(self.bits_per_pixel);
+            let stride = row_bits.div_ceil(pad) * pad / 8;
+            for row in 0..usize::from(read_height) {
+                for column in 0..usize::from(read_width) {
+                    let offset = row * stride + column * 4;
+                    let Some(bytes) = reply.data.get(offset..offset + 4) else {
+                        continue;
+                    };
+                    let value = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+                    let target_x = (left - x) as usize + column;
+                    let target_y = (top - y) as usize + row;
+                    image.pixels[target_y * width as usize + target_x] = self.masks.rgb(value);
+                }
+            }
+            Ok(image)
+        }
+    }
+}
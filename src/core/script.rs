@@ -0,0 +1,466 @@
+//! 脚本模块
+//!
+//! 用Rhai脚本描述固定间隔无法表达的点击流程。脚本运行在受限的引擎中：
+//! 不能加载模块或访问文件，调用深度和字符串、数组大小均有上限。
+//! 连点器停止后脚本在下一步操作前终止，且无法被脚本中的 `try` 捕获；
+//! 长时间不执行任何操作的脚本视为陷入死循环，同样被终止
+
+use crate::config::{ClickPoint, ScriptSettings};
+use crate::core::actions::ActionHost;
+use crate::core::clicker::ClickerState;
+use crate::core::condition::format_color;
+use crate::core::keyboard::parse_key;
+use crate::utils::Result;
+use enigo::Direction;
+use rhai::module_resolvers::DummyModuleResolver;
+use rhai::{Dynamic, Engine, EvalAltResult, Map, AST, INT};
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+/// 函数调用的最大嵌套层数
+const MAX_CALL_LEVELS: usize = 32;
+
+/// 表达式的最大嵌套深度（全局, 函数内）
+const MAX_EXPR_DEPTHS: (usize, usize) = (64, 32);
+
+/// 字符串的最大长度
+const MAX_STRING_SIZE: usize = 64 * 1024;
+
+/// 数组和对象的最大元素数
+const MAX_COLLECTION_SIZE: usize = 10_000;
+
+/// 两次操作之间允许执行的最大运算次数
+const MAX_IDLE_OPERATIONS: u64 = 50_000_000;
+
+/// 两次操作之间允许运行的最长时间
+const MAX_IDLE_TIME: Duration = Duration::from_secs(30);
+
+/// 每执行这么多次运算检查一次运行时间
+const TIME_CHECK_OPERATIONS: u64 = 1024;
+
+/// 脚本函数的返回值
+type ScriptResult<T> = std::result::Result<T, Box<EvalAltResult>>;
+
+/// 读取脚本源码，设置了文件路径时从文件读取
+pub fn load_source(settings: &ScriptSettings) -> Result<String> {
+    if settings.path.as_os_str().is_empty() {
+        return Ok(settings.source.clone());
+    }
+    std::fs::read_to_string(&settings.path).map_err(|e| {
+        t!(
+            "script.error.read",
+            path = settings.path.display(),
+            error = e
+        )
+    })
+}
+
+/// 检查脚本能否编译，在启动前发现语法错误
+pub fn check(source: &str) -> Result<()> {
+    compile(&sandbox(), source).map(|_| ())
+}
+
+/// 运行脚本，直到脚本结束、出错或连点器停止
+pub fn run<H: ActionHost + 'static>(source: &str, host: H) -> Result<()> {
+    execute(source, host, IdleLimits::default())
+}
+
+/// 死循环检测的上限，两次操作之间的运算次数或运行时间超过任一上限时终止脚本
+#[derive(Debug, Clone, Copy)]
+struct IdleLimits {
+    operations: u64,
+    time: Duration,
+}
+
+impl Default for IdleLimits {
+    fn default() -> Self {
+        Self {
+            operations: MAX_IDLE_OPERATIONS,
+            time: MAX_IDLE_TIME,
+        }
+    }
+}
+
+/// 脚本的宿主，记录脚本是否执行过操作
+struct ScriptHost<H> {
+    host: H,
+    /// 上次检查后是否执行过操作
+    acted: bool,
+}
+
+impl<H> ScriptHost<H> {
+    /// 执行操作，重新开始死循环检测
+    fn act(&mut self) -> &mut H {
+        self.acted = true;
+        &mut self.host
+    }
+}
+
+/// 按指定的死循环检测上限运行脚本
+fn execute<H: ActionHost + 'static>(source: &str, host: H, limits: IdleLimits) -> Result<()> {
+    let host = Rc::new(RefCell::new(ScriptHost { host, acted: false }));
+    let mut engine = sandbox();
+    register(&mut engine, &host, limits);
+
+    let ast = compile(&engine, source)?;
+    engine.run_ast(&ast).map_err(|e| match *e {
+        // 死循环检测以终止原因作为错误信息
+        EvalAltResult::ErrorTerminated(reason, _) if reason.is_string() => {
+            reason.into_string().unwrap_or_default()
+        }
+        e => e.to_string(),
+    })
+}
+
+/// 创建受限的脚本引擎
+fn sandbox() -> Engine {
+    let mut engine = Engine::new();
+    engine
+        .set_module_resolver(DummyModuleResolver::new())
+        .disable_symbol("eval")
+        .set_max_call_levels(MAX_CALL_LEVELS)
+        .set_max_expr_depths(MAX_EXPR_DEPTHS.0, MAX_EXPR_DEPTHS.1)
+        .set_max_string_size(MAX_STRING_SIZE)
+        .set_max_array_size(MAX_COLLECTION_SIZE)
+        .set_max_map_size(MAX_COLLECTION_SIZE)
+        .on_debug(|text, _, position| log::debug!("脚本 {}: {}", position, text));
+    engine
+}
+
+/// 编译脚本
+fn compile(engine: &Engine, source: &str) -> Result<AST> {
+    engine
+        .compile(source)
+        .map_err(|e| t!("script.error.compile", error = e))
+}
+
+/// 注册脚本可以调用的函数
+fn register<H: ActionHost + 'static>(
+    engine: &mut Engine,
+    host: &Rc<RefCell<ScriptHost<H>>>,
+    limits: IdleLimits,
+) {
+    // 连点器停止时终止脚本，包括不调用任何函数的死循环
+    let h = host.clone();
+    let idle_since = Cell::new((0, Instant::now()));
+    engine.on_progress(move |operations| {
+        let mut host = h.borrow_mut();
+        if !host.host.is_running() {
+            return Some(Dynamic::UNIT);
+        }
+        if std::mem::take(&mut host.acted) {
+            idle_since.set((operations, Instant::now()));
+            return None;
+        }
+
+        let (since_operations, since) = idle_since.get();
+        let idle = operations - since_operations;
+        if idle > limits.operations {
+            return Some(t!("script.error.idle_operations", count = limits.operations).into());
+        }
+        if idle % TIME_CHECK_OPERATIONS == 0 && since.elapsed() > limits.time {
+            return Some(
+                t!(
+                    "script.error.idle_time",
+                    seconds = limits.time.as_secs_f32()
+                )
+                .into(),
+            );
+        }
+        None
+    });
+
+    let h = host.clone();
+    engine.on_print(move |text| h.borrow_mut().host.log(text.to_string()));
+
+    let h = host.clone();
+    engine.register_fn("click", move || -> ScriptResult<()> {
+        Ok(h.borrow_mut().act().click(None, None)?)
+    });
+    let h = host.clone();
+    engine.register_fn("click", move |button: &str| -> ScriptResult<()> {
+        Ok(h.borrow_mut().act().click(Some(button.parse()?), None)?)
+    });
+    let h = host.clone();
+    engine.register_fn("click_at", move |x: INT, y: INT| -> ScriptResult<()> {
+        Ok(h.borrow_mut().act().click(None, Some(point(x, y)?))?)
+    });
+    let h = host.clone();
+    engine.register_fn(
+        "click_at",
+        move |x: INT, y: INT, button: &str| -> ScriptResult<()> {
+            Ok(h.borrow_mut()
+                .act()
+                .click(Some(button.parse()?), Some(point(x, y)?))?)
+        },
+    );
+    let h = host.clone();
+    engine.register_fn("move_to", move |x: INT, y: INT| -> ScriptResult<()> {
+        Ok(h.borrow_mut().act().move_to(point(x, y)?)?)
+    });
+    let h = host.clone();
+    engine.register_fn(
+        "drag",
+        move |x1: INT, y1: INT, x2: INT, y2: INT| -> ScriptResult<()> {
+            Ok(h.borrow_mut().act().drag(point(x1, y1)?, point(x2, y2)?)?)
+        },
+    );
+    let h = host.clone();
+    engine.register_fn("scroll", move |length: INT| -> ScriptResult<()> {
+        Ok(h.borrow_mut().act().scroll(int(length)?)?)
+    });
+
+    let h = host.clone();
+    engine.register_fn("type_text", move |text: &str| -> ScriptResult<()> {
+        Ok(h.borrow_mut().act().type_text(text)?)
+    });
+
+    for (name, direction) in [
+        ("key", Direction::Click),
+        ("key_down", Direction::Press),
+        ("key_up", Direction::Release),
+    ] {
+        let h = host.clone();
+        engine.register_fn(name, move |key: &str| -> ScriptResult<()> {
+            Ok(h.borrow_mut().act().key(parse_key(key)?, direction)?)
+        });
+    }
+
+    let h = host.clone();
+    engine.register_fn("sleep", move |ms: INT| -> ScriptResult<()> {
+        let ms = u64::try_from(ms).map_err(|_| t!("script.error.negative_sleep", ms = ms))?;
+        Ok(h.borrow_mut().act().sleep(Duration::from_millis(ms))?)
+    });
+    let h = host.clone();
+    engine.register_fn("get_pixel", move |x: INT, y: INT| -> ScriptResult<String> {
+        Ok(format_color(h.borrow_mut().act().pixel(point(x, y)?)?))
+    });
+    let h = host.clone();
+    engine.register_fn("get_cursor", move || -> ScriptResult<Map> {
+        let ClickPoint { x, y } = h.borrow_mut().act().cursor()?;
+        let mut map = Map::new();
+        map.insert("x".into(), Dynamic::from(INT::from(x)));
+        map.insert("y".into(), Dynamic::from(INT::from(y)));
+        Ok(map)
+    });
+    let h = host.clone();
+    engine.register_fn("status", move || -> Map {
+        let status = h.borrow().host.status();
+        let mut map = Map::new();
+        map.insert(
+            "clicks".into(),
+            Dynamic::from(INT::from(status.click_count)),
+        );
+        map.insert(
+            "target".into(),
+            status
+                .target_count
+                .map_or(Dynamic::UNIT, |target| Dynamic::from(INT::from(target))),
+        );
+        map.insert(
+            "runtime".into(),
+            Dynamic::from(INT::try_from(status.runtime_seconds).unwrap_or(INT::MAX)),
+        );
+        map.insert(
+            "running".into(),
+            Dynamic::from(status.state == ClickerState::Running),
+        );
+        map
+    });
+}
+
+/// 把脚本中的整数转换为坐标或滚动量
+fn int(value: INT) -> Result<i32> {
+    i32::try_from(value).map_err(|_| t!("script.error.out_of_range", value = value))
+}
+
+/// 由脚本中的坐标创建屏幕坐标
+fn point(x: INT, y: INT) -> Result<ClickPoint> {
+    Ok(ClickPoint {
+        x: int(x)?,
+        y: int(y)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::actions::tests::RecordingHost;
+
+    fn run_recorded(source: &str, stop_after: Option<usize>) -> (Result<()>, Vec<String>) {
+        let host = Rc::new(RefCell::new(RecordingHost {
+            stop_after,
+            ..RecordingHost::default()
+        }));
+        let result = run(source, SharedHost(host.clone()));
+        let performed = std::mem::take(&mut host.borrow_mut().performed);
+        (result, performed)
+    }
+
+    /// 让测试在脚本结束后仍能读取记录的操作
+    struct SharedHost(Rc<RefCell<RecordingHost>>);
+
+    impl ActionHost for SharedHost {
+        fn click(
+            &mut self,
+            button: Option<crate::config::MouseButton>,
+            point: Option<ClickPoint>,
+        ) -> Result<()> {
+            self.0.borrow_mut().click(button, point)
+        }
+
+        fn move_to(&mut self, point: ClickPoint) -> Result<()> {
+            self.0.borrow_mut().move_to(point)
+        }
+
+        fn scroll(&mut self, length: i32) -> Result<()> {
+            self.0.borrow_mut().scroll(length)
+        }
+
+        fn key(&mut self, key: enigo::Key, direction: Direction) -> Result<()> {
+            self.0.borrow_mut().key(key, direction)
+        }
+
+        fn type_text(&mut self, text: &str) -> Result<()> {
+            self.0.borrow_mut().type_text(text)
+        }
+
+        fn drag(&mut self, from: ClickPoint, to: ClickPoint) -> Result<()> {
+            self.0.borrow_mut().drag(from, to)
+        }
+
+        fn sleep(&mut self, duration: Duration) -> Result<()> {
+            self.0.borrow_mut().sleep(duration)
+        }
+
+        fn pixel(&mut self, point: ClickPoint) -> Result<[u8; 3]> {
+            self.0.borrow_mut().pixel(point)
+        }
+
+        fn cursor(&mut self) -> Result<ClickPoint> {
+            self.0.borrow_mut().cursor()
+        }
+
+        fn status(&self) -> crate::core::clicker::ClickerStatus {
+            self.0.borrow().status()
+        }
+
+        fn is_running(&self) -> bool {
+            self.0.borrow().is_running()
+        }
+
+        fn log(&mut self, message: String) {
+            self.0.borrow_mut().log(message)
+        }
+    }
+
+    #[test]
+    fn script_functions_reach_the_host() {
+        let source = r#"
+            click();
+            click("right");
+            click_at(10, 20);
+            move_to(30, 40);
+            drag(1, 2, 3, 4);
+            scroll(-3);
+            type_text("hi");
+            key("enter");
+            key_down("shift");
+            key_up("shift");
+            sleep(250);
+        "#;
+        let (result, performed) = run_recorded(source, None);
+        result.unwrap();
+        assert_eq!(
+            performed,
+            [
+                "click None",
+                "click Some(Right)",
+                "click None",
+                "move (30, 40)",
+                "drag (1, 2) (3, 4)",
+                "scroll -3",
+                "text hi",
+                "key Return Click",
+                "key Shift Press",
+                "key Shift Release",
+                "sleep 250",
+            ]
+        );
+    }
+
+    #[test]
+    fn invalid_arguments_are_script_errors() {
+        for source in [
+            r#"click("middle");"#,
+            r#"key("hyper");"#,
+            "sleep(-1);",
+            "scroll(10000000000);",
+        ] {
+            let (result, performed) = run_recorded(source, None);
+            assert!(result.is_err(), "{} 应当报错", source);
+            assert!(performed.is_empty());
+        }
+    }
+
+    #[test]
+    fn stopping_the_clicker_ends_the_script() {
+        let (result, performed) = run_recorded("loop { click(); }", Some(3));
+        assert!(result.is_err());
+        assert_eq!(performed.len(), 3);
+    }
+
+    #[test]
+    fn busy_loops_hit_the_operation_limit() {
+        let limits = IdleLimits {
+            operations: 10_000,
+            time: Duration::from_secs(3600),
+        };
+        let error = execute("loop {}", RecordingHost::default(), limits).unwrap_err();
+        assert_eq!(error, t!("script.error.idle_operations", count = 10_000));
+
+        // 限制的是两次操作之间的运算，持续操作的脚本不受影响
+        let host = Rc::new(RefCell::new(RecordingHost::default()));
+        execute(
+            "for i in 0..2000 { let x = i * 2; scroll(1); }",
+            SharedHost(host.clone()),
+            limits,
+        )
+        .unwrap();
+        assert_eq!(host.borrow().performed.len(), 2000);
+    }
+
+    #[test]
+    fn busy_loops_hit_the_time_limit() {
+        let limits = IdleLimits {
+            operations: u64::MAX,
+            time: Duration::from_millis(50),
+        };
+        let error = execute("loop {}", RecordingHost::default(), limits).unwrap_err();
+        assert_eq!(error, t!("script.error.idle_time", seconds = 0.05_f32));
+    }
+
+    #[test]
+    fn sandbox_limits_resources() {
+        // 无限递归受调用深度限制
+        let (result, _) = run_recorded("fn f(x) { f(x + 1) } f(0);", None);
+        assert!(result.is_err());
+        // 字符串长度受限
+        let (result, _) = run_recorded(r#"let s = "x"; loop { s += s; }"#, None);
+        assert!(result.is_err());
+        // 不能加载模块
+        let (result, _) = run_recorded(r#"import "os" as os;"#, None);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn check_rejects_syntax_errors() {
+        assert!(check("click(); sleep(100);").is_ok());
+
+        let error = check("click(").unwrap_err();
+        assert!(error.starts_with(t!("script.error.compile", error = "").trim_end()));
+        assert!(check("let = 1;").is_err());
+        assert!(check(r#"eval("click()");"#).is_err());
+    }
+}
@@ -0,0 +1,133 @@
+//! 运行统计模块
+//!
+//! 订阅连点器事件，统计每次运行的点击次数、失败次数和实际间隔，结束后写入历史记录
+
+use crate::config::{HistoryStore, SessionRecord};
+use crate::core::events::{ClickerEvent, EventBus, StopReason};
+use jiff::Timestamp;
+use std::thread;
+use std::time::Instant;
+
+/// 单次运行的统计数据
+struct SessionStats {
+    started_at: Timestamp,
+    profile: String,
+    target_count: Option<u32>,
+    click_count: u32,
+    failures: u32,
+    /// 上一次点击的时间
+    last_click: Option<Instant>,
+    interval_total_ms: f64,
+    interval_samples: u32,
+    min_interval_ms: Option<f64>,
+    max_interval_ms: Option<f64>,
+}
+
+impl SessionStats {
+    fn new(profile: String, target_count: Option<u32>) -> Self {
+        Self {
+            started_at: Timestamp::now(),
+            profile,
+            target_count,
+            click_count: 0,
+            failures: 0,
+            last_click: None,
+            interval_total_ms: 0.0,
+            interval_samples: 0,
+            min_interval_ms: None,
+            max_interval_ms: None,
+        }
+    }
+
+    /// 记录一次成功的点击
+    fn record_click(&mut self, count: u32, now: Instant) {
+        self.click_count = count;
+        if let Some(last) = self.last_click.replace(now) {
+            let interval = now.duration_since(last).as_secs_f64() * 1000.0;
+            self.interval_total_ms += interval;
+            self.interval_samples += 1;
+            self.min_interval_ms = Some(self.min_interval_ms.map_or(interval, |m| m.min(interval)));
+            self.max_interval_ms = Some(self.max_interval_ms.map_or(interval, |m| m.max(interval)));
+        }
+    }
+
+    /// 生成历史记录
+    fn finish(self, reason: &StopReason) -> SessionRecord {
+        SessionRecord {
+            started_at: self.started_at,
+            ended_at: Timestamp::now(),
+            profile: self.profile,
+            target_count: self.target_count,
+            click_count: self.click_count,
+            failures: self.failures,
+            avg_interval_ms: (self.interval_samples > 0)
+                .then(|| self.interval_total_ms / f64::from(self.interval_samples)),
+            min_interval_ms: self.min_interval_ms,
+            max_interval_ms: self.max_interval_ms,
+            stop_reason: reason.kind(),
+        }
+    }
+}
+
+/// 启动统计订阅者，每次运行结束后追加一条历史记录
+///
+/// 事件总线的所有发送端被丢弃后线程自动退出
+pub fn spawn_recorder(bus: &EventBus, store: HistoryStore) {
+    let receiver = bus.subscribe();
+    thread::spawn(move || {
+        let mut session: Option<SessionStats> = None;
+        for event in receiver {
+            match event {
+                // 模拟运行不记入历史
+                ClickerEvent::Started {
+                    profile,
+                    target_count,
+                    dry_run,
+                } => {
+                    session = (!dry_run).then(|| SessionStats::new(profile, target_count));
+                }
+                ClickerEvent::Clicked { count, at, .. } => {
+                    if let Some(stats) = &mut session {
+                        stats.record_click(count, at);
+                    }
+                }
+                ClickerEvent::ClickFailed => {
+                    if let Some(stats) = &mut session {
+                        stats.failures += 1;
+                    }
+                }
+                ClickerEvent::Stopped(reason) => {
+                    if let Some(stats) = session.take() {
+                        if let Err(e) = store.append(&stats.finish(&reason)) {
+                            log::error!("保存历史记录失败: {}", e);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::StopKind;
+    use std::time::Duration;
+
+    #[test]
+    fn intervals_use_event_timestamps() {
+        let mut stats = SessionStats::new("默认".to_string(), Some(3));
+        let start = Instant::now();
+        stats.record_click(1, start);
+        stats.record_click(2, start + Duration::from_millis(100));
+        stats.record_click(3, start + Duration::from_millis(400));
+
+        let record = stats.finish(&StopReason::TargetReached);
+        assert_eq!(record.click_count, 3);
+        assert_eq!(record.avg_interval_ms, Some(200.0));
+        assert_eq!(record.min_interval_ms, Some(100.0));
+        assert_eq!(record.max_interval_ms, Some(300.0));
+        assert_eq!(record.stop_reason, StopKind::TargetReached);
+    }
+}
@@ -0,0 +1,512 @@
+//! 图像模板匹配模块
+//!
+//! 在屏幕截图中查找参考图像，用归一化互相关（NCC）评估相似度。
+//! 匹配算法只依赖内存中的灰度图像，不需要显示器即可验证
+
+use crate::config::{ClickPoint, TemplateSettings};
+use crate::core::screen::ScreenReader;
+use crate::utils::Result;
+use std::path::Path;
+use std::time::Duration;
+
+/// 粗搜索时缩小后模板的最小边长
+const MIN_COARSE_SIZE: usize = 8;
+
+/// 粗搜索的最大缩小倍数
+const MAX_PYRAMID_FACTOR: usize = 4;
+
+/// 粗搜索保留的候选位置数量
+const COARSE_CANDIDATES: usize = 5;
+
+/// 灰度图像
+#[derive(Debug, Clone)]
+pub struct GrayImage {
+    pub width: usize,
+    pub height: usize,
+    /// 按行排列的亮度值（0-255）
+    pub pixels: Vec<f32>,
+}
+
+impl GrayImage {
+    /// 从RGB像素创建
+    pub fn from_rgb(width: usize, height: usize, pixels: &[[u8; 3]]) -> Self {
+        Self {
+            width,
+            height,
+            pixels: pixels
+                .iter()
+                .map(|&[r, g, b]| {
+                    0.299 * f32::from(r) + 0.587 * f32::from(g) + 0.114 * f32::from(b)
+                })
+                .collect(),
+        }
+    }
+
+    /// 读取PNG文件并转换为灰度
+    pub fn load_png(path: &Path) -> Result<Self> {
+        let image = image::open(path)
+            .map_err(|e| t!("template.error.load", path = path.display(), error = e))?
+            .to_luma8();
+        Ok(Self {
+            width: image.width() as usize,
+            height: image.height() as usize,
+            pixels: image.pixels().map(|p| f32::from(p.0[0])).collect(),
+        })
+    }
+
+    /// 获取像素亮度
+    fn get(&self, x: usize, y: usize) -> f32 {
+        self.pixels[y * self.width + x]
+    }
+
+    /// 按整数倍缩小，每个像素取对应区域的平均值
+    fn downscale(&self, factor: usize) -> Self {
+        let width = self.width / factor;
+        let height = self.height / factor;
+        let area = (factor * factor) as f32;
+        let mut pixels = Vec::with_capacity(width * height);
+        for y in 0..height {
+            for x in 0..width {
+                let mut sum = 0.0;
+                for dy in 0..factor {
+                    for dx in 0..factor {
+                        sum += self.get(x * factor + dx, y * factor + dy);
+                    }
+                }
+                pixels.push(sum / area);
+            }
+        }
+        Self {
+            width,
+            height,
+            pixels,
+        }
+    }
+}
+
+/// 匹配结果
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TemplateMatch {
+    /// 匹配区域左上角在被搜索图像中的坐标
+    pub x: usize,
+    pub y: usize,
+    pub width: usize,
+    pub height: usize,
+    /// 相似度，1表示完全一致
+    pub score: f32,
+}
+
+impl TemplateMatch {
+    /// 匹配区域中心
+    pub fn center(&self) -> (usize, usize) {
+        (self.x + self.width / 2, self.y + self.height / 2)
+    }
+}
+
+/// 去均值后的模板
+struct PreparedTemplate<'a> {
+    image: &'a GrayImage,
+    /// 每个像素减去平均亮度
+    centered: Vec<f64>,
+    /// 去均值后的平方和的平方根
+    norm: f64,
+}
+
+impl<'a> PreparedTemplate<'a> {
+    /// 模板亮度完全一致时无法计算相关性，返回None
+    fn new(image: &'a GrayImage) -> Option<Self> {
+        let count = image.pixels.len() as f64;
+        let mean = image.pixels.iter().map(|&v| f64::from(v)).sum::<f64>() / count;
+        let centered: Vec<f64> = image.pixels.iter().map(|&v| f64::from(v) - mean).collect();
+        let norm = centered.iter().map(|v| v * v).sum::<f64>().sqrt();
+        (norm > f64::EPSILON).then_some(Self {
+            image,
+            centered,
+            norm,
+        })
+    }
+}
+
+/// 积分图，用于快速计算任意窗口的亮度和与平方和
+struct IntegralImage {
+    width: usize,
+    sum: Vec<f64>,
+    square_sum: Vec<f64>,
+}
+
+impl IntegralImage {
+    fn new(image: &GrayImage) -> Self {
+        let width = image.width + 1;
+        let mut sum = vec![0.0; width * (image.height + 1)];
+        let mut square_sum = sum.clone();
+        for y in 0..image.height {
+            let (mut row, mut square_row) = (0.0, 0.0);
+            for x in 0..image.width {
+                let value = f64::from(image.get(x, y));
+                row += value;
+                square_row += value * value;
+                let index = (y + 1) * width + x + 1;
+                sum[index] = sum[index - width] + row;
+                square_sum[index] = square_sum[index - width] + square_row;
+            }
+        }
+        Self {
+            width,
+            sum,
+            square_sum,
+        }
+    }
+
+    /// 窗口内的亮度和与平方和
+    fn window(&self, x: usize, y: usize, width: usize, height: usize) -> (f64, f64) {
+        let at = |table: &[f64], x: usize, y: usize| table[y * self.width + x];
+        let area = |table: &[f64]| {
+            at(table, x + width, y + height) - at(table, x, y + height) - at(table, x + width, y)
+                + at(table, x, y)
+        };
+        (area(&self.sum), area(&self.square_sum))
+    }
+}
+
+/// 计算模板放在 (x, y) 处的归一化互相关
+fn score_at(
+    haystack: &GrayImage,
+    integral: &IntegralImage,
+    template: &PreparedTemplate,
+    x: usize,
+    y: usize,
+) -> f32 {
+    let (width, height) = (template.image.width, template.image.height);
+    let (sum, square_sum) = integral.window(x, y, width, height);
+    let variance = square_sum - sum * sum / (width * height) as f64;
+    if variance <= f64::EPSILON {
+        return 0.0;
+    }
+
+    // 模板已去均值，窗口均值项的贡献为0
+    let mut cross = 0.0;
+    for ty in 0..height {
+        let row = &haystack.pixels[(y + ty) * haystack.width + x..][..width];
+        let template_row = &template.centered[ty * width..][..width];
+        for (value, weight) in row.iter().zip(template_row) {
+            cross += f64::from(*value) * weight;
+        }
+    }
+    (cross / (variance.sqrt() * template.norm)) as f32
+}
+
+/// 在给定的左上角坐标范围内计算所有位置的相似度
+fn scores_in(
+    haystack: &GrayImage,
+    integral: &IntegralImage,
+    template: &PreparedTemplate,
+    xs: std::ops::RangeInclusive<usize>,
+    ys: std::ops::RangeInclusive<usize>,
+) -> Vec<TemplateMatch> {
+    let mut matches = Vec::new();
+    for y in ys {
+        for x in xs.clone() {
+            matches.push(TemplateMatch {
+                x,
+                y,
+                width: template.image.width,
+                height: template.image.height,
+                score: score_at(haystack, integral, template, x, y),
+            });
+        }
+    }
+    matches
+}
+
+/// 取相似度最高且互不相邻的若干位置
+fn top_candidates(mut matches: Vec<TemplateMatch>, count: usize) -> Vec<TemplateMatch> {
+    matches.sort_by(|a, b| b.score.total_cmp(&a.score));
+    let mut candidates: Vec<TemplateMatch> = Vec::with_capacity(count);
+    for candidate in matches {
+        if candidates.len() == count {
+            break;
+        }
+        let adjacent = candidates
+            .iter()
+            .any(|c| c.x.abs_diff(candidate.x) <= 1 && c.y.abs_diff(candidate.y) <= 1);
+        if !adjacent {
+            candidates.push(candidate);
+        }
+    }
+    candidates
+}
+
+/// 在图像中查找模板，返回相似度不低于阈值的最佳位置
+///
+/// 模板较大时先在缩小的图像上粗搜索，再在原图上对候选位置附近精确匹配
+pub fn find_template(
+    haystack: &GrayImage,
+    template: &GrayImage,
+    threshold: f32,
+) -> Option<TemplateMatch> {
+    if template.width == 0
+        || template.height == 0
+        || template.width > haystack.width
+        || template.height > haystack.height
+    {
+        return None;
+    }
+
+    let prepared = PreparedTemplate::new(template)?;
+    let integral = IntegralImage::new(haystack);
+    let max_x = haystack.width - template.width;
+    let max_y = haystack.height - template.height;
+
+    let factor =
+        (template.width.min(template.height) / MIN_COARSE_SIZE).clamp(1, MAX_PYRAMID_FACTOR);
+    let best = if factor == 1 {
+        scores_in(haystack, &integral, &prepared, 0..=max_x, 0..=max_y)
+            .into_iter()
+            .max_by(|a, b| a.score.total_cmp(&b.score))
+    } else {
+        let coarse_haystack = haystack.downscale(factor);
+        let coarse_template = template.downscale(factor);
+        let coarse_prepared = PreparedTemplate::new(&coarse_template)?;
+        let coarse_integral = IntegralImage::new(&coarse_haystack);
+        let coarse = scores_in(
+            &coarse_haystack,
+            &coarse_integral,
+            &coarse_prepared,
+            0..=coarse_haystack.width - coarse_template.width,
+            0..=coarse_haystack.height - coarse_template.height,
+        );
+
+        top_candidates(coarse, COARSE_CANDIDATES)
+            .into_iter()
+            .filter_map(|candidate| {
+                let (x, y) = (candidate.x * factor, candidate.y * factor);
+                let xs = x.saturating_sub(factor).min(max_x)..=(x + factor).min(max_x);
+                let ys = y.saturating_sub(factor).min(max_y)..=(y + factor).min(max_y);
+                scores_in(haystack, &integral, &prepared, xs, ys)
+                    .into_iter()
+                    .max_by(|a, b| a.score.total_cmp(&b.score))
+            })
+            .max_by(|a, b| a.score.total_cmp(&b.score))
+    };
+    best.filter(|m| m.score >= threshold)
+}
+
+/// 屏幕上的模板定位器
+pub struct TemplateLocator {
+    reader: ScreenReader,
+    template: GrayImage,
+    settings: TemplateSettings,
+}
+
+impl TemplateLocator {
+    /// 加载参考图像并连接屏幕，未启用时返回None
+    pub fn new(settings: &TemplateSettings, display: Option<&str>) -> Result<Option<Self>> {
+        if !settings.enabled {
+            return Ok(None);
+        }
+        Ok(Some(Self {
+            template: GrayImage::load_png(&settings.path)?,
+            reader: ScreenReader::new(display)?,
+            settings: settings.clone(),
+        }))
+    }
+
+    /// 找不到模板时持续重试的时长
+    pub fn timeout(&self) -> Duration {
+        Duration::from_millis(self.settings.timeout_ms)
+    }
+
+    /// 截取屏幕查找一次模板，返回加上偏移后的点击坐标
+    pub fn locate(&self) -> Result<Option<ClickPoint>> {
+        let (x, y, width, height) = match self.settings.region {
+            Some(region) => (region.x, region.y, region.width, region.height),
+            None => {
+                let (width, height) = self.reader.screen_size()?;
+                (0, 0, width, height)
+            }
+        };
+        let screen = self.reader.capture(x, y, width, height)?;
+        let haystack = GrayImage::from_rgb(
+            screen.width as usize,
+            screen.height as usize,
+            &screen.pixels,
+        );
+        Ok(locate_in(&haystack, (x, y), &self.template, &self.settings))
+    }
+}
+
+/// 在左上角位于屏幕坐标 `origin` 的截图中查找模板，返回加上偏移后的点击坐标
+fn locate_in(
+    haystack: &GrayImage,
+    origin: (i32, i32),
+    template: &GrayImage,
+    settings: &TemplateSettings,
+) -> Option<ClickPoint> {
+    let (x, y) = origin;
+    let found = find_template(haystack, template, settings.threshold)?;
+    log::debug!(
+        "找到模板图像，位置 ({}, {})，相似度 {:.3}",
+        found.x as i32 + x,
+        found.y as i32 + y,
+        found.score
+    );
+    let (center_x, center_y) = found.center();
+    Some(ClickPoint {
+        x: x + center_x as i32 + settings.offset.x,
+        y: y + center_y as i32 + settings.offset.y,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ScreenRegion;
+
+    /// 生成可复现的纹理图像，相邻像素经过平滑，缩小后仍保留特征
+    fn texture(width: usize, height: usize, seed: u32) -> GrayImage {
+        let mut state = seed;
+        let noise: Vec<f32> = (0..width * height)
+            .map(|_| {
+                state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+                (state >> 24) as f32
+            })
+            .collect();
+        let mut pixels = Vec::with_capacity(noise.len());
+        for y in 0..height {
+            for x in 0..width {
+                let (mut sum, mut count) = (0.0, 0.0);
+                for ny in y.saturating_sub(1)..(y + 2).min(height) {
+                    for nx in x.saturating_sub(1)..(x + 2).min(width) {
+                        sum += noise[ny * width + nx];
+                        count += 1.0;
+                    }
+                }
+                pixels.push(sum / count);
+            }
+        }
+        GrayImage {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    /// 截取图像的一部分
+    fn crop(image: &GrayImage, x: usize, y: usize, width: usize, height: usize) -> GrayImage {
+        GrayImage {
+            width,
+            height,
+            pixels: (y..y + height)
+                .flat_map(|row| image.pixels[row * image.width + x..][..width].to_vec())
+                .collect(),
+        }
+    }
+
+    /// 把 `patch` 复制到 `image` 的 (x, y) 处
+    fn paste(image: &mut GrayImage, patch: &GrayImage, x: usize, y: usize) {
+        for row in 0..patch.height {
+            let target = (y + row) * image.width + x;
+            image.pixels[target..target + patch.width]
+                .copy_from_slice(&patch.pixels[row * patch.width..][..patch.width]);
+        }
+    }
+
+    /// 不经过粗搜索，逐个位置计算相似度
+    fn full_search(haystack: &GrayImage, template: &GrayImage) -> TemplateMatch {
+        let prepared = PreparedTemplate::new(template).unwrap();
+        let integral = IntegralImage::new(haystack);
+        scores_in(
+            haystack,
+            &integral,
+            &prepared,
+            0..=haystack.width - template.width,
+            0..=haystack.height - template.height,
+        )
+        .into_iter()
+        .max_by(|a, b| a.score.total_cmp(&b.score))
+        .unwrap()
+    }
+
+    #[test]
+    fn finds_identical_image() {
+        let image = texture(12, 10, 1);
+        let found = find_template(&image, &image, 0.99).unwrap();
+        assert_eq!((found.x, found.y), (0, 0));
+        assert!(found.score > 0.999);
+    }
+
+    #[test]
+    fn finds_template_at_offset() {
+        let haystack = texture(64, 48, 2);
+        let template = crop(&haystack, 21, 13, 12, 9);
+        let found = find_template(&haystack, &template, 0.99).unwrap();
+        assert_eq!((found.x, found.y), (21, 13));
+        assert_eq!(found.center(), (27, 17));
+    }
+
+    #[test]
+    fn search_region_limits_matches_and_offsets_result() {
+        let template = texture(10, 10, 3);
+        let mut screen = texture(120, 80, 4);
+        paste(&mut screen, &template, 5, 5);
+        paste(&mut screen, &template, 90, 50);
+
+        // 只截取右下角的区域，左上角的同一图像不在搜索范围内
+        let region = ScreenRegion {
+            x: 60,
+            y: 40,
+            width: 60,
+            height: 40,
+        };
+        let settings = TemplateSettings {
+            region: Some(region),
+            offset: ClickPoint { x: 2, y: -1 },
+            ..TemplateSettings::default()
+        };
+        let haystack = crop(&screen, 60, 40, 60, 40);
+        let point = locate_in(&haystack, (region.x, region.y), &template, &settings);
+        assert_eq!(point, Some(ClickPoint { x: 97, y: 54 }));
+    }
+
+    #[test]
+    fn rejects_match_below_threshold() {
+        let haystack = texture(40, 30, 5);
+        let template = texture(10, 10, 6);
+        assert!(find_template(&haystack, &template, 0.9).is_none());
+        // 阈值足够低时返回最佳位置，说明只是被阈值过滤
+        assert!(find_template(&haystack, &template, -1.0).is_some());
+    }
+
+    #[test]
+    fn rejects_flat_template() {
+        let haystack = texture(40, 30, 7);
+        let template = GrayImage {
+            width: 6,
+            height: 6,
+            pixels: vec![128.0; 36],
+        };
+        assert!(find_template(&haystack, &template, 0.0).is_none());
+    }
+
+    #[test]
+    fn rejects_template_larger_than_haystack() {
+        let haystack = texture(20, 20, 8);
+        let template = texture(21, 10, 9);
+        assert!(find_template(&haystack, &template, 0.0).is_none());
+        let template = texture(10, 21, 9);
+        assert!(find_template(&haystack, &template, 0.0).is_none());
+    }
+
+    #[test]
+    fn coarse_search_agrees_with_full_search() {
+        let haystack = texture(160, 120, 10);
+        // 最短边24像素，粗搜索时缩小3倍；位置不是3的倍数
+        let template = crop(&haystack, 37, 29, 32, 24);
+        let expected = full_search(&haystack, &template);
+        assert_eq!((expected.x, expected.y), (37, 29));
+
+        let found = find_template(&haystack, &template, 0.9).unwrap();
+        assert_eq!((found.x, found.y), (expected.x, expected.y));
+        assert!((found.score - expected.score).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,431 @@
+//! 目标窗口模块
+//!
+//! 读取当前前台窗口的标题、类名和进程名，判断是否为设置的目标窗口。
+//! 读取前台窗口目前仅支持X11
+
+use crate::config::{ScreenRegion, TargetWindowSettings, WindowMatchKind};
+use crate::utils::Result;
+use regex::Regex;
+
+/// 窗口标识（X11窗口ID）
+pub type WindowId = u32;
+
+/// 窗口信息
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WindowInfo {
+    pub id: WindowId,
+    pub title: String,
+    /// WM_CLASS中的类名
+    pub class: String,
+    /// WM_CLASS中的实例名
+    pub instance: String,
+    /// 所属进程名，无法获取时为None
+    pub process: Option<String>,
+}
+
+impl WindowInfo {
+    /// 按匹配方式取出用于匹配的值
+    pub fn value(&self, kind: WindowMatchKind) -> Option<&str> {
+        match kind {
+            WindowMatchKind::Title => Some(&self.title),
+            WindowMatchKind::Class => Some(&self.class),
+            WindowMatchKind::Process => self.process.as_deref(),
+        }
+    }
+}
+
+/// 窗口匹配规则
+pub struct WindowMatcher {
+    kind: WindowMatchKind,
+    pattern: String,
+    title: Option<Regex>,
+}
+
+impl WindowMatcher {
+    /// 根据设置创建，标题正则表达式无效时返回错误
+    pub fn new(settings: &TargetWindowSettings) -> Result<Self> {
+        let title = match settings.kind {
+            WindowMatchKind::Title => Some(
+                Regex::new(&settings.pattern)
+                    .map_err(|e| t!("settings.error.window_regex", error = e))?,
+            ),
+            _ => None,
+        };
+        Ok(Self {
+            kind: settings.kind,
+            pattern: settings.pattern.trim().to_string(),
+            title,
+        })
+    }
+
+    /// 判断窗口是否匹配，类名同时接受实例名，类名和进程名不区分大小写
+    pub fn matches(&self, window: &WindowInfo) -> bool {
+        match self.kind {
+            WindowMatchKind::Title => self
+                .title
+                .as_ref()
+                .is_some_and(|regex| regex.is_match(&window.title)),
+            WindowMatchKind::Class => {
+                window.class.eq_ignore_ascii_case(&self.pattern)
+                    || window.instance.eq_ignore_ascii_case(&self.pattern)
+            }
+            WindowMatchKind::Process => window
+                .process
+                .as_ref()
+                .is_some_and(|process| process.eq_ignore_ascii_case(&self.pattern)),
+        }
+    }
+}
+
+/// 前台窗口读取器
+pub struct WindowInspector {
+    #[cfg(target_os = "linux")]
+    x11: x11::X11Windows,
+}
+
+impl WindowInspector {
+    /// 连接到指定的X11显示，None表示使用DISPLAY环境变量
+    #[cfg(target_os = "linux")]
+    pub fn new(display: Option<&str>) -> Result<Self> {
+        Ok(Self {
+            x11: x11::X11Windows::new(display)?,
+        })
+    }
+
+    /// 创建读取器
+    #[cfg(not(target_os = "linux"))]
+    pub fn new(_display: Option<&str>) -> Result<Self> {
+        Err(t!("window.error.unsupported").to_string())
+    }
+
+    /// 获取前台窗口，没有前台窗口时返回None
+    pub fn active_window(&self) -> Result<Option<WindowInfo>> {
+        #[cfg(target_os = "linux")]
+        return self.x11.active_window();
+        #[cfg(not(target_os = "linux"))]
+        Ok(None)
+    }
+
+    /// 窗口客户区在屏幕上的位置和尺寸
+    pub fn client_area(&self, window: WindowId) -> Result<ScreenRegion> {
+        #[cfg(target_os = "linux")]
+        return self.x11.client_area(window);
+        #[cfg(not(target_os = "linux"))]
+        {
+            let _ = window;
+            Err(t!("window.error.unsupported").to_string())
+        }
+    }
+
+    /// 在所有顶层窗口中查找第一个匹配的窗口，不要求位于前台
+    pub fn find_window(&self, matcher: &WindowMatcher) -> Result<Option<WindowInfo>> {
+        #[cfg(target_os = "linux")]
+        return Ok(self
+            .x11
+            .client_windows()?
+            .into_iter()
+            .find(|window| matcher.matches(window)));
+        #[cfg(not(target_os = "linux"))]
+        {
+            let _ = matcher;
+            Ok(None)
+        }
+    }
+}
+
+/// 目标窗口过滤器
+pub struct WindowFilter {
+    inspector: WindowInspector,
+    matcher: WindowMatcher,
+}
+
+impl WindowFilter {
+    /// 创建过滤器，未启用时返回None
+    pub fn new(settings: &TargetWindowSettings, display: Option<&str>) -> Result<Option<Self>> {
+        if !settings.enabled {
+            return Ok(None);
+        }
+        Ok(Some(Self {
+            matcher: WindowMatcher::new(settings)?,
+            inspector: WindowInspector::new(display)?,
+        }))
+    }
+
+    /// 前台窗口是否为目标窗口
+    pub fn is_focused(&self) -> Result<bool> {
+        Ok(self
+            .inspector
+            .active_window()?
+            .is_some_and(|window| self.matcher.matches(&window)))
+    }
+}
+
+/// 后台点击的目标窗口查找器
+pub struct WindowFinder {
+    inspector: WindowInspector,
+    matcher: WindowMatcher,
+}
+
+impl WindowFinder {
+    /// 按目标窗口的匹配规则创建，不受是否启用前台检查影响
+    pub fn new(settings: &TargetWindowSettings, display: Option<&str>) -> Result<Self> {
+        Ok(Self {
+            matcher: WindowMatcher::new(settings)?,
+            inspector: WindowInspector::new(display)?,
+        })
+    }
+
+    /// 查找目标窗口，窗口不存在时返回None
+    pub fn find(&self) -> Result<Option<WindowInfo>> {
+        self.inspector.find_window(&self.matcher)
+    }
+
+    /// 窗口客户区在屏幕上的位置和尺寸
+    pub fn client_area(&self, window: WindowId) -> Result<ScreenRegion> {
+        self.inspector.client_area(window)
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod x11 {
+    use super::WindowInfo;
+    use crate::config::ScreenRegion;
+    use crate::utils::Result;
+    use x11rb::connection::Connection;
+    use x11rb::protocol::xproto::{Atom, AtomEnum, ConnectionExt as _, Window};
+    use x11rb::rust_connection::RustConnection;
+
+    /// 读取属性的最大长度（32位单位）
+    const MAX_PROPERTY_LENGTH: u32 = 1024;
+
+    /// X11窗口读取器
+    pub(super) struct X11Windows {
+        conn: RustConnection,
+        root: Window,
+        net_active_window: Atom,
+        net_client_list: Atom,
+        net_wm_name: Atom,
+        net_wm_pid: Atom,
+        utf8_string: Atom,
+    }
+
+    impl X11Windows {
+        pub(super) fn new(display: Option<&str>) -> Result<Self> {
+            let (conn, screen_num) =
+                x11rb::connect(display).map_err(|e| t!("screen.error.connect", error = e))?;
+            let root = conn.setup().roots[screen_num].root;
+            let intern = |name: &[u8]| -> Result<Atom> {
+                Ok(conn
+                    .intern_atom(false, name)
+                    .map_err(|e| t!("window.error.query", error = e))?
+                    .reply()
+                    .map_err(|e| t!("window.error.query", error = e))?
+                    .atom)
+            };
+            let net_active_window = intern(b"_NET_ACTIVE_WINDOW")?;
+            let net_client_list = intern(b"_NET_CLIENT_LIST")?;
+            let net_wm_name = intern(b"_NET_WM_NAME")?;
+            let net_wm_pid = intern(b"_NET_WM_PID")?;
+            let utf8_string = intern(b"UTF8_STRING")?;
+
+            Ok(Self {
+                conn,
+                root,
+                net_active_window,
+                net_client_list,
+                net_wm_name,
+                net_wm_pid,
+                utf8_string,
+            })
+        }
+
+        /// 读取窗口属性的原始字节
+        fn property(&self, window: Window, property: Atom, type_: Atom) -> Result<Vec<u8>> {
+            let reply = self
+                .conn
+                .get_property(false, window, property, type_, 0, MAX_PROPERTY_LENGTH)
+                .map_err(|e| t!("window.error.query", error = e))?
+                .reply()
+                .map_err(|e| t!("window.error.query", error = e))?;
+            Ok(reply.value)
+        }
+
+        /// 读取32位属性的所有值
+        fn property_u32s(&self, window: Window, property: Atom, type_: Atom) -> Result<Vec<u32>> {
+            let value = self.property(window, property, type_)?;
+            Ok(value
+                .chunks_exact(4)
+                .map(|bytes| u32::from_ne_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+                .collect())
+        }
+
+        /// 读取32位属性的第一个值
+        fn property_u32(&self, window: Window, property: Atom, type_: Atom) -> Result<Option<u32>> {
+            Ok(self
+                .property_u32s(window, property, type_)?
+                .first()
+                .copied())
+        }
+
+        pub(super) fn active_window(&self) -> Result<Option<WindowInfo>> {
+            let window =
+                self.property_u32(self.root, self.net_active_window, AtomEnum::WINDOW.into())?;
+            match window.filter(|&window| window != x11rb::NONE) {
+                Some(window) => self.window_info(window).map(Some),
+                None => Ok(None),
+            }
+        }
+
+        pub(super) fn client_area(&self, window: Window) -> Result<ScreenRegion> {
+            let geometry = self
+                .conn
+                .get_geometry(window)
+                .map_err(|e| t!("window.error.query", error = e))?
+                .reply()
+                .map_err(|e| t!("window.error.query", error = e))?;
+            let origin = self
+                .conn
+                .translate_coordinates(window, self.root, 0, 0)
+                .map_err(|e| t!("window.error.query", error = e))?
+                .reply()
+                .map_err(|e| t!("window.error.query", error = e))?;
+            Ok(ScreenRegion {
+                x: i32::from(origin.dst_x),
+                y: i32::from(origin.dst_y),
+                width: u32::from(geometry.width),
+                height: u32::from(geometry.height),
+            })
+        }
+
+        /// 窗口管理器管理的所有顶层窗口
+        pub(super) fn client_windows(&self) -> Result<Vec<WindowInfo>> {
+            let windows =
+                self.property_u32s(self.root, self.net_client_list, AtomEnum::WINDOW.into())?;
+            // 窗口可能在读取期间关闭，跳过读取失败的窗口
+            Ok(windows
+                .into_iter()
+                .filter_map(|window| self.window_info(window).ok())
+                .collect())
+        }
+
+        /// 读取窗口的标题、类名和进程名
+        fn window_info(&self, window: Window) -> Result<WindowInfo> {
+            // 优先使用UTF-8标题，旧程序只设置WM_NAME
+            let mut title = self.property(window, self.net_wm_name, self.utf8_string)?;
+            if title.is_empty() {
+                title = self.property(window, AtomEnum::WM_NAME.into(), AtomEnum::STRING.into())?;
+            }
+
+            // WM_CLASS为 "实例名\0类名\0"
+            let class =
+                self.property(window, AtomEnum::WM_CLASS.into(), AtomEnum::STRING.into())?;
+            let mut parts = class
+                .split(|&b| b == 0)
+                .map(|part| String::from_utf8_lossy(part).into_owned());
+            let instance = parts.next().unwrap_or_default();
+            let class = parts.next().unwrap_or_default();
+
+            let process = self
+                .property_u32(window, self.net_wm_pid, AtomEnum::CARDINAL.into())?
+                .and_then(|pid| std::fs::read_to_string(format!("/proc/{}/comm", pid)).ok())
+                .map(|name| name.trim().to_string());
+
+            Ok(WindowInfo {
+                id: window,
+                title: String::from_utf8_lossy(&title).into_owned(),
+                class,
+                instance,
+                process,
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::AppSettings;
+
+    fn matcher(kind: WindowMatchKind, pattern: &str) -> WindowMatcher {
+        WindowMatcher::new(&TargetWindowSettings {
+            enabled: true,
+            kind,
+            pattern: pattern.to_string(),
+        })
+        .unwrap()
+    }
+
+    fn window(title: &str, class: &str, instance: &str, process: Option<&str>) -> WindowInfo {
+        WindowInfo {
+            id: 1,
+            title: title.to_string(),
+            class: class.to_string(),
+            instance: instance.to_string(),
+            process: process.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn title_is_a_case_sensitive_regex_search() {
+        let notepad = window("无标题 - Notepad", "Notepad", "notepad", None);
+
+        assert!(matcher(WindowMatchKind::Title, "Notepad").matches(&notepad));
+        assert!(matcher(WindowMatchKind::Title, "^无标题 - Notepad$").matches(&notepad));
+        assert!(!matcher(WindowMatchKind::Title, "^Notepad$").matches(&notepad));
+        assert!(!matcher(WindowMatchKind::Title, "notepad").matches(&notepad));
+        assert!(matcher(WindowMatchKind::Title, "(?i)notepad").matches(&notepad));
+        assert!(matcher(WindowMatchKind::Title, r"\w+pad$").matches(&notepad));
+    }
+
+    #[test]
+    fn class_matches_class_or_instance_exactly_ignoring_case() {
+        let firefox = window("Mozilla Firefox", "firefox", "Navigator", None);
+
+        assert!(matcher(WindowMatchKind::Class, "Firefox").matches(&firefox));
+        assert!(matcher(WindowMatchKind::Class, "navigator").matches(&firefox));
+        assert!(matcher(WindowMatchKind::Class, " firefox ").matches(&firefox));
+        assert!(!matcher(WindowMatchKind::Class, "fire").matches(&firefox));
+        assert!(!matcher(WindowMatchKind::Class, "Mozilla Firefox").matches(&firefox));
+    }
+
+    #[test]
+    fn process_matches_exactly_ignoring_case() {
+        let game = window("Game", "Game", "game", Some("Game.exe"));
+
+        assert!(matcher(WindowMatchKind::Process, "game.EXE").matches(&game));
+        assert!(!matcher(WindowMatchKind::Process, "game").matches(&game));
+        assert!(!matcher(WindowMatchKind::Process, "Game")
+            .matches(&window("Game", "Game", "game", None)));
+    }
+
+    #[test]
+    fn invalid_title_regex_is_rejected() {
+        let target_window = TargetWindowSettings {
+            enabled: true,
+            kind: WindowMatchKind::Title,
+            pattern: "Notepad (".to_string(),
+        };
+        let error = match WindowMatcher::new(&target_window) {
+            Ok(_) => panic!("无效的正则表达式应当报错"),
+            Err(e) => e,
+        };
+        assert!(error.starts_with(t!("settings.error.window_regex", error = "").trim_end()));
+
+        // 保存设置时用同样的规则检查，错误信息一致
+        let settings = AppSettings {
+            target_window,
+            ..AppSettings::default()
+        };
+        assert_eq!(settings.validate(), Err(error));
+
+        // 类名和进程名不是正则表达式
+        for kind in [WindowMatchKind::Class, WindowMatchKind::Process] {
+            let settings = TargetWindowSettings {
+                enabled: true,
+                kind,
+                pattern: "Notepad (".to_string(),
+            };
+            assert!(WindowMatcher::new(&settings).is_ok());
+        }
+    }
+}
@@ -0,0 +1,422 @@
+//! 英文文案
+
+/// 按键查找文案
+pub(super) fn get(key: &str) -> Option<&'static str> {
+    let text = match key {
+        "action.click" => "Click",
+        "action.drag" => "Drag",
+        "action.end_loop" => "End loop",
+        "action.key_press" => "Key down",
+        "action.key_release" => "Key up",
+        "action.key_tap" => "Key tap",
+        "action.loop" => "Loop",
+        "action.move" => "Move",
+        "action.scroll" => "Scroll",
+        "action.type_text" => "Type text",
+        "action.wait" => "Wait",
+        "app.error.start" => "Failed to start the application: {error}",
+        "app.init_error.message" => "The application failed to initialize:",
+        "app.init_error.retry" => "Retry",
+        "app.init_error.title" => "Initialization error",
+        "app.initializing" => "Initializing...",
+        "app.quit" => "Quit",
+        "app.title_admin" => "Mouse Clicker (Administrator)",
+        "backend.error.button_unsupported" => "The {backend} backend does not support button {button}",
+        "backend.error.enigo_init" => "Failed to initialize the mouse controller: {error}",
+        "backend.error.key_unsupported" => "The {backend} backend does not support keyboard keys",
+        "backend.error.linux_only" => "The {backend} backend is only available on Linux",
+        "backend.error.location_unsupported" => "The {backend} backend cannot read the pointer position",
+        "backend.error.move_unsupported" => "The {backend} backend cannot move the pointer",
+        "backend.error.size_unsupported" => "The {backend} backend cannot read the screen size",
+        "burst.clicks_per_burst" => "Clicks per burst:",
+        "burst.enabled" => "Enable burst mode",
+        "burst.hint" => "Replaces the click interval while enabled; scripts and sequences do not use bursts.",
+        "burst.inner_interval" => "Interval within burst:",
+        "burst.limit" => "Limit bursts:",
+        "burst.rest" => "Rest between bursts:",
+        "cli.doctor.failed" => "Some checks failed",
+        "cli.error.missing_value" => "{option} requires a value",
+        "cli.error.unknown_argument" => "Unknown argument: {argument}",
+        "cli.probe.backend" => "Input backend: {backend}",
+        "cli.probe.clicked" => "Left click succeeded",
+        "cli.probe.move_mismatch" => "Pointer move check failed: expected {expected}, got {actual}",
+        "cli.probe.moved" => "Pointer moved to: ({x}, {y})",
+        "cli.probe.pointer" => "Pointer position: ({x}, {y})",
+        "cli.probe.screen" => "Screen size: {width}x{height}",
+        "cli.run.started" => "Script started; press {hotkey} to stop",
+        "cli.usage" => "Usage: mouse-clicker [OPTIONS] [COMMAND]\n\nCommands:\n  probe                Check the input backend: read screen and pointer, move the pointer to the screen center and click\n  doctor               Diagnose display server, input permissions, hotkey and config directory, with suggested fixes\n  run <FILE>           Run a Rhai script file until it finishes or the hotkey is pressed\n  help                 Show this help\n\nOptions:\n  --backend <BACKEND>  Input backend: auto, enigo, uinput, xtest\n  --display <DISPLAY>  X11 display used by the XTest backend, e.g. :99\n  --dry-run            With run: print the inputs that would be sent instead of sending them\n  --fast               With --dry-run: fast-forward on a virtual clock instead of waiting",
+        "click_delivery.cursor" => "Move the cursor and click",
+        "click_delivery.window" => "Background click (cursor stays put)",
+        "click_target.monitor" => "Monitor {name} {point}",
+        "click_target.window" => "Window {point}",
+        "condition_mode.click_while_match" => "Click only while the color matches",
+        "condition_mode.stop_on_mismatch" => "Stop when the color changes",
+        "condition_mode.wait_for_match" => "Wait for the color before starting",
+        "control.start" => "Start",
+        "control.stop" => "Stop",
+        "corner.bottom_left" => "bottom-left corner",
+        "corner.bottom_right" => "bottom-right corner",
+        "corner.top_left" => "top-left corner",
+        "corner.top_right" => "top-right corner",
+        "diagnostics.config_dir.fix_missing" => "Make sure your home directory exists and is writable",
+        "diagnostics.config_dir.fix_permissions" => "Fix the owner or permissions of this directory, otherwise settings cannot be saved",
+        "diagnostics.config_dir.name" => "Config directory",
+        "diagnostics.config_dir.not_writable" => "{path} is not writable: {error}",
+        "diagnostics.config_dir.ok" => "{path} is writable",
+        "diagnostics.copy" => "Copy report",
+        "diagnostics.display.name" => "Display server",
+        "diagnostics.display.no_display" => "{session} session, neither DISPLAY nor WAYLAND_DISPLAY is set",
+        "diagnostics.display.no_display_fix" => "Run inside a graphical session, or pass an X11 display with --display",
+        "diagnostics.display.session" => "{session} session",
+        "diagnostics.display.wayland_mismatch" => "Wayland session, but using the {backend} backend",
+        "diagnostics.display.wayland_mismatch_fix" => "On Wayland, X11 backends can only control XWayland windows. Choose uinput in Settings → Input backend",
+        "diagnostics.elevation.fix" => "To control administrator windows, choose \"Restart as administrator\" in the Settings menu",
+        "diagnostics.elevation.missing" => "Not running as administrator; windows running as administrator cannot be clicked",
+        "diagnostics.elevation.name" => "Administrator rights",
+        "diagnostics.elevation.not_needed" => "Not needed on this platform",
+        "diagnostics.elevation.ok" => "Running as administrator",
+        "diagnostics.failsafe.disabled" => "Disabled",
+        "diagnostics.failsafe.fix" => "Use an input backend that can read the pointer position, or turn off the fail-safe under Settings → Fail-safe",
+        "diagnostics.failsafe.name" => "Fail-safe",
+        "diagnostics.failsafe.no_location" => "The {backend} backend cannot read the pointer position, so the fail-safe is not checked while running",
+        "diagnostics.failsafe.ok" => "Available, watching {bounds}",
+        "diagnostics.failsafe.unchecked" => "Not checked because the input backend could not be created: {error}",
+        "diagnostics.failsafe.unsupported" => "The current backend cannot support the fail-safe: {error}",
+        "diagnostics.fix" => "Suggested fix: {fix}",
+        "diagnostics.hotkey.fix" => "The key may be taken by another program; pick a different hotkey in the main window. Global hotkeys may not work on Wayland",
+        "diagnostics.hotkey.name" => "Hotkey registration",
+        "diagnostics.hotkey.ok" => "{hotkey} registered",
+        "diagnostics.injection.fix_linux" => "Set up uinput permissions as described in the error, or choose another backend in Settings → Input backend",
+        "diagnostics.injection.fix_macos" => "Allow Mouse Clicker in System Settings → Privacy & Security → Accessibility, then restart the app",
+        "diagnostics.injection.fix_windows" => "Restart the app as administrator and try again",
+        "diagnostics.injection.name" => "Input injection",
+        "diagnostics.injection.ok" => "{backend} backend works, pointer at ({x}, {y})",
+        "diagnostics.injection.ok_no_location" => "{backend} backend works (cannot read the pointer position)",
+        "diagnostics.monitors.fix_points" => "After the monitor layout changes, pick the click or color sample position again, or use a window- or monitor-relative position",
+        "diagnostics.monitors.fix_query" => "Points are not checked before starting when monitors cannot be enumerated; on X11 make sure DISPLAY is set correctly",
+        "diagnostics.monitors.name" => "Monitors",
+        "diagnostics.rerun" => "Check again",
+        "diagnostics.status.fail" => "FAIL",
+        "diagnostics.status.pass" => "PASS",
+        "diagnostics.status.warning" => "WARN",
+        "dry_run.clear" => "Clear",
+        "dry_run.copy" => "Copy",
+        "dry_run.dropped" => "{count} earlier entries dropped",
+        "dry_run.empty" => "No entries yet",
+        "dry_run.enabled" => "Dry run (no real clicks or key presses)",
+        "dry_run.entries" => "{count} entries",
+        "dry_run.fast_forward" => "Fast-forward (skip waiting)",
+        "dry_run.fast_forward_hint" => "Uses a virtual clock: every wait completes instantly while recorded times still follow the configured intervals. Stops automatically after 4 virtual hours",
+        "dry_run.hint" => "Timing, conditions, sequences and scripts run as usual, but inputs are only recorded and the run is not added to history.",
+        "dry_run.input.button" => "Mouse {button} {direction}",
+        "dry_run.input.key" => "Key {key} {direction}",
+        "dry_run.input.move" => "Move pointer to ({x}, {y})",
+        "dry_run.input.scroll" => "Scroll {length}",
+        "dry_run.input.text" => "Type \"{text}\"",
+        "dry_run.input.window_click" => "Send {button} to window {window} at ({x}, {y})",
+        "dry_run.skipped" => "Fast-forward produced too many entries; {count} more were not recorded",
+        "error.backend" => "Failed to create the mouse controller: {error}",
+        "error.click" => "Click failed: {error}",
+        "error.failsafe" => "The fail-safe is unavailable, so clicking was not started: {error}. Turn it off under Settings → Fail-safe to run anyway",
+        "error.panic" => "The clicker thread crashed",
+        "error.permission" => "Missing permission: {error}",
+        "error.screen" => "Pixel condition check failed: {error}",
+        "error.script" => "Script error: {error}",
+        "error.template" => "Template matching failed: {error}",
+        "error.window" => "Target window check failed: {error}",
+        "event.click_failed" => "Click was not performed",
+        "event.clicked" => "Clicked, count: {count}",
+        "event.coordinates_unchecked" => "Could not enumerate monitors, so saved coordinates were not checked against the screen: {reason}",
+        "event.dry_run" => "[dry run] {event}",
+        "event.events_dropped" => "Fast-forward produced too many events; {count} more were not broadcast",
+        "event.paused" => "Clicker paused: {reason}",
+        "event.profile_switched" => "Switched to profile: {name}",
+        "event.resumed" => "Clicker resumed",
+        "event.script_log" => "Script output: {message}",
+        "event.simulated" => "[dry run {elapsed}] {input}",
+        "event.started" => "Clicker started ({profile})",
+        "event.started_with_target" => "Clicker started ({profile}), target {target} clicks",
+        "event.step_started" => "Running step {step}",
+        "event.stopped" => "Clicker stopped: {reason}",
+        "failsafe.corner" => "pointer entered the {corner} of the screen",
+        "failsafe.corner_label" => "Stop when the pointer enters:",
+        "failsafe.drift_prefix" => "Stop when moved more than",
+        "failsafe.drift_suffix" => "pixels",
+        "failsafe.enabled" => "Enabled",
+        "failsafe.manual_move" => "pointer was moved manually by {distance} pixels",
+        "failsafe.no_corner" => "Don't check",
+        "history.column.avg_interval" => "Avg interval",
+        "history.column.clicks" => "Clicks",
+        "history.column.failures" => "Failures",
+        "history.column.profile" => "Profile",
+        "history.column.started" => "Started",
+        "history.column.stop_reason" => "Stop reason",
+        "history.duration_rate" => "Duration {duration} s, rate {rate}",
+        "history.empty" => "No records yet",
+        "history.error.export" => "Failed to export CSV: {error}",
+        "history.error.open" => "Failed to open the history file: {error}",
+        "history.error.read" => "Failed to read the history file: {error}",
+        "history.error.serialize" => "Failed to serialize history record: {error}",
+        "history.error.write" => "Failed to write the history file: {error}",
+        "history.export" => "Export CSV",
+        "history.exported" => "Exported to {path}",
+        "history.min_max" => "Min {min}, max {max}",
+        "history.rate" => "{rate} clicks/s",
+        "history.refresh" => "Refresh",
+        "history.stop.error" => "Stopped by an error",
+        "history.stop.failsafe" => "Fail-safe triggered",
+        "hotkey.error.init" => "Failed to initialize the hotkey manager: {error}",
+        "hotkey.error.register" => "Failed to register hotkey {key}: {error}",
+        "input_backend.auto" => "Auto",
+        "input_backend.unknown" => "Unknown input backend: {name}",
+        "key_picker.capture" => "Capture",
+        "key_picker.waiting" => "Press a key…",
+        "keyboard.error.unknown_key" => "Unknown key: {key}",
+        "main.click_count" => "Click count:",
+        "main.click_target" => "Click at:",
+        "main.click_target.clear" => "Clear",
+        "main.click_target.cursor" => "Pointer position",
+        "main.click_target.pick" => "Pick",
+        "main.click_target.window_center" => "Window center",
+        "main.error.click_anchor" => "Failed to convert the click position: {error}",
+        "main.error.init_clicker" => "Failed to initialize the clicker: {error}",
+        "main.error.invalid_count" => "Please enter a valid number (1-1000000)",
+        "main.error.invalid_interval" => "Please enter a valid number (1-60000 ms)",
+        "main.error.permission" => "Permission problem detected. Grant accessibility permission in system settings\n{error}",
+        "main.error.pick" => "Cannot start picking a point: {error}",
+        "main.error.pick_window" => "Failed to start picking a window: {error}",
+        "main.error.point_unmapped" => "Point {point} is not inside the target window or on a monitor",
+        "main.error.restart" => "Restart failed: {error}",
+        "main.error.save_profile" => "Failed to save profile: {error}",
+        "main.error.switch_profile" => "Failed to switch profile: {error}",
+        "main.error.title" => "Error",
+        "main.error.toggle" => "Failed to toggle the clicker: {error}",
+        "main.error.update_settings" => "Failed to update settings: {error}",
+        "main.error.window_not_found" => "The target window was not found, so the point cannot be made window-relative",
+        "main.hotkey" => "Hotkey:",
+        "main.interval" => "Interval (ms):",
+        "main.mouse_button" => "Mouse button:",
+        "main.ok" => "OK",
+        "main.unlimited" => "Unlimited",
+        "menu.about" => "About",
+        "menu.burst" => "Burst mode",
+        "menu.click_indicator" => "Show click locations",
+        "menu.dark_mode" => "Switch to dark mode",
+        "menu.diagnostics" => "Diagnostics",
+        "menu.dry_run" => "Dry run",
+        "menu.failsafe" => "Fail-safe",
+        "menu.hide_to_tray" => "Hide to tray",
+        "menu.history" => "History",
+        "menu.input_backend" => "Input backend",
+        "menu.language" => "Language / 语言",
+        "menu.language_auto" => "System default",
+        "menu.light_mode" => "Switch to light mode",
+        "menu.open_accessibility" => "Open accessibility settings",
+        "menu.pixel_condition" => "Color condition",
+        "menu.profiles" => "Profiles",
+        "menu.restart_admin" => "Restart as administrator",
+        "menu.script" => "Script",
+        "menu.sequence" => "Action sequence",
+        "menu.settings" => "Settings",
+        "menu.target_window" => "Target window",
+        "menu.template" => "Image matching",
+        "monitor.error.disconnected" => "Monitor {name} is not connected (connected: {monitors}); please pick the click position again",
+        "monitor.error.off_screen" => "Point {point} is not on any connected monitor (connected: {monitors}); please pick it again",
+        "monitor.error.query" => "Failed to read monitor information: {error}",
+        "monitor.error.unresolved" => "Cannot resolve click position {point}",
+        "monitor.error.unsupported" => "Enumerating monitors is not supported on this platform yet",
+        "monitor.primary" => "(primary)",
+        "monitor.screen" => "Screen",
+        "mouse.error.background_unsupported" => "Background clicks are not supported on this platform yet",
+        "mouse.error.current_exe" => "Failed to get the program path: {error}",
+        "mouse.error.key" => "Key {key} failed: {error}",
+        "mouse.error.left_click" => "Left click failed: {error}",
+        "mouse.error.left_press" => "Left button press failed: {error}",
+        "mouse.error.left_release" => "Left button release failed: {error}",
+        "mouse.error.macos_windows_only" => "This feature is only available on macOS and Windows",
+        "mouse.error.move" => "Failed to move the pointer: {error}",
+        "mouse.error.open_settings" => "Cannot open system settings: {error}",
+        "mouse.error.restart_admin" => "Failed to restart as administrator: {error}",
+        "mouse.error.right_click" => "Right click failed: {error}",
+        "mouse.error.right_press" => "Right button press failed: {error}",
+        "mouse.error.right_release" => "Right button release failed: {error}",
+        "mouse.error.scroll" => "Scroll failed: {error}",
+        "mouse.error.scroll_down" => "Scroll down failed: {error}",
+        "mouse.error.scroll_up" => "Scroll up failed: {error}",
+        "mouse.error.type_text" => "Failed to type text: {error}",
+        "mouse.error.window_click" => "Background click failed: {error}",
+        "mouse.error.windows_only" => "This feature is only available on Windows",
+        "mouse_button.left" => "Left click",
+        "mouse_button.left_long_press" => "Left long press",
+        "mouse_button.right" => "Right click",
+        "mouse_button.right_long_press" => "Right long press",
+        "mouse_button.scroll_down" => "Scroll down",
+        "mouse_button.scroll_up" => "Scroll up",
+        "mouse_button.unknown" => "Unknown mouse button: {name}; available: left, right, left_long, right_long, scroll_up, scroll_down",
+        "pause_reason.permission_denied" => "Accessibility permission missing",
+        "pause_reason.waiting_for_color" => "Waiting for the pixel color to match",
+        "pause_reason.window_not_focused" => "The target window is not in the foreground",
+        "pause_reason.window_not_found" => "The target window was not found",
+        "picker.countdown" => "Recording in {seconds}s",
+        "picker.hint" => "Move the pointer to the target; its position is recorded when the countdown ends",
+        "picker.monitor" => "Monitor {name}, scale ×{scale}, logical ({x}, {y})",
+        "picker.restart" => "Restart countdown",
+        "picker.title" => "Pick point",
+        "pixel_condition.color" => "Target color:",
+        "pixel_condition.enabled" => "Enabled",
+        "pixel_condition.point" => "Sample point: {point}",
+        "pixel_condition.radius" => "Sample radius:",
+        "pixel_condition.tolerance" => "Tolerance:",
+        "point_anchor.monitor" => "Monitor",
+        "point_anchor.screen" => "Screen",
+        "point_anchor.window" => "Window",
+        "point_anchor.window_percent" => "Window %",
+        "profile.default" => "Default",
+        "profile.error.create_dir" => "Failed to create the profiles directory: {error}",
+        "profile.error.invalid_name" => "Profile names cannot start with . or contain / \\ or : ({name})",
+        "profile.error.parse" => "Failed to parse profile {name}: {error}",
+        "profile.error.read" => "Failed to read profile {name}: {error}",
+        "profile.error.read_dir" => "Failed to read the profiles directory: {error}",
+        "profile.error.serialize" => "Failed to serialize profile: {error}",
+        "profile.error.write" => "Failed to write profile: {error}",
+        "profile.new_name" => "New profile name",
+        "profile.save" => "Save current profile",
+        "profile.save_as" => "Save as",
+        "screen.error.capture" => "Failed to read screen pixels: {error}",
+        "screen.error.capture_unsupported" => "Reading screen pixels is not supported on this platform yet",
+        "screen.error.connect" => "Cannot connect to the X11 display: {error}",
+        "screen.error.pixel_format" => "Unsupported pixel format ({bits} bits)",
+        "screen.error.visual" => "Unrecognized screen color format",
+        "script.clear_output" => "Clear output",
+        "script.enabled" => "Enable script (start button and hotkey run the script)",
+        "script.error.compile" => "Script syntax error: {error}",
+        "script.error.idle_operations" => "The script ran {count} operations without any action and may be stuck in a loop",
+        "script.error.idle_time" => "The script ran for {seconds} s without any action and may be stuck in a loop",
+        "script.error.negative_sleep" => "Sleep duration cannot be negative: {ms}",
+        "script.error.off_screen" => "Point {point} is off screen",
+        "script.error.out_of_range" => "Value out of range: {value}",
+        "script.error.read" => "Failed to read script file {path}: {error}",
+        "script.error.stopped" => "The clicker has stopped",
+        "script.no_output" => "No output yet",
+        "script.path" => "Script file:",
+        "script.path_hint" => "Leave empty to run the script below",
+        "script.run" => "Run",
+        "script.stop" => "Stop",
+        "script.using_file" => "Using the script file; the script below will not run",
+        "send_event.error.no_x11" => "Background clicking needs an X11 display, but DISPLAY is not set (pure Wayland sessions do not support background clicking)",
+        "send_event.error.query" => "Failed to query the target window: {error}",
+        "send_event.error.rejected" => "Could not deliver the synthetic event to the target window on display {display}; the window may have been closed: {error}",
+        "send_event.error.send" => "Failed to send the synthetic event: {error}",
+        "sequence.add" => "Add step",
+        "sequence.at_point" => "At point",
+        "sequence.conflict_hint" => "Unavailable while an action sequence is enabled",
+        "sequence.duplicate" => "Duplicate this step",
+        "sequence.empty" => "No steps yet",
+        "sequence.enabled" => "Enable sequence (runs all steps each round, waiting the click interval between rounds)",
+        "sequence.error.empty_text" => "Text to type is empty",
+        "sequence.error.step" => "Step {step}: {error}",
+        "sequence.error.unclosed_loop" => "No matching loop end",
+        "sequence.error.unmatched_end" => "No matching loop start",
+        "sequence.error.zero_loop" => "Loop count must be greater than 0",
+        "sequence.move_down" => "Move down",
+        "sequence.move_up" => "Move up",
+        "sequence.remove" => "Remove this step",
+        "sequence.run" => "Run sequence",
+        "sequence.stop" => "Stop",
+        "session.unknown" => "Unknown",
+        "settings.error.burst_clicks" => "Clicks per burst must be between 1 and 10000",
+        "settings.error.burst_interval" => "Interval within a burst must be between 1 and 60000 ms",
+        "settings.error.burst_rest" => "Rest between bursts cannot exceed 1 hour",
+        "settings.error.click_monitor" => "A monitor-relative click position needs a monitor name",
+        "settings.error.click_percent" => "A percentage click position must be between 0 and 100",
+        "settings.error.corner_margin" => "Fail-safe corner size must be between 1 and 200 pixels",
+        "settings.error.count_too_large" => "Click count cannot exceed 1,000,000",
+        "settings.error.create_dir" => "Failed to create the config directory: {error}",
+        "settings.error.empty_profile" => "Profile name cannot be empty",
+        "settings.error.empty_region" => "The search region width and height cannot be 0",
+        "settings.error.empty_script" => "A script file or script source is required when scripting is enabled",
+        "settings.error.empty_sequence" => "The action sequence needs at least one step when enabled",
+        "settings.error.empty_window_pattern" => "The target window pattern cannot be empty",
+        "settings.error.interval_too_long" => "Click interval cannot exceed 60 seconds",
+        "settings.error.no_config_dir" => "Cannot determine the config directory",
+        "settings.error.parse" => "Failed to parse the config file: {error}",
+        "settings.error.read" => "Failed to read the config file: {error}",
+        "settings.error.sample_radius" => "The sample radius cannot exceed {max} pixels",
+        "settings.error.script_and_sequence" => "The script and the action sequence cannot both be enabled",
+        "settings.error.sequence_conflict" => "Action sequences do not support the color condition, image matching or background clicks; turn them off first",
+        "settings.error.serialize" => "Failed to serialize settings: {error}",
+        "settings.error.template_threshold" => "The similarity threshold must be between 0 and 1",
+        "settings.error.template_timeout" => "The template retry timeout cannot exceed 60 seconds",
+        "settings.error.window_regex" => "Invalid target window title regex: {error}",
+        "settings.error.write" => "Failed to write the config file: {error}",
+        "settings.error.zero_bursts" => "Number of bursts cannot be 0",
+        "settings.error.zero_count" => "Click count cannot be 0",
+        "settings.error.zero_drift" => "Fail-safe movement distance cannot be 0",
+        "settings.error.zero_interval" => "Click interval cannot be 0",
+        "status.burst" => "Burst {burst} · {clicks}",
+        "status.clicks" => "Clicks: {count}",
+        "status.dry_run" => "Dry run",
+        "status.running" => "Running",
+        "status.state" => "Status: {state}",
+        "status.stopped" => "Stopped",
+        "status.time" => "Time: {time}",
+        "status.time_days" => "Time: {days}d {time}",
+        "stop_reason.bursts_completed" => "All bursts completed",
+        "stop_reason.color_mismatch" => "The pixel color changed",
+        "stop_reason.exit" => "Application exited",
+        "stop_reason.failsafe" => "Fail-safe: {trigger}",
+        "stop_reason.script_finished" => "Script finished",
+        "stop_reason.simulation_limit" => "Fast-forward simulation reached the 4-hour limit",
+        "stop_reason.target_reached" => "Target count reached",
+        "stop_reason.template_not_found" => "The reference image was not found before the timeout",
+        "stop_reason.user" => "Stopped by user",
+        "target_window.delivery" => "Delivery:",
+        "target_window.delivery_hint" => "Background clicks are sent as synthetic events. Some programs silently ignore them and no error is shown; if clicks have no effect, switch to moving the pointer",
+        "target_window.enabled" => "Only click while the target window is in the foreground",
+        "target_window.error.no_value" => "The picked window has no {kind}",
+        "target_window.pick" => "Pick window",
+        "template.enabled" => "Click where the image is found",
+        "template.error.load" => "Cannot read the reference image {path}: {error}",
+        "template.limit_region" => "Limit the search region",
+        "template.offset" => "Click offset X/Y:",
+        "template.path" => "Reference image (PNG):",
+        "template.region_size" => "Width/height:",
+        "template.threshold" => "Similarity:",
+        "template.timeout" => "Retry timeout (ms):",
+        "tray.error.gtk" => "Failed to initialize GTK: {error}",
+        "tray.error.icon" => "Failed to create the tray icon: {error}",
+        "tray.error.load_icon" => "Failed to load the tray icon: {error}",
+        "tray.error.menu" => "Failed to create the tray menu: {error}",
+        "tray.error.thread" => "The tray thread exited unexpectedly",
+        "tray.show_window" => "Show window",
+        "tray.switch_profile" => "Switch profile",
+        "tray.tooltip.running" => "Mouse Clicker - running ({profile}) {count}",
+        "tray.tooltip.stopped" => "Mouse Clicker - stopped ({profile})",
+        "uinput.error.create" => "Failed to create the uinput virtual device: {error}",
+        "uinput.error.missing" => "{path} not found. Load the kernel module: sudo modprobe uinput",
+        "uinput.error.not_in_group" => "No access to {path}: you are not in the input group. Run sudo usermod -aG input $USER and log in again",
+        "uinput.error.open" => "Cannot open {path}: {error}",
+        "uinput.error.relogin" => "No access to {path}: you were added to the input group, but need to log out and back in for it to take effect",
+        "uinput.error.write" => "Failed to write uinput events: {error}",
+        "uinput.error.wrong_group" => "No access to {path}: you are in the input group, but the device node does not belong to it. Add the udev rule KERNEL==\"uinput\", GROUP=\"input\", MODE=\"0660\" and reload udev",
+        "window.error.query" => "Failed to read window information: {error}",
+        "window.error.unsupported" => "Reading the foreground window is not supported on this platform yet",
+        "window_match.class" => "Class",
+        "window_match.process" => "Process",
+        "window_match.title" => "Title",
+        "window_picker.hint" => "Switch to the target window; the foreground window is recorded when the countdown ends",
+        "window_picker.none" => "No foreground window",
+        "window_picker.title" => "Pick window",
+        "xtest.display_unset" => "(DISPLAY not set)",
+        "xtest.error.connect" => "Cannot connect to X11 display {display}: {error}",
+        "xtest.error.query_extension" => "Failed to query the XTest extension: {error}",
+        "xtest.error.query_pointer" => "Failed to query the pointer position: {error}",
+        "xtest.error.rejected" => "X11 display {display} rejected the XTest event: {error}",
+        "xtest.error.send" => "Failed to send XTest event: {error}",
+        "xtest.error.unsupported" => "X11 display {display} does not support the XTest extension",
+        "xtest.error.x_out_of_range" => "X coordinate out of range: {x}",
+        "xtest.error.y_out_of_range" => "Y coordinate out of range: {y}",
+        _ => return None,
+    };
+    Some(text)
+}
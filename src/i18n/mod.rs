@@ -0,0 +1,210 @@
+//! 国际化模块
+//!
+//! 提供简体中文和英文两套文案，默认跟随系统语言，也可以在设置中手动指定。
+//! 文案通过 `t!` 宏按键查找，参数以 `{名称}` 占位
+
+mod en_us;
+mod zh_cn;
+
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// 界面语言
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Locale {
+    #[serde(rename = "zh-CN")]
+    ZhCn,
+    #[serde(rename = "en-US")]
+    EnUs,
+}
+
+impl std::fmt::Display for Locale {
+    /// 始终以该语言自身显示，便于在任何语言下找到自己的语言
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::ZhCn => "简体中文",
+            Self::EnUs => "English",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl Locale {
+    /// 获取所有语言
+    pub fn all() -> Vec<Locale> {
+        vec![Self::ZhCn, Self::EnUs]
+    }
+
+    /// 根据系统语言选择，中文环境使用简体中文，其余使用英文
+    pub fn detect() -> Self {
+        match sys_locale::get_locale() {
+            Some(tag) if tag.to_lowercase().starts_with("zh") => Self::ZhCn,
+            Some(_) => Self::EnUs,
+            None => Self::ZhCn,
+        }
+    }
+
+    /// 在该语言的文案表中查找
+    fn lookup(self, key: &str) -> Option<&'static str> {
+        match self {
+            Self::ZhCn => zh_cn::get(key),
+            Self::EnUs => en_us::get(key),
+        }
+    }
+}
+
+/// 当前语言
+static CURRENT: AtomicU8 = AtomicU8::new(0);
+
+/// 设置当前语言，None表示跟随系统
+pub fn set_locale(locale: Option<Locale>) {
+    let locale = locale.unwrap_or_else(Locale::detect);
+    CURRENT.store(locale as u8, Ordering::Relaxed);
+    log::info!("界面语言: {:?}", locale);
+}
+
+/// 获取当前语言
+pub fn locale() -> Locale {
+    match CURRENT.load(Ordering::Relaxed) {
+        1 => Locale::EnUs,
+        _ => Locale::ZhCn,
+    }
+}
+
+/// 查找文案，当前语言缺失时回退到简体中文，仍缺失时返回键本身
+pub fn tr(key: &'static str) -> &'static str {
+    locale()
+        .lookup(key)
+        .or_else(|| Locale::ZhCn.lookup(key))
+        .unwrap_or_else(|| {
+            log::warn!("缺少文案: {}", key);
+            key
+        })
+}
+
+/// 查找文案并替换 `{名称}` 占位符
+pub fn tr_args(key: &'static str, args: &[(&str, &dyn Display)]) -> String {
+    args.iter()
+        .fold(tr(key).to_string(), |text, (name, value)| {
+            text.replace(&format!("{{{}}}", name), &value.to_string())
+        })
+}
+
+/// 按键查找当前语言的文案
+///
+/// `t!("key")` 返回 `&'static str`，`t!("key", name = value)` 替换占位符后返回 `String`
+macro_rules! t {
+    ($key:literal) => {
+        crate::i18n::tr($key)
+    };
+    ($key:literal, $($name:ident = $value:expr),+ $(,)?) => {
+        crate::i18n::tr_args(
+            $key,
+            &[$((stringify!($name), &$value as &dyn std::fmt::Display)),+],
+        )
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use regex::Regex;
+    use std::collections::BTreeSet;
+    use std::path::Path;
+
+    /// 从文案表源码中取出所有键
+    fn catalog_keys(source: &str) -> BTreeSet<&str> {
+        source
+            .lines()
+            .filter_map(|line| line.trim().strip_prefix('"'))
+            .filter_map(|line| line.split_once("\" =>"))
+            .map(|(key, _)| key)
+            .collect()
+    }
+
+    /// 文案中的 `{名称}` 占位符
+    fn placeholders(text: &str) -> BTreeSet<&str> {
+        text.split('{')
+            .skip(1)
+            .filter_map(|part| part.split_once('}'))
+            .map(|(name, _)| name)
+            .collect()
+    }
+
+    /// 递归收集目录下的Rust源文件
+    fn rust_sources(dir: &Path, files: &mut Vec<String>) {
+        for entry in std::fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                rust_sources(&path, files);
+            } else if path.extension().is_some_and(|ext| ext == "rs") {
+                files.push(std::fs::read_to_string(path).unwrap());
+            }
+        }
+    }
+
+    #[test]
+    fn catalogs_have_the_same_keys() {
+        let zh = catalog_keys(include_str!("zh_cn.rs"));
+        let en = catalog_keys(include_str!("en_us.rs"));
+        assert!(!zh.is_empty());
+        assert_eq!(zh.difference(&en).collect::<Vec<_>>(), Vec::<&&str>::new());
+        assert_eq!(en.difference(&zh).collect::<Vec<_>>(), Vec::<&&str>::new());
+        for key in &zh {
+            assert!(Locale::ZhCn.lookup(key).is_some(), "{}", key);
+            assert!(Locale::EnUs.lookup(key).is_some(), "{}", key);
+        }
+    }
+
+    #[test]
+    fn translations_use_the_same_placeholders() {
+        for key in catalog_keys(include_str!("zh_cn.rs")) {
+            let zh = placeholders(Locale::ZhCn.lookup(key).unwrap());
+            let en = placeholders(Locale::EnUs.lookup(key).unwrap());
+            assert_eq!(zh, en, "{}", key);
+        }
+    }
+
+    #[test]
+    fn every_used_key_exists() {
+        let mut sources = Vec::new();
+        rust_sources(
+            &Path::new(env!("CARGO_MANIFEST_DIR")).join("src"),
+            &mut sources,
+        );
+        let usage = Regex::new(r#"\bt!\(\s*"([a-z0-9_]+(?:\.[a-z0-9_]+)+)""#).unwrap();
+        let missing: Vec<_> = sources
+            .iter()
+            .flat_map(|source| usage.captures_iter(source))
+            .map(|captures| captures[1].to_string())
+            .filter(|key| Locale::ZhCn.lookup(key).is_none())
+            .collect();
+        assert_eq!(missing, Vec::<String>::new());
+    }
+
+    #[test]
+    fn tr_args_replaces_every_placeholder() {
+        let text = tr_args(
+            "monitor.error.disconnected",
+            &[
+                ("name", &"DP-2"),
+                ("monitors", &"DP-1, HDMI-1"),
+                ("unused", &1),
+            ],
+        );
+        let expected = locale()
+            .lookup("monitor.error.disconnected")
+            .unwrap()
+            .replace("{name}", "DP-2")
+            .replace("{monitors}", "DP-1, HDMI-1");
+        assert_eq!(text, expected);
+        assert!(placeholders(&text).is_empty());
+    }
+
+    #[test]
+    fn missing_keys_fall_back_to_the_key() {
+        assert_eq!(tr("no.such.key"), "no.such.key");
+        assert_eq!(tr_args("no.such.key", &[("count", &3)]), "no.such.key");
+    }
+}
@@ -0,0 +1,422 @@
+//! 简体中文文案
+
+/// 按键查找文案
+pub(super) fn get(key: &str) -> Option<&'static str> {
+    let text = match key {
+        "action.click" => "点击",
+        "action.drag" => "拖动",
+        "action.end_loop" => "循环结束",
+        "action.key_press" => "按下按键",
+        "action.key_release" => "松开按键",
+        "action.key_tap" => "按键",
+        "action.loop" => "循环开始",
+        "action.move" => "移动",
+        "action.scroll" => "滚动",
+        "action.type_text" => "输入文本",
+        "action.wait" => "等待",
+        "app.error.start" => "启动应用程序失败: {error}",
+        "app.init_error.message" => "应用程序初始化失败:",
+        "app.init_error.retry" => "重试",
+        "app.init_error.title" => "初始化错误",
+        "app.initializing" => "正在初始化...",
+        "app.quit" => "退出",
+        "app.title_admin" => "Mouse Clicker (管理员)",
+        "backend.error.button_unsupported" => "{backend}后端不支持按键 {button}",
+        "backend.error.enigo_init" => "初始化鼠标控制器失败: {error}",
+        "backend.error.key_unsupported" => "{backend}后端不支持键盘按键",
+        "backend.error.linux_only" => "{backend}后端仅在Linux上可用",
+        "backend.error.location_unsupported" => "{backend}后端无法获取指针位置",
+        "backend.error.move_unsupported" => "{backend}后端不支持移动指针",
+        "backend.error.size_unsupported" => "{backend}后端无法获取屏幕尺寸",
+        "burst.clicks_per_burst" => "每组点击次数:",
+        "burst.enabled" => "启用连发",
+        "burst.hint" => "启用后点击间隔设置不再生效；脚本和操作序列不使用连发。",
+        "burst.inner_interval" => "组内间隔:",
+        "burst.limit" => "限制组数:",
+        "burst.rest" => "组间休息:",
+        "cli.doctor.failed" => "存在未通过的检查项",
+        "cli.error.missing_value" => "{option} 需要一个参数",
+        "cli.error.unknown_argument" => "未知参数: {argument}",
+        "cli.probe.backend" => "输入后端: {backend}",
+        "cli.probe.clicked" => "左键点击成功",
+        "cli.probe.move_mismatch" => "指针移动校验失败: 期望 {expected}，实际 {actual}",
+        "cli.probe.moved" => "指针已移动到: ({x}, {y})",
+        "cli.probe.pointer" => "指针位置: ({x}, {y})",
+        "cli.probe.screen" => "屏幕尺寸: {width}x{height}",
+        "cli.run.started" => "脚本已开始运行，按 {hotkey} 停止",
+        "cli.usage" => "用法: mouse-clicker [选项] [命令]\n\n命令:\n  probe                检查输入后端：读取屏幕和指针，移动指针到屏幕中心并点击\n  doctor               诊断显示服务器、输入权限、热键和配置目录，并给出修复建议\n  run <文件>          运行Rhai脚本文件，脚本结束或按下热键时退出\n  help                 显示此帮助\n\n选项:\n  --backend <后端>     输入后端: auto, enigo, uinput, xtest\n  --display <显示>     XTest后端连接的X11显示，例如 :99\n  --dry-run            与 run 一起使用：只打印本应执行的输入，不实际点击\n  --fast               与 --dry-run 一起使用：使用虚拟时钟快进，不实际等待",
+        "click_delivery.cursor" => "移动指针点击",
+        "click_delivery.window" => "后台点击（不移动指针）",
+        "click_target.monitor" => "显示器 {name} {point}",
+        "click_target.window" => "窗口 {point}",
+        "condition_mode.click_while_match" => "颜色匹配时才点击",
+        "condition_mode.stop_on_mismatch" => "颜色变化时停止",
+        "condition_mode.wait_for_match" => "等待颜色出现后开始",
+        "control.start" => "开始",
+        "control.stop" => "停止",
+        "corner.bottom_left" => "左下角",
+        "corner.bottom_right" => "右下角",
+        "corner.top_left" => "左上角",
+        "corner.top_right" => "右上角",
+        "diagnostics.config_dir.fix_missing" => "请确认用户主目录存在且可写",
+        "diagnostics.config_dir.fix_permissions" => "请修正该目录的所有者或权限，否则设置无法保存",
+        "diagnostics.config_dir.name" => "配置目录",
+        "diagnostics.config_dir.not_writable" => "{path} 不可写: {error}",
+        "diagnostics.config_dir.ok" => "{path} 可写",
+        "diagnostics.copy" => "复制报告",
+        "diagnostics.display.name" => "显示服务器",
+        "diagnostics.display.no_display" => "{session}会话，未检测到DISPLAY或WAYLAND_DISPLAY",
+        "diagnostics.display.no_display_fix" => "请在图形会话中运行，或通过 --display 指定X11显示",
+        "diagnostics.display.session" => "{session}会话",
+        "diagnostics.display.wayland_mismatch" => "Wayland会话，但使用的是{backend}后端",
+        "diagnostics.display.wayland_mismatch_fix" => "Wayland下X11后端只能操作XWayland窗口，请在「设置 → 输入后端」中选择uinput",
+        "diagnostics.elevation.fix" => "如需操作管理员窗口，请在「设置」菜单中选择「以管理员权限重启」",
+        "diagnostics.elevation.missing" => "未以管理员权限运行，无法点击以管理员权限运行的窗口",
+        "diagnostics.elevation.name" => "管理员权限",
+        "diagnostics.elevation.not_needed" => "当前平台不需要",
+        "diagnostics.elevation.ok" => "以管理员权限运行",
+        "diagnostics.failsafe.disabled" => "已关闭",
+        "diagnostics.failsafe.fix" => "换用能读取指针位置的输入后端，或在「设置 → 紧急停止」中关闭紧急停止",
+        "diagnostics.failsafe.name" => "紧急停止",
+        "diagnostics.failsafe.no_location" => "{backend} 后端无法读取指针位置，运行时不检查紧急停止",
+        "diagnostics.failsafe.ok" => "可用，检测范围 {bounds}",
+        "diagnostics.failsafe.unchecked" => "无法创建输入后端，未检查: {error}",
+        "diagnostics.failsafe.unsupported" => "当前后端无法支持紧急停止: {error}",
+        "diagnostics.fix" => "修复建议: {fix}",
+        "diagnostics.hotkey.fix" => "该按键可能已被其他程序占用，请在主界面换一个热键；Wayland下全局热键可能不可用",
+        "diagnostics.hotkey.name" => "热键注册",
+        "diagnostics.hotkey.ok" => "{hotkey} 已注册",
+        "diagnostics.injection.fix_linux" => "按照错误信息配置uinput权限，或在「设置 → 输入后端」中换用其他后端",
+        "diagnostics.injection.fix_macos" => "在「系统设置 → 隐私与安全性 → 辅助功能」中允许Mouse Clicker，然后重启应用",
+        "diagnostics.injection.fix_windows" => "请以管理员身份重启应用后重试",
+        "diagnostics.injection.name" => "输入注入",
+        "diagnostics.injection.ok" => "{backend}后端可用，指针位于 ({x}, {y})",
+        "diagnostics.injection.ok_no_location" => "{backend}后端可用（无法读取指针位置）",
+        "diagnostics.monitors.fix_points" => "显示器布局变化后请重新选取点击位置或颜色采样位置，或改用相对窗口/显示器的参照",
+        "diagnostics.monitors.fix_query" => "无法枚举显示器时不会在启动前检查坐标；X11下请确认DISPLAY设置正确",
+        "diagnostics.monitors.name" => "显示器",
+        "diagnostics.rerun" => "重新检查",
+        "diagnostics.status.fail" => "失败",
+        "diagnostics.status.pass" => "通过",
+        "diagnostics.status.warning" => "警告",
+        "dry_run.clear" => "清空",
+        "dry_run.copy" => "复制",
+        "dry_run.dropped" => "较早的 {count} 条已丢弃",
+        "dry_run.empty" => "暂无记录",
+        "dry_run.enabled" => "模拟运行（不实际点击或按键）",
+        "dry_run.entries" => "共 {count} 条",
+        "dry_run.fast_forward" => "快进（不实际等待）",
+        "dry_run.fast_forward_hint" => "使用虚拟时钟，所有等待立即完成，记录的时间仍按设置的间隔计算；虚拟时间达到4小时后自动停止",
+        "dry_run.hint" => "启用后照常计时、检查条件并执行序列或脚本，但只记录本应执行的输入，也不计入历史记录。",
+        "dry_run.input.button" => "鼠标 {button} {direction}",
+        "dry_run.input.key" => "按键 {key} {direction}",
+        "dry_run.input.move" => "移动指针到 ({x}, {y})",
+        "dry_run.input.scroll" => "滚动 {length}",
+        "dry_run.input.text" => "输入文本 \"{text}\"",
+        "dry_run.input.window_click" => "向窗口 {window} 的 ({x}, {y}) 发送{button}",
+        "dry_run.skipped" => "快进产生的记录过多，另有 {count} 条未记录",
+        "error.backend" => "创建鼠标控制器失败: {error}",
+        "error.click" => "点击操作失败: {error}",
+        "error.failsafe" => "紧急停止不可用，已拒绝开始: {error}。可在「设置 → 紧急停止」中关闭后再运行",
+        "error.panic" => "连点线程异常退出",
+        "error.permission" => "缺少权限: {error}",
+        "error.screen" => "像素条件检查失败: {error}",
+        "error.script" => "脚本出错: {error}",
+        "error.template" => "图像匹配失败: {error}",
+        "error.window" => "目标窗口检查失败: {error}",
+        "event.click_failed" => "点击未能执行",
+        "event.clicked" => "执行点击，当前计数: {count}",
+        "event.coordinates_unchecked" => "无法枚举显示器，未检查保存的坐标是否仍在屏幕上: {reason}",
+        "event.dry_run" => "[模拟] {event}",
+        "event.events_dropped" => "快进模拟产生的事件过多，另有 {count} 条未广播",
+        "event.paused" => "连点器已暂停: {reason}",
+        "event.profile_switched" => "已切换到配置: {name}",
+        "event.resumed" => "连点器已恢复",
+        "event.script_log" => "脚本输出: {message}",
+        "event.simulated" => "[模拟 {elapsed}] {input}",
+        "event.started" => "连点器已启动（{profile}）",
+        "event.started_with_target" => "连点器已启动（{profile}），目标 {target} 次",
+        "event.step_started" => "执行第 {step} 步",
+        "event.stopped" => "连点器已停止: {reason}",
+        "failsafe.corner" => "指针进入屏幕{corner}",
+        "failsafe.corner_label" => "指针移入角落时停止:",
+        "failsafe.drift_prefix" => "手动移动超过",
+        "failsafe.drift_suffix" => "像素时停止",
+        "failsafe.enabled" => "启用",
+        "failsafe.manual_move" => "指针被手动移动了{distance}像素",
+        "failsafe.no_corner" => "不检查",
+        "history.column.avg_interval" => "平均间隔",
+        "history.column.clicks" => "点击",
+        "history.column.failures" => "失败",
+        "history.column.profile" => "配置",
+        "history.column.started" => "开始时间",
+        "history.column.stop_reason" => "停止原因",
+        "history.duration_rate" => "时长 {duration} 秒，速率 {rate}",
+        "history.empty" => "暂无记录",
+        "history.error.export" => "导出CSV失败: {error}",
+        "history.error.open" => "打开历史文件失败: {error}",
+        "history.error.read" => "读取历史文件失败: {error}",
+        "history.error.serialize" => "序列化历史记录失败: {error}",
+        "history.error.write" => "写入历史文件失败: {error}",
+        "history.export" => "导出CSV",
+        "history.exported" => "已导出到 {path}",
+        "history.min_max" => "最短 {min}，最长 {max}",
+        "history.rate" => "{rate} 次/秒",
+        "history.refresh" => "刷新",
+        "history.stop.error" => "发生错误",
+        "history.stop.failsafe" => "紧急停止",
+        "hotkey.error.init" => "初始化热键管理器失败: {error}",
+        "hotkey.error.register" => "注册热键 {key} 失败: {error}",
+        "input_backend.auto" => "自动",
+        "input_backend.unknown" => "未知的输入后端: {name}",
+        "key_picker.capture" => "捕获",
+        "key_picker.waiting" => "请按键…",
+        "keyboard.error.unknown_key" => "未知的按键: {key}",
+        "main.click_count" => "点击次数:",
+        "main.click_target" => "点击位置:",
+        "main.click_target.clear" => "清除",
+        "main.click_target.cursor" => "指针位置",
+        "main.click_target.pick" => "选取",
+        "main.click_target.window_center" => "窗口中心",
+        "main.error.click_anchor" => "无法换算点击位置: {error}",
+        "main.error.init_clicker" => "初始化连点器失败: {error}",
+        "main.error.invalid_count" => "请输入有效的数字（1-1000000）",
+        "main.error.invalid_interval" => "请输入有效的数字（1-60000毫秒）",
+        "main.error.permission" => "检测到权限问题，请在系统设置中授予辅助功能权限\n{error}",
+        "main.error.pick" => "无法开始选取坐标: {error}",
+        "main.error.pick_window" => "无法开始选取窗口: {error}",
+        "main.error.point_unmapped" => "坐标 {point} 不在目标窗口或显示器上",
+        "main.error.restart" => "重启失败: {error}",
+        "main.error.save_profile" => "保存配置失败: {error}",
+        "main.error.switch_profile" => "切换配置失败: {error}",
+        "main.error.title" => "错误",
+        "main.error.toggle" => "切换连点器状态失败: {error}",
+        "main.error.update_settings" => "更新设置失败: {error}",
+        "main.error.window_not_found" => "找不到目标窗口，无法换算为窗口内坐标",
+        "main.hotkey" => "热键:",
+        "main.interval" => "点击间隔 (毫秒):",
+        "main.mouse_button" => "鼠标按键:",
+        "main.ok" => "确定",
+        "main.unlimited" => "无限制",
+        "menu.about" => "关于",
+        "menu.burst" => "连发",
+        "menu.click_indicator" => "显示点击位置",
+        "menu.dark_mode" => "切换到暗色模式",
+        "menu.diagnostics" => "诊断",
+        "menu.dry_run" => "模拟运行",
+        "menu.failsafe" => "紧急停止",
+        "menu.hide_to_tray" => "隐藏到托盘",
+        "menu.history" => "历史记录",
+        "menu.input_backend" => "输入后端",
+        "menu.language" => "语言 / Language",
+        "menu.language_auto" => "跟随系统",
+        "menu.light_mode" => "切换到亮色模式",
+        "menu.open_accessibility" => "打开辅助功能设置",
+        "menu.pixel_condition" => "颜色条件",
+        "menu.profiles" => "配置",
+        "menu.restart_admin" => "以管理员权限重启",
+        "menu.script" => "脚本",
+        "menu.sequence" => "操作序列",
+        "menu.settings" => "设置",
+        "menu.target_window" => "目标窗口",
+        "menu.template" => "图像匹配",
+        "monitor.error.disconnected" => "显示器 {name} 未连接（当前显示器: {monitors}），请重新选取点击位置",
+        "monitor.error.off_screen" => "坐标 {point} 不在任何已连接的显示器上（当前显示器: {monitors}），请重新选取",
+        "monitor.error.query" => "读取显示器信息失败: {error}",
+        "monitor.error.unresolved" => "无法换算点击位置 {point}",
+        "monitor.error.unsupported" => "当前平台暂不支持枚举显示器",
+        "monitor.primary" => "（主）",
+        "monitor.screen" => "屏幕",
+        "mouse.error.background_unsupported" => "当前平台暂不支持后台点击",
+        "mouse.error.current_exe" => "获取当前程序路径失败: {error}",
+        "mouse.error.key" => "按键 {key} 失败: {error}",
+        "mouse.error.left_click" => "左键点击失败: {error}",
+        "mouse.error.left_press" => "左键按下失败: {error}",
+        "mouse.error.left_release" => "左键释放失败: {error}",
+        "mouse.error.macos_windows_only" => "此功能仅在macOS和Windows上可用",
+        "mouse.error.move" => "移动指针失败: {error}",
+        "mouse.error.open_settings" => "无法打开系统设置: {error}",
+        "mouse.error.restart_admin" => "以管理员权限重启失败: {error}",
+        "mouse.error.right_click" => "右键点击失败: {error}",
+        "mouse.error.right_press" => "右键按下失败: {error}",
+        "mouse.error.right_release" => "右键释放失败: {error}",
+        "mouse.error.scroll" => "滚动失败: {error}",
+        "mouse.error.scroll_down" => "向下滚动失败: {error}",
+        "mouse.error.scroll_up" => "向上滚动失败: {error}",
+        "mouse.error.type_text" => "输入文本失败: {error}",
+        "mouse.error.window_click" => "后台点击失败: {error}",
+        "mouse.error.windows_only" => "此功能仅在Windows上可用",
+        "mouse_button.left" => "左键单击",
+        "mouse_button.left_long_press" => "左键长按",
+        "mouse_button.right" => "右键单击",
+        "mouse_button.right_long_press" => "右键长按",
+        "mouse_button.scroll_down" => "向下滚动",
+        "mouse_button.scroll_up" => "向上滚动",
+        "mouse_button.unknown" => "未知的鼠标按键: {name}，可用: left, right, left_long, right_long, scroll_up, scroll_down",
+        "pause_reason.permission_denied" => "缺少辅助功能权限",
+        "pause_reason.waiting_for_color" => "等待像素颜色匹配",
+        "pause_reason.window_not_focused" => "目标窗口不在前台",
+        "pause_reason.window_not_found" => "找不到目标窗口",
+        "picker.countdown" => "{seconds} 秒后记录",
+        "picker.hint" => "将指针移到目标位置，倒计时结束时记录坐标",
+        "picker.monitor" => "显示器 {name}，缩放 ×{scale}，逻辑坐标 ({x}, {y})",
+        "picker.restart" => "重新计时",
+        "picker.title" => "选取坐标",
+        "pixel_condition.color" => "目标颜色:",
+        "pixel_condition.enabled" => "启用",
+        "pixel_condition.point" => "采样位置: {point}",
+        "pixel_condition.radius" => "采样半径:",
+        "pixel_condition.tolerance" => "容差:",
+        "point_anchor.monitor" => "显示器内坐标",
+        "point_anchor.screen" => "屏幕坐标",
+        "point_anchor.window" => "窗口内坐标",
+        "point_anchor.window_percent" => "窗口百分比",
+        "profile.default" => "默认",
+        "profile.error.create_dir" => "创建配置方案目录失败: {error}",
+        "profile.error.invalid_name" => "配置名称不能以 . 开头，也不能包含 / \\ : 等字符: {name}",
+        "profile.error.parse" => "解析配置方案 {name} 失败: {error}",
+        "profile.error.read" => "读取配置方案 {name} 失败: {error}",
+        "profile.error.read_dir" => "读取配置方案目录失败: {error}",
+        "profile.error.serialize" => "序列化配置方案失败: {error}",
+        "profile.error.write" => "写入配置方案失败: {error}",
+        "profile.new_name" => "新配置名称",
+        "profile.save" => "保存当前配置",
+        "profile.save_as" => "另存为",
+        "screen.error.capture" => "读取屏幕像素失败: {error}",
+        "screen.error.capture_unsupported" => "当前平台暂不支持读取屏幕像素",
+        "screen.error.connect" => "无法连接X11显示: {error}",
+        "screen.error.pixel_format" => "不支持的像素格式（{bits}位）",
+        "screen.error.visual" => "无法识别屏幕的颜色格式",
+        "script.clear_output" => "清空输出",
+        "script.enabled" => "启用脚本（开始按钮和热键运行脚本）",
+        "script.error.compile" => "脚本语法错误: {error}",
+        "script.error.idle_operations" => "脚本连续执行 {count} 次运算没有任何操作，可能陷入了死循环",
+        "script.error.idle_time" => "脚本连续 {seconds} 秒没有任何操作，可能陷入了死循环",
+        "script.error.negative_sleep" => "等待时长不能为负数: {ms}",
+        "script.error.off_screen" => "坐标 {point} 不在屏幕上",
+        "script.error.out_of_range" => "数值超出范围: {value}",
+        "script.error.read" => "读取脚本文件 {path} 失败: {error}",
+        "script.error.stopped" => "连点器已停止",
+        "script.no_output" => "暂无输出",
+        "script.path" => "脚本文件:",
+        "script.path_hint" => "留空则运行下方脚本",
+        "script.run" => "运行",
+        "script.stop" => "停止",
+        "script.using_file" => "正在使用脚本文件，下方内容不会运行",
+        "send_event.error.no_x11" => "后台点击需要X11显示，当前会话未设置DISPLAY（纯Wayland会话不支持后台点击）",
+        "send_event.error.query" => "读取目标窗口失败: {error}",
+        "send_event.error.rejected" => "向目标窗口投递合成事件失败（显示 {display}），窗口可能已关闭: {error}",
+        "send_event.error.send" => "发送合成事件失败: {error}",
+        "sequence.add" => "添加步骤",
+        "sequence.at_point" => "指定位置",
+        "sequence.conflict_hint" => "启用操作序列时不可用",
+        "sequence.duplicate" => "复制此步骤",
+        "sequence.empty" => "还没有步骤",
+        "sequence.enabled" => "启用操作序列（每轮按顺序执行，轮间等待点击间隔）",
+        "sequence.error.empty_text" => "输入的文本为空",
+        "sequence.error.step" => "第 {step} 步: {error}",
+        "sequence.error.unclosed_loop" => "没有对应的循环结束",
+        "sequence.error.unmatched_end" => "没有对应的循环开始",
+        "sequence.error.zero_loop" => "循环次数必须大于0",
+        "sequence.move_down" => "下移",
+        "sequence.move_up" => "上移",
+        "sequence.remove" => "删除此步骤",
+        "sequence.run" => "运行序列",
+        "sequence.stop" => "停止",
+        "session.unknown" => "未知",
+        "settings.error.burst_clicks" => "每组点击次数必须在1到10000之间",
+        "settings.error.burst_interval" => "组内间隔必须在1到60000毫秒之间",
+        "settings.error.burst_rest" => "组间休息不能超过1小时",
+        "settings.error.click_monitor" => "相对显示器的点击位置缺少显示器名称",
+        "settings.error.click_percent" => "按百分比参照的点击位置必须在0-100之间",
+        "settings.error.corner_margin" => "紧急停止的角落范围必须在1-200像素之间",
+        "settings.error.count_too_large" => "点击次数不能超过100万次",
+        "settings.error.create_dir" => "创建配置目录失败: {error}",
+        "settings.error.empty_profile" => "配置名称不能为空",
+        "settings.error.empty_region" => "搜索区域的宽度和高度不能为0",
+        "settings.error.empty_script" => "启用脚本时需要填写脚本文件或脚本内容",
+        "settings.error.empty_sequence" => "启用操作序列时至少需要一个步骤",
+        "settings.error.empty_window_pattern" => "目标窗口的匹配规则不能为空",
+        "settings.error.interval_too_long" => "点击间隔不能超过60秒",
+        "settings.error.no_config_dir" => "无法获取配置目录",
+        "settings.error.parse" => "解析配置文件失败: {error}",
+        "settings.error.read" => "读取配置文件失败: {error}",
+        "settings.error.sample_radius" => "采样半径不能超过{max}像素",
+        "settings.error.script_and_sequence" => "脚本和操作序列不能同时启用",
+        "settings.error.sequence_conflict" => "操作序列不支持颜色条件、图像匹配和后台点击，请先关闭这些设置",
+        "settings.error.serialize" => "序列化设置失败: {error}",
+        "settings.error.template_threshold" => "相似度阈值必须在0到1之间",
+        "settings.error.template_timeout" => "图像匹配的重试时长不能超过60秒",
+        "settings.error.window_regex" => "目标窗口标题的正则表达式无效: {error}",
+        "settings.error.write" => "写入配置文件失败: {error}",
+        "settings.error.zero_bursts" => "连发组数不能为0",
+        "settings.error.zero_count" => "点击次数不能为0",
+        "settings.error.zero_drift" => "紧急停止的移动距离不能为0",
+        "settings.error.zero_interval" => "点击间隔不能为0",
+        "status.burst" => "第 {burst} 组 · {clicks}",
+        "status.clicks" => "点击: {count}",
+        "status.dry_run" => "模拟运行",
+        "status.running" => "运行中",
+        "status.state" => "状态: {state}",
+        "status.stopped" => "已停止",
+        "status.time" => "时间: {time}",
+        "status.time_days" => "时间: {days}天{time}",
+        "stop_reason.bursts_completed" => "所有连发已完成",
+        "stop_reason.color_mismatch" => "像素颜色已变化",
+        "stop_reason.exit" => "应用退出",
+        "stop_reason.failsafe" => "紧急停止: {trigger}",
+        "stop_reason.script_finished" => "脚本执行完毕",
+        "stop_reason.simulation_limit" => "快进模拟已达到4小时上限",
+        "stop_reason.target_reached" => "已达到目标次数",
+        "stop_reason.template_not_found" => "超时仍未找到参考图像",
+        "stop_reason.user" => "用户停止",
+        "target_window.delivery" => "点击方式:",
+        "target_window.delivery_hint" => "后台点击以合成事件发送，部分程序会忽略这类事件且不会有任何提示；点击没有效果时请改用移动指针点击",
+        "target_window.enabled" => "仅在目标窗口位于前台时点击",
+        "target_window.error.no_value" => "选取的窗口没有{kind}",
+        "target_window.pick" => "选取窗口",
+        "template.enabled" => "在匹配到的图像处点击",
+        "template.error.load" => "无法读取参考图像 {path}: {error}",
+        "template.limit_region" => "限定搜索区域",
+        "template.offset" => "点击偏移 X/Y:",
+        "template.path" => "参考图像 (PNG):",
+        "template.region_size" => "宽/高:",
+        "template.threshold" => "相似度:",
+        "template.timeout" => "重试时长 (毫秒):",
+        "tray.error.gtk" => "初始化GTK失败: {error}",
+        "tray.error.icon" => "创建托盘图标失败: {error}",
+        "tray.error.load_icon" => "加载托盘图标失败: {error}",
+        "tray.error.menu" => "创建托盘菜单失败: {error}",
+        "tray.error.thread" => "托盘线程异常退出",
+        "tray.show_window" => "显示主窗口",
+        "tray.switch_profile" => "切换配置",
+        "tray.tooltip.running" => "Mouse Clicker - 运行中（{profile}）{count}",
+        "tray.tooltip.stopped" => "Mouse Clicker - 已停止（{profile}）",
+        "uinput.error.create" => "创建uinput虚拟设备失败: {error}",
+        "uinput.error.missing" => "未找到 {path}，请加载内核模块: sudo modprobe uinput",
+        "uinput.error.not_in_group" => "无权访问 {path}：当前用户不在input组中。请执行 sudo usermod -aG input $USER 并重新登录",
+        "uinput.error.open" => "无法打开 {path}: {error}",
+        "uinput.error.relogin" => "无权访问 {path}：当前用户已加入input组，但需要注销并重新登录后才会生效",
+        "uinput.error.write" => "写入uinput事件失败: {error}",
+        "uinput.error.wrong_group" => "无权访问 {path}：当前用户已在input组中，但设备节点不属于该组。请添加udev规则 KERNEL==\"uinput\", GROUP=\"input\", MODE=\"0660\" 后重新加载udev",
+        "window.error.query" => "读取窗口信息失败: {error}",
+        "window.error.unsupported" => "当前平台暂不支持读取前台窗口",
+        "window_match.class" => "类名",
+        "window_match.process" => "进程名",
+        "window_match.title" => "标题",
+        "window_picker.hint" => "切换到目标窗口，倒计时结束时记录前台窗口",
+        "window_picker.none" => "没有前台窗口",
+        "window_picker.title" => "选取窗口",
+        "xtest.display_unset" => "(未设置DISPLAY)",
+        "xtest.error.connect" => "无法连接X11显示 {display}: {error}",
+        "xtest.error.query_extension" => "查询XTest扩展失败: {error}",
+        "xtest.error.query_pointer" => "查询指针位置失败: {error}",
+        "xtest.error.rejected" => "X11显示 {display} 拒绝了XTest事件: {error}",
+        "xtest.error.send" => "发送XTest事件失败: {error}",
+        "xtest.error.unsupported" => "X11显示 {display} 不支持XTest扩展",
+        "xtest.error.x_out_of_range" => "横坐标超出范围: {x}",
+        "xtest.error.y_out_of_range" => "纵坐标超出范围: {y}",
+        _ => return None,
+    };
+    Some(text)
+}
@@ -3,15 +3,72 @@
 //! 跨平台鼠标自动点击工具
 //! 支持Windows/macOS/Linux
 #![windows_subsystem = "windows"]
+// 文案宏需要在其他模块之前声明
+#[macro_use]
+mod i18n;
+
 mod app;
+mod cli;
 mod config;
 mod core;
 mod ui;
 mod utils;
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    // 启动应用程序
-    app::run_app().map_err(|e| {
-        Box::new(std::io::Error::new(std::io::ErrorKind::Other, e)) as Box<dyn std::error::Error>
-    })
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    env_logger::init();
+    i18n::set_locale(
+        config::SettingsManager::new()
+            .map(|manager| manager.get().language)
+            .unwrap_or_default(),
+    );
+
+    let args = cli::parse_args(std::env::args().skip(1));
+    // 只有图形界面不需要控制台，参数错误同样要输出到终端
+    if !matches!(
+        args,
+        Ok(cli::CliArgs {
+            command: cli::Command::Gui,
+            ..
+        })
+    ) {
+        attach_console();
+    }
+
+    let result = args.and_then(|args| {
+        core::backend::set_cli_override(args.backend, args.display);
+        match args.command {
+            // 启动应用程序
+            cli::Command::Gui => app::run_app(),
+            cli::Command::Probe => cli::run_probe(),
+            cli::Command::Doctor => cli::run_doctor(),
+            cli::Command::Run(path) => cli::run_script(path, args.dry_run, args.fast),
+            cli::Command::Help => {
+                println!("{}", cli::usage());
+                Ok(())
+            }
+        }
+    });
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// 连接启动本程序的终端的控制台
+///
+/// 本程序在Windows上按图形程序构建，没有自己的控制台，命令行子命令的输出需要写到父进程的控制台。
+/// 从资源管理器启动时没有父控制台，忽略失败
+fn attach_console() {
+    #[cfg(windows)]
+    let _ = unsafe {
+        windows::Win32::System::Console::AttachConsole(
+            windows::Win32::System::Console::ATTACH_PARENT_PROCESS,
+        )
+    };
 }
@@ -0,0 +1,139 @@
+//! 点击指示层
+//!
+//! 置顶、不接收鼠标事件的透明窗口，在每次点击的位置绘制逐渐消失的波纹，
+//! 便于调试时观察点击落点
+
+use crate::config::{MouseButton, ScreenRegion};
+use egui::{Color32, Context, Pos2, Stroke, Vec2, ViewportBuilder, ViewportClass, ViewportId};
+use std::time::{Duration, Instant};
+
+/// 波纹持续时间
+const RIPPLE_DURATION: Duration = Duration::from_millis(600);
+
+/// 波纹的起始和最终半径
+const RIPPLE_RADIUS: (f32, f32) = (4.0, 24.0);
+
+/// 动画刷新间隔
+const ANIMATION_INTERVAL: Duration = Duration::from_millis(16);
+
+/// 一次点击的波纹
+struct Ripple {
+    position: (i32, i32),
+    button: MouseButton,
+    created_at: Instant,
+}
+
+/// 点击指示层
+#[derive(Default)]
+pub struct ClickOverlay {
+    ripples: Vec<Ripple>,
+    /// 窗口覆盖的范围（物理像素），为所有显示器的外接矩形；
+    /// 无法枚举显示器时为None，只覆盖当前显示器
+    desktop: Option<ScreenRegion>,
+    /// 连点器运行期间保持窗口，避免每批波纹消失后重新创建
+    active: bool,
+}
+
+impl ClickOverlay {
+    /// 连点器开始运行，窗口覆盖 `desktop` 并保持到 [`Self::stop`]
+    pub fn start(&mut self, desktop: Option<ScreenRegion>) {
+        self.desktop = desktop;
+        self.active = true;
+    }
+
+    /// 连点器停止运行，剩余波纹消失后关闭窗口
+    pub fn stop(&mut self) {
+        self.active = false;
+    }
+
+    /// 在屏幕坐标处添加一个波纹
+    pub fn push(&mut self, position: (i32, i32), button: MouseButton) {
+        self.ripples.push(Ripple {
+            position,
+            button,
+            created_at: Instant::now(),
+        });
+    }
+
+    /// 绘制指示层，连点器未运行且没有波纹时关闭窗口
+    pub fn show(&mut self, ctx: &Context) {
+        self.ripples
+            .retain(|ripple| ripple.created_at.elapsed() < RIPPLE_DURATION);
+        if !self.active && self.ripples.is_empty() {
+            return;
+        }
+
+        // 窗口位置和尺寸以界面坐标给出，按物理像素换算
+        let scale = ctx.pixels_per_point();
+        let (origin, size) = match self.desktop {
+            Some(desktop) => (
+                (desktop.x, desktop.y),
+                Vec2::new(desktop.width as f32, desktop.height as f32) / scale,
+            ),
+            None => match ctx.input(|i| i.viewport().monitor_size) {
+                Some(monitor_size) => ((0, 0), monitor_size),
+                None => return,
+            },
+        };
+
+        let builder = ViewportBuilder::default()
+            .with_title("Mouse Clicker Overlay")
+            .with_position(Pos2::new(origin.0 as f32, origin.1 as f32) / scale)
+            .with_inner_size(size)
+            .with_decorations(false)
+            .with_transparent(true)
+            .with_taskbar(false)
+            .with_active(false)
+            .with_window_level(egui::WindowLevel::AlwaysOnTop)
+            .with_mouse_passthrough(true);
+
+        let ripples = &self.ripples;
+        ctx.show_viewport_immediate(
+            ViewportId::from_hash_of("click_overlay"),
+            builder,
+            |ctx, class| {
+                // 不支持多窗口的平台上无法显示
+                if class == ViewportClass::Embedded {
+                    return;
+                }
+                egui::CentralPanel::default()
+                    .frame(egui::Frame::none())
+                    .show(ctx, |ui| {
+                        // 点击坐标是物理像素，换算为相对窗口左上角的界面坐标
+                        let scale = ctx.pixels_per_point();
+                        let painter = ui.painter();
+                        for ripple in ripples {
+                            let progress = ripple.created_at.elapsed().as_secs_f32()
+                                / RIPPLE_DURATION.as_secs_f32();
+                            let (start, end) = RIPPLE_RADIUS;
+                            let center = Pos2::new(
+                                (ripple.position.0 - origin.0) as f32 / scale,
+                                (ripple.position.1 - origin.1) as f32 / scale,
+                            );
+                            let color = button_color(ripple.button).gamma_multiply(1.0 - progress);
+                            painter.circle_stroke(
+                                center,
+                                start + (end - start) * progress,
+                                Stroke::new(3.0, color),
+                            );
+                            painter.circle_filled(center, 3.0, color);
+                        }
+                    });
+            },
+        );
+        if !self.ripples.is_empty() {
+            ctx.request_repaint_after(ANIMATION_INTERVAL);
+        }
+    }
+}
+
+/// 各类按键的波纹颜色
+fn button_color(button: MouseButton) -> Color32 {
+    match button {
+        MouseButton::Left => Color32::from_rgb(0x3b, 0x82, 0xf6),
+        MouseButton::Right => Color32::from_rgb(0xf5, 0x9e, 0x0b),
+        MouseButton::LeftLongPress => Color32::from_rgb(0x8b, 0x5c, 0xf6),
+        MouseButton::RightLongPress => Color32::from_rgb(0xef, 0x44, 0x44),
+        MouseButton::ScrollUp | MouseButton::ScrollDown => Color32::from_rgb(0x22, 0xc5, 0x5e),
+    }
+}
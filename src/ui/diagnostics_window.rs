@@ -0,0 +1,81 @@
+//! 诊断报告窗口
+//!
+//! 展示启动诊断结果和修复建议
+
+use crate::core::diagnostics::{CheckStatus, DiagnosticReport};
+use egui::{Color32, Context, RichText};
+
+/// 诊断报告窗口
+#[derive(Default)]
+pub struct DiagnosticsWindow {
+    /// 最近一次诊断结果
+    report: Option<DiagnosticReport>,
+    /// 是否显示窗口
+    open: bool,
+    /// 用户是否请求重新检查
+    rerun_requested: bool,
+}
+
+impl DiagnosticsWindow {
+    /// 设置新的诊断结果并打开窗口
+    pub fn show_report(&mut self, report: DiagnosticReport) {
+        self.report = Some(report);
+        self.open = true;
+    }
+
+    /// 取出“重新检查”请求
+    pub fn take_rerun_request(&mut self) -> bool {
+        std::mem::take(&mut self.rerun_requested)
+    }
+
+    /// 绘制窗口
+    pub fn show(&mut self, ctx: &Context) {
+        let Some(report) = &self.report else {
+            return;
+        };
+
+        let mut open = self.open;
+        let mut rerun = false;
+        egui::Window::new(t!("menu.diagnostics"))
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(true)
+            .default_width(280.0)
+            .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
+            .show(ctx, |ui| {
+                egui::ScrollArea::vertical()
+                    .max_height(200.0)
+                    .show(ui, |ui| {
+                        for check in &report.checks {
+                            let color = match check.status {
+                                CheckStatus::Pass => Color32::GREEN,
+                                CheckStatus::Warning => Color32::YELLOW,
+                                CheckStatus::Fail => Color32::RED,
+                            };
+                            ui.horizontal_wrapped(|ui| {
+                                ui.colored_label(color, format!("[{}]", check.status));
+                                ui.label(RichText::new(check.name).strong());
+                            });
+                            ui.label(&check.detail);
+                            if let Some(fix) = &check.fix {
+                                ui.label(RichText::new(t!("diagnostics.fix", fix = fix)).italics());
+                            }
+                            ui.add_space(6.0);
+                        }
+                    });
+
+                ui.separator();
+                ui.horizontal(|ui| {
+                    if ui.button(t!("diagnostics.rerun")).clicked() {
+                        rerun = true;
+                    }
+                    if ui.button(t!("diagnostics.copy")).clicked() {
+                        ui.output_mut(|o| o.copied_text = report.to_text());
+                    }
+                });
+            });
+
+        self.open = open;
+        self.rerun_requested |= rerun;
+    }
+}
@@ -0,0 +1,140 @@
+//! 模拟运行窗口
+//!
+//! 开关模拟运行，并按时间顺序列出模拟运行中本应执行的输入
+
+use crate::core::backend::dry_run::{format_elapsed, SimulatedInput};
+use egui::{Context, RichText};
+use std::collections::VecDeque;
+use std::time::Duration;
+
+/// 保留的记录条数
+const MAX_TIMELINE_ENTRIES: usize = 5000;
+
+/// 模拟运行窗口
+#[derive(Default)]
+pub struct DryRunWindow {
+    /// 是否显示窗口
+    open: bool,
+    /// 是否启用模拟运行
+    enabled: bool,
+    /// 模拟运行时是否快进
+    fast_forward: bool,
+    /// 最近一次模拟运行的输入，最新的在后
+    timeline: VecDeque<String>,
+    /// 超出上限被丢弃的记录数
+    dropped: usize,
+    /// 快进时超出事件配额、未广播到窗口的记录数
+    skipped: usize,
+    /// 开关是否被修改
+    changed: bool,
+}
+
+impl DryRunWindow {
+    /// 打开窗口
+    pub fn open(&mut self) {
+        self.open = true;
+    }
+
+    /// 是否启用模拟运行
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// 模拟运行时是否快进
+    pub fn fast_forward(&self) -> bool {
+        self.fast_forward
+    }
+
+    /// 开始新的模拟运行，清空上一次的记录
+    pub fn start(&mut self) {
+        self.timeline.clear();
+        self.dropped = 0;
+        self.skipped = 0;
+    }
+
+    /// 追加一条记录
+    pub fn push(&mut self, elapsed: Duration, input: &SimulatedInput) {
+        if self.timeline.len() == MAX_TIMELINE_ENTRIES {
+            self.timeline.pop_front();
+            self.dropped += 1;
+        }
+        self.timeline
+            .push_back(format!("{}  {}", format_elapsed(elapsed), input));
+    }
+
+    /// 记录快进时未广播的记录数
+    pub fn skip(&mut self, count: usize) {
+        self.skipped += count;
+    }
+
+    /// 取出“开关已修改”标记
+    pub fn take_changed(&mut self) -> bool {
+        std::mem::take(&mut self.changed)
+    }
+
+    /// 绘制窗口
+    pub fn show(&mut self, ctx: &Context) {
+        if !self.open {
+            return;
+        }
+
+        let mut open = self.open;
+        egui::Window::new(t!("menu.dry_run"))
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(true)
+            .default_width(360.0)
+            .show(ctx, |ui| {
+                self.changed |= ui
+                    .checkbox(&mut self.enabled, t!("dry_run.enabled"))
+                    .changed();
+                ui.add_enabled_ui(self.enabled, |ui| {
+                    self.changed |= ui
+                        .checkbox(&mut self.fast_forward, t!("dry_run.fast_forward"))
+                        .on_hover_text(t!("dry_run.fast_forward_hint"))
+                        .changed();
+                });
+                ui.label(RichText::new(t!("dry_run.hint")).small());
+                ui.separator();
+
+                ui.horizontal(|ui| {
+                    ui.label(t!("dry_run.entries", count = self.timeline.len()));
+                    if ui.button(t!("dry_run.copy")).clicked() {
+                        let text = self.timeline.iter().cloned().collect::<Vec<_>>().join("\n");
+                        ui.output_mut(|output| output.copied_text = text);
+                    }
+                    if ui.button(t!("dry_run.clear")).clicked() {
+                        self.start();
+                    }
+                });
+                if self.dropped > 0 {
+                    ui.label(
+                        RichText::new(t!("dry_run.dropped", count = self.dropped))
+                            .small()
+                            .weak(),
+                    );
+                }
+                if self.skipped > 0 {
+                    ui.label(
+                        RichText::new(t!("dry_run.skipped", count = self.skipped))
+                            .small()
+                            .weak(),
+                    );
+                }
+
+                egui::ScrollArea::vertical()
+                    .max_height(320.0)
+                    .stick_to_bottom(true)
+                    .show(ui, |ui| {
+                        if self.timeline.is_empty() {
+                            ui.label(RichText::new(t!("dry_run.empty")).weak());
+                        }
+                        for line in &self.timeline {
+                            ui.label(RichText::new(line).monospace());
+                        }
+                    });
+            });
+
+        self.open = open;
+    }
+}
@@ -0,0 +1,163 @@
+//! 历史记录窗口
+//!
+//! 展示每次运行的统计数据，并支持导出CSV
+
+use crate::config::{HistoryStore, SessionRecord};
+use egui::{Context, RichText};
+use jiff::tz::TimeZone;
+
+/// 历史记录窗口
+#[derive(Default)]
+pub struct HistoryWindow {
+    /// 已加载的记录，最新的在前
+    records: Vec<SessionRecord>,
+    /// 是否显示窗口
+    open: bool,
+    /// 加载或导出的结果提示
+    message: Option<String>,
+}
+
+impl HistoryWindow {
+    /// 打开窗口并重新加载记录
+    pub fn open(&mut self) {
+        self.open = true;
+        self.reload();
+    }
+
+    /// 从历史文件重新加载
+    fn reload(&mut self) {
+        match HistoryStore::new().and_then(|store| store.load()) {
+            Ok(mut records) => {
+                records.reverse();
+                self.records = records;
+                self.message = None;
+            }
+            Err(e) => self.message = Some(e),
+        }
+    }
+
+    /// 导出CSV
+    fn export(&mut self) {
+        // 导出时按时间正序
+        let records: Vec<_> = self.records.iter().rev().cloned().collect();
+        self.message = Some(
+            match HistoryStore::new().and_then(|store| store.export_csv(&records)) {
+                Ok(path) => t!("history.exported", path = path.display()),
+                Err(e) => e,
+            },
+        );
+    }
+
+    /// 绘制窗口
+    pub fn show(&mut self, ctx: &Context) {
+        if !self.open {
+            return;
+        }
+
+        let mut open = self.open;
+        let mut reload = false;
+        let mut export = false;
+        egui::Window::new(t!("menu.history"))
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(true)
+            .default_width(280.0)
+            .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    reload = ui.button(t!("history.refresh")).clicked();
+                    export = ui
+                        .add_enabled(
+                            !self.records.is_empty(),
+                            egui::Button::new(t!("history.export")),
+                        )
+                        .clicked();
+                });
+                if let Some(message) = &self.message {
+                    ui.label(RichText::new(message).small());
+                }
+                ui.separator();
+
+                if self.records.is_empty() {
+                    ui.label(t!("history.empty"));
+                    return;
+                }
+
+                egui::ScrollArea::both().max_height(200.0).show(ui, |ui| {
+                    egui::Grid::new("history_grid")
+                        .striped(true)
+                        .num_columns(6)
+                        .show(ui, |ui| {
+                            for header in [
+                                "history.column.started",
+                                "history.column.profile",
+                                "history.column.clicks",
+                                "history.column.failures",
+                                "history.column.avg_interval",
+                                "history.column.stop_reason",
+                            ]
+                            .map(crate::i18n::tr)
+                            {
+                                ui.label(RichText::new(header).strong());
+                            }
+                            ui.end_row();
+
+                            for record in &self.records {
+                                ui.label(format_time(record));
+                                ui.label(&record.profile);
+                                ui.label(match record.target_count {
+                                    Some(target) => format!("{}/{}", record.click_count, target),
+                                    None => record.click_count.to_string(),
+                                })
+                                .on_hover_text(t!(
+                                    "history.duration_rate",
+                                    duration = format!("{:.1}", record.duration_seconds()),
+                                    rate = record
+                                        .clicks_per_second()
+                                        .map(|r| t!("history.rate", rate = format!("{:.2}", r)))
+                                        .unwrap_or_else(|| "-".to_string())
+                                ));
+                                ui.label(record.failures.to_string());
+                                ui.label(
+                                    record
+                                        .avg_interval_ms
+                                        .map(|v| format!("{:.0}ms", v))
+                                        .unwrap_or_else(|| "-".to_string()),
+                                )
+                                .on_hover_text(t!(
+                                    "history.min_max",
+                                    min = format_ms(record.min_interval_ms),
+                                    max = format_ms(record.max_interval_ms)
+                                ));
+                                ui.label(record.stop_reason.to_string());
+                                ui.end_row();
+                            }
+                        });
+                });
+            });
+
+        self.open = open;
+        if reload {
+            self.reload();
+        }
+        if export {
+            self.export();
+        }
+    }
+}
+
+/// 以本地时区显示开始时间
+fn format_time(record: &SessionRecord) -> String {
+    record
+        .started_at
+        .to_zoned(TimeZone::system())
+        .strftime("%m-%d %H:%M:%S")
+        .to_string()
+}
+
+/// 显示可选的毫秒数
+fn format_ms(value: Option<f64>) -> String {
+    value
+        .map(|v| format!("{:.0}ms", v))
+        .unwrap_or_else(|| "-".to_string())
+}
@@ -0,0 +1,57 @@
+//! 按键选择器
+//!
+//! 从常用按键中选择，或点击「捕获」后直接按下要使用的按键
+
+use crate::core::keyboard::{parse_key, KEY_NAMES};
+use egui::{Event, Ui};
+use std::hash::Hash;
+
+/// 绘制按键选择器，返回按键是否改变
+///
+/// 修饰键不会单独产生按键事件，只能从列表中选择
+pub fn key_picker(ui: &mut Ui, id_source: impl Hash, key: &mut String) -> bool {
+    let id = ui.make_persistent_id(id_source);
+    let mut capturing = ui.data(|data| data.get_temp::<bool>(id).unwrap_or(false));
+    let mut changed = false;
+
+    ui.horizontal(|ui| {
+        egui::ComboBox::from_id_source(id.with("list"))
+            .selected_text(key.as_str())
+            .width(80.0)
+            .show_ui(ui, |ui| {
+                for name in KEY_NAMES {
+                    if ui.selectable_label(key == name, *name).clicked() {
+                        *key = name.to_string();
+                        changed = true;
+                    }
+                }
+            });
+
+        let text = if capturing {
+            t!("key_picker.waiting")
+        } else {
+            t!("key_picker.capture")
+        };
+        if ui.selectable_label(capturing, text).clicked() {
+            capturing = !capturing;
+        } else if capturing {
+            // egui的按键名称转为小写后与按键名称一致，无法识别的按键忽略
+            let captured = ui.input(|input| {
+                input.events.iter().find_map(|event| match event {
+                    Event::Key {
+                        key, pressed: true, ..
+                    } => Some(key.name().to_lowercase()),
+                    _ => None,
+                })
+            });
+            if let Some(name) = captured.filter(|name| parse_key(name).is_ok()) {
+                *key = name;
+                changed = true;
+                capturing = false;
+            }
+        }
+    });
+
+    ui.data_mut(|data| data.insert_temp(id, capturing));
+    changed
+}
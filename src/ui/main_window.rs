@@ -2,12 +2,32 @@
 //!
 //! 使用egui实现连点器主界面
 
-use crate::config::{AppSettings, FunctionKey, MouseButton};
+use crate::config::{
+    AppSettings, ClickDelivery, ClickPoint, ClickTarget, ConditionMode, FunctionKey, InputBackend,
+    MouseButton, PointAnchor, ProfileStore, ScreenCorner, ScreenRegion, WindowMatchKind,
+    MAX_SAMPLE_RADIUS,
+};
+use crate::core::backend::BackendConfig;
+use crate::core::condition::format_color;
+use crate::core::diagnostics::{DiagnosticReport, HotkeyProbe};
+use crate::core::monitor::MonitorLayout;
 use crate::core::mouse::MouseController;
-use crate::core::{ClickerManager, ClickerState, ClickerStatus};
+use crate::core::window::{WindowFinder, WindowInfo};
+use crate::core::{
+    ClickerEvent, ClickerManager, ClickerState, ClickerStatus, ErrorKind, StopReason,
+};
+use crate::i18n::{self, Locale};
+use crate::ui::{
+    ClickOverlay, DiagnosticsWindow, DryRunWindow, HistoryWindow, PickPurpose, PointPicker,
+    ScriptWindow, SequenceWindow, WindowPicker,
+};
 use crate::utils::{detect_system_dark_mode, Result};
 use egui::{Color32, Context, RichText, Ui};
 use git_version::git_version;
+use std::sync::mpsc::Receiver;
+
+/// 主界面内容的最大宽度
+const MAX_CONTENT_WIDTH: f32 = 480.0;
 
 /// 主窗口应用程序状态
 pub struct MainWindow {
@@ -15,12 +35,32 @@ pub struct MainWindow {
     settings: AppSettings,
     /// 连点器管理器
     clicker_manager: Option<ClickerManager>,
+    /// 连点器事件
+    clicker_events: Option<Receiver<ClickerEvent>>,
     /// 当前状态
     current_status: ClickerStatus,
     /// 错误消息
     error_message: Option<String>,
     /// UI状态
     ui_state: UiState,
+    /// 诊断报告窗口
+    diagnostics_window: DiagnosticsWindow,
+    /// 历史记录窗口
+    history_window: HistoryWindow,
+    /// 坐标选取窗口
+    point_picker: PointPicker,
+    /// 目标窗口选取窗口
+    window_picker: WindowPicker,
+    /// 脚本窗口
+    script_window: ScriptWindow,
+    /// 操作序列窗口
+    sequence_window: SequenceWindow,
+    /// 模拟运行窗口
+    dry_run_window: DryRunWindow,
+    /// 点击指示层
+    click_overlay: ClickOverlay,
+    /// 托盘是否可用
+    tray_enabled: bool,
 }
 
 /// UI状态
@@ -32,8 +72,14 @@ struct UiState {
     count_text: String,
     /// 是否显示无限点击
     unlimited_clicks: bool,
-    /// 上次渲染时热键是否被按下
-    last_hotkey_pressed: bool,
+    /// 另存为的配置名称
+    new_profile_name: String,
+    /// 模板图像路径输入框的文本
+    template_path: String,
+    /// 目标窗口匹配规则输入框的文本
+    window_pattern: String,
+    /// 选取点击位置时使用的参照
+    click_anchor: PointAnchor,
     /// 是否使用暗色主题
     dark_mode: bool,
 }
@@ -47,16 +93,33 @@ impl MainWindow {
                 .click_count
                 .map_or(String::new(), |c| c.to_string()),
             unlimited_clicks: settings.click_count.is_none(),
-            last_hotkey_pressed: false,
+            new_profile_name: String::new(),
+            template_path: settings.template.path.display().to_string(),
+            window_pattern: settings.target_window.pattern.clone(),
+            click_anchor: settings
+                .click_target
+                .as_ref()
+                .map_or(PointAnchor::default(), |target| target.anchor),
+            // 没有保存过主题时跟随系统
             dark_mode: detect_system_dark_mode(),
         };
 
         Self {
             settings,
             clicker_manager: None,
+            clicker_events: None,
             current_status: ClickerStatus::default(),
             error_message: None,
             ui_state,
+            diagnostics_window: DiagnosticsWindow::default(),
+            history_window: HistoryWindow::default(),
+            point_picker: PointPicker::default(),
+            window_picker: WindowPicker::default(),
+            script_window: ScriptWindow::default(),
+            sequence_window: SequenceWindow::default(),
+            dry_run_window: DryRunWindow::default(),
+            click_overlay: ClickOverlay::default(),
+            tray_enabled: false,
         }
     }
 
@@ -64,11 +127,14 @@ impl MainWindow {
     pub fn initialize_clicker(&mut self) -> Result<()> {
         match ClickerManager::new(self.settings.clone()) {
             Ok(manager) => {
+                self.clicker_events = Some(manager.subscribe());
                 self.clicker_manager = Some(manager);
+                // 启动诊断，仅在存在失败项时弹出报告
+                self.run_diagnostics(false);
                 Ok(())
             }
             Err(e) => {
-                self.error_message = Some(format!("初始化连点器失败: {}", e));
+                self.error_message = Some(t!("main.error.init_clicker", error = e));
                 Err(e)
             }
         }
@@ -85,9 +151,7 @@ impl MainWindow {
 
         // 更新连点器状态
         self.update_clicker_status();
-
-        // 检查热键
-        self.check_hotkey();
+        self.handle_clicker_events();
 
         // 绘制菜单栏
         self.draw_menu_bar(ctx);
@@ -97,10 +161,115 @@ impl MainWindow {
             self.draw_main_content(ui);
         });
 
+        // 诊断报告
+        self.diagnostics_window.show(ctx);
+        if self.diagnostics_window.take_rerun_request() {
+            self.run_diagnostics(true);
+        }
+
+        // 历史记录
+        self.history_window.show(ctx);
+
+        // 目标窗口选取
+        self.window_picker.show(ctx);
+        if let Some(window) = self.window_picker.take_picked() {
+            self.apply_picked_window(&window);
+        }
+
+        // 脚本
+        self.script_window.show(
+            ctx,
+            &mut self.settings.script,
+            self.current_status.state == ClickerState::Running,
+        );
+        if self.script_window.take_changed() {
+            self.update_clicker_settings();
+        }
+        if self.script_window.take_toggle_request() {
+            // 脚本和操作序列不能同时启用，从脚本窗口运行时关闭序列
+            if self.settings.script.enabled && self.settings.sequence.enabled {
+                self.settings.sequence.enabled = false;
+                self.update_clicker_settings();
+            }
+            self.toggle_clicking();
+        }
+
+        // 操作序列
+        self.sequence_window.show(
+            ctx,
+            &mut self.settings.sequence,
+            self.current_status.state == ClickerState::Running,
+        );
+        if self.sequence_window.take_changed() {
+            self.close_sequence_conflicts();
+            self.update_clicker_settings();
+        }
+        if self.sequence_window.take_toggle_request() {
+            let mut changed = self.close_sequence_conflicts();
+            if self.settings.sequence.enabled && self.settings.script.enabled {
+                self.settings.script.enabled = false;
+                changed = true;
+            }
+            if changed {
+                self.update_clicker_settings();
+            }
+            self.toggle_clicking();
+        }
+
+        // 模拟运行
+        self.dry_run_window.show(ctx);
+        if self.dry_run_window.take_changed() {
+            if let Some(manager) = &mut self.clicker_manager {
+                manager.set_dry_run(self.dry_run_window.enabled());
+                manager.set_fast_forward(self.dry_run_window.fast_forward());
+            }
+        }
+
+        // 点击指示
+        self.click_overlay.show(ctx);
+
+        // 坐标选取
+        self.point_picker.show(ctx);
+        if let Some(picked) = self.point_picker.take_picked() {
+            match picked.purpose {
+                PickPurpose::ClickTarget => {
+                    match self.to_click_target(self.ui_state.click_anchor, picked.point) {
+                        Ok(target) => self.settings.click_target = Some(target),
+                        Err(e) => self.error_message = Some(e),
+                    }
+                }
+                PickPurpose::PixelCondition => {
+                    let condition = &mut self.settings.pixel_condition;
+                    condition.point = picked.point;
+                    if let Some(color) = picked.color {
+                        condition.color = color;
+                    }
+                }
+            }
+            self.update_clicker_settings();
+        }
+
         // 处理错误消息
         self.show_error_dialog(ctx);
     }
 
+    /// 执行诊断
+    ///
+    /// `always_show` 为false时只在存在失败项时打开报告窗口
+    fn run_diagnostics(&mut self, always_show: bool) {
+        // 连点器创建成功即说明热键已注册，不能再重复注册
+        let hotkey = if self.clicker_manager.is_some() {
+            HotkeyProbe::Registered(Ok(()))
+        } else {
+            HotkeyProbe::Register
+        };
+        let report = DiagnosticReport::run(&self.settings, hotkey);
+        log::info!("诊断结果:\n{}", report.to_text());
+        if always_show || report.has_failures() {
+            self.diagnostics_window.show_report(report);
+        }
+    }
+
     /// 更新连点器状态
     fn update_clicker_status(&mut self) {
         if let Some(manager) = &self.clicker_manager {
@@ -108,18 +277,70 @@ impl MainWindow {
         }
     }
 
-    /// 检查热键
-    fn check_hotkey(&mut self) {
-        if let Some(manager) = &mut self.clicker_manager {
-            let pressed = manager.check_hotkey_pressed();
-            if pressed && !self.ui_state.last_hotkey_pressed {
-                // 只在“未按下”->“按下”时触发
-                if let Err(e) = manager.toggle() {
-                    self.error_message = Some(format!("热键操作失败: {}", e));
+    /// 处理连点器事件，把错误和紧急停止告知用户
+    fn handle_clicker_events(&mut self) {
+        let Some(events) = &self.clicker_events else {
+            return;
+        };
+
+        let events: Vec<_> = events.try_iter().collect();
+        for event in events {
+            match event {
+                ClickerEvent::Error(ErrorKind::Permission(e)) => {
+                    self.error_message = Some(t!("main.error.permission", error = e));
+                }
+                ClickerEvent::Error(kind @ ErrorKind::Script(_)) => {
+                    self.script_window.push_output(kind.to_string());
+                    self.error_message = Some(kind.to_string());
+                }
+                ClickerEvent::Error(kind) => {
+                    self.error_message = Some(kind.to_string());
+                }
+                ClickerEvent::Stopped(
+                    reason @ (StopReason::FailSafe(_) | StopReason::TemplateNotFound),
+                ) => {
+                    self.click_overlay.stop();
+                    self.sequence_window.set_current_step(None);
+                    self.error_message = Some(reason.to_string());
                 }
-                log::info!("热键触发，切换连点器状态");
+                ClickerEvent::Stopped(_) => {
+                    self.click_overlay.stop();
+                    self.sequence_window.set_current_step(None);
+                }
+                ClickerEvent::StepStarted(index) => {
+                    self.sequence_window.set_current_step(Some(index))
+                }
+                ClickerEvent::Clicked {
+                    button,
+                    position: Some(position),
+                    ..
+                } if self.settings.click_indicator => {
+                    self.click_overlay.push(position, button);
+                }
+                ClickerEvent::ScriptLog(message) => self.script_window.push_output(message),
+                ClickerEvent::Started { dry_run, .. } => {
+                    if dry_run {
+                        self.dry_run_window.start();
+                    }
+                    if self.settings.click_indicator {
+                        self.click_overlay.start(self.desktop_bounds());
+                    }
+                }
+                ClickerEvent::Simulated { elapsed, input } => {
+                    self.dry_run_window.push(elapsed, &input)
+                }
+                ClickerEvent::EventsDropped(count) => self.dry_run_window.skip(count),
+                event @ ClickerEvent::CoordinatesUnchecked(_) => {
+                    self.error_message = Some(event.to_string());
+                }
+                ClickerEvent::ProfileSwitched(_) => {
+                    if let Some(manager) = &self.clicker_manager {
+                        self.settings = manager.handle().settings();
+                        self.reset_inputs();
+                    }
+                }
+                _ => {}
             }
-            self.ui_state.last_hotkey_pressed = pressed;
         }
     }
 
@@ -127,66 +348,657 @@ impl MainWindow {
     fn draw_menu_bar(&mut self, ctx: &Context) {
         egui::TopBottomPanel::top("menu_bar").show(ctx, |ui| {
             egui::menu::bar(ui, |ui| {
-                ui.menu_button("设置", |ui| {
+                ui.menu_button(t!("menu.settings"), |ui| {
                     if MouseController::is_macos() {
-                        if ui.button("打开辅助功能设置").clicked() {
+                        if ui.button(t!("menu.open_accessibility")).clicked() {
                             if let Err(e) = MouseController::open_privacy_settings() {
-                                self.error_message = Some(format!("无法打开系统设置: {}", e));
+                                self.error_message =
+                                    Some(t!("mouse.error.open_settings", error = e));
                             }
                             ui.close_menu();
                         }
                     }
 
                     if MouseController::is_windows() && !MouseController::is_admin() {
-                        if ui.button("以管理员权限重启").clicked() {
+                        if ui.button(t!("menu.restart_admin")).clicked() {
                             if let Err(e) = MouseController::restart_as_admin() {
-                                self.error_message = Some(format!("重启失败: {}", e));
+                                self.error_message = Some(t!("main.error.restart", error = e));
                             }
                             ui.close_menu();
                         }
                     }
 
+                    if MouseController::is_linux() {
+                        ui.menu_button(t!("menu.input_backend"), |ui| {
+                            for kind in InputBackend::all() {
+                                if ui
+                                    .radio_value(
+                                        &mut self.settings.input_backend,
+                                        kind,
+                                        kind.to_string(),
+                                    )
+                                    .changed()
+                                {
+                                    self.update_clicker_settings();
+                                    ui.close_menu();
+                                }
+                            }
+                        });
+                    }
+
+                    ui.menu_button(t!("menu.burst"), |ui| {
+                        self.draw_burst_menu(ui);
+                    });
+
+                    ui.menu_button(t!("menu.failsafe"), |ui| {
+                        self.draw_failsafe_menu(ui);
+                    });
+
+                    ui.menu_button(t!("menu.pixel_condition"), |ui| {
+                        self.draw_pixel_condition_menu(ui);
+                    });
+
+                    ui.menu_button(t!("menu.template"), |ui| {
+                        self.draw_template_menu(ui);
+                    });
+
+                    ui.menu_button(t!("menu.target_window"), |ui| {
+                        self.draw_target_window_menu(ui);
+                    });
+
+                    if ui
+                        .checkbox(
+                            &mut self.settings.click_indicator,
+                            t!("menu.click_indicator"),
+                        )
+                        .changed()
+                    {
+                        if !self.settings.click_indicator {
+                            self.click_overlay.stop();
+                        } else if self.current_status.state == ClickerState::Running {
+                            self.click_overlay.start(self.desktop_bounds());
+                        }
+                        self.update_clicker_settings();
+                        ui.close_menu();
+                    }
+
                     let theme_text = if self.ui_state.dark_mode {
-                        "切换到亮色模式"
+                        t!("menu.light_mode")
                     } else {
-                        "切换到暗色模式"
+                        t!("menu.dark_mode")
                     };
                     if ui.button(theme_text).clicked() {
                         self.ui_state.dark_mode = !self.ui_state.dark_mode;
                         ui.close_menu();
                     }
+
+                    ui.menu_button(t!("menu.language"), |ui| {
+                        self.draw_language_menu(ui);
+                    });
                     ui.separator();
 
-                    if ui.button("关于").clicked() {
+                    if self.tray_enabled && ui.button(t!("menu.hide_to_tray")).clicked() {
+                        ui.ctx()
+                            .send_viewport_cmd(egui::ViewportCommand::Visible(false));
+                        ui.close_menu();
+                    }
+
+                    if ui.button(t!("menu.sequence")).clicked() {
+                        self.sequence_window.open();
+                        ui.close_menu();
+                    }
+
+                    if ui.button(t!("menu.script")).clicked() {
+                        self.script_window.open(&self.settings.script);
+                        ui.close_menu();
+                    }
+
+                    if ui.button(t!("menu.dry_run")).clicked() {
+                        self.dry_run_window.open();
+                        ui.close_menu();
+                    }
+
+                    if ui.button(t!("menu.history")).clicked() {
+                        self.history_window.open();
+                        ui.close_menu();
+                    }
+
+                    if ui.button(t!("menu.diagnostics")).clicked() {
+                        self.run_diagnostics(true);
+                        ui.close_menu();
+                    }
+
+                    if ui.button(t!("menu.about")).clicked() {
                         let version = git_version!(fallback = "unknown");
                         self.error_message = Some(format!("Mouse Clicker @{}", version));
                         ui.close_menu();
                     }
                 });
+
+                ui.menu_button(t!("menu.profiles"), |ui| {
+                    self.draw_profile_menu(ui);
+                });
+            });
+        });
+    }
+
+    /// 绘制配置方案菜单
+    fn draw_profile_menu(&mut self, ui: &mut Ui) {
+        let store = match ProfileStore::new() {
+            Ok(store) => store,
+            Err(e) => {
+                ui.label(e);
+                return;
+            }
+        };
+
+        // 当前配置可能尚未保存过，同样列出
+        let mut profiles = store.list().unwrap_or_default();
+        if !profiles.contains(&self.settings.profile) {
+            profiles.push(self.settings.profile.clone());
+            profiles.sort();
+        }
+        for name in profiles {
+            if ui
+                .radio(name == self.settings.profile, name.as_str())
+                .clicked()
+            {
+                if let Some(manager) = &self.clicker_manager {
+                    if let Err(e) = manager.handle().switch_profile(&store, &name) {
+                        self.error_message = Some(t!("main.error.switch_profile", error = e));
+                    }
+                }
+                ui.close_menu();
+            }
+        }
+
+        ui.separator();
+        if ui.button(t!("profile.save")).clicked() {
+            if let Err(e) = store.save(&self.settings) {
+                self.error_message = Some(t!("main.error.save_profile", error = e));
+            }
+            ui.close_menu();
+        }
+
+        ui.horizontal(|ui| {
+            ui.add(
+                egui::TextEdit::singleline(&mut self.ui_state.new_profile_name)
+                    .hint_text(t!("profile.new_name"))
+                    .desired_width(100.0),
+            );
+            if ui.button(t!("profile.save_as")).clicked() {
+                let mut settings = self.settings.clone();
+                settings.profile = self.ui_state.new_profile_name.trim().to_string();
+                match store.save(&settings) {
+                    Ok(()) => {
+                        self.settings = settings;
+                        self.ui_state.new_profile_name.clear();
+                        self.update_clicker_settings();
+                        ui.close_menu();
+                    }
+                    Err(e) => self.error_message = Some(t!("main.error.save_profile", error = e)),
+                }
+            }
+        });
+    }
+
+    /// 绘制语言菜单
+    fn draw_language_menu(&mut self, ui: &mut Ui) {
+        let mut changed = ui
+            .radio_value(&mut self.settings.language, None, t!("menu.language_auto"))
+            .changed();
+        for locale in Locale::all() {
+            changed |= ui
+                .radio_value(
+                    &mut self.settings.language,
+                    Some(locale),
+                    locale.to_string(),
+                )
+                .changed();
+        }
+
+        if changed {
+            i18n::set_locale(self.settings.language);
+            self.update_clicker_settings();
+            ui.close_menu();
+        }
+    }
+
+    /// 绘制紧急停止设置菜单
+    fn draw_failsafe_menu(&mut self, ui: &mut Ui) {
+        let failsafe = &mut self.settings.failsafe;
+        let mut changed = ui
+            .checkbox(&mut failsafe.enabled, t!("failsafe.enabled"))
+            .changed();
+
+        ui.add_enabled_ui(failsafe.enabled, |ui| {
+            ui.separator();
+            ui.label(t!("failsafe.corner_label"));
+            changed |= ui
+                .radio_value(&mut failsafe.corner, None, t!("failsafe.no_corner"))
+                .changed();
+            for corner in ScreenCorner::all() {
+                changed |= ui
+                    .radio_value(&mut failsafe.corner, Some(corner), corner.to_string())
+                    .changed();
+            }
+
+            ui.separator();
+            let mut check_drift = failsafe.max_drift.is_some();
+            ui.horizontal(|ui| {
+                if ui
+                    .checkbox(&mut check_drift, t!("failsafe.drift_prefix"))
+                    .changed()
+                {
+                    failsafe.max_drift = check_drift.then_some(50);
+                    changed = true;
+                }
+                if let Some(drift) = &mut failsafe.max_drift {
+                    changed |= ui
+                        .add(egui::DragValue::new(drift).clamp_range(1..=10000))
+                        .changed();
+                }
+                ui.label(t!("failsafe.drift_suffix"));
+            });
+        });
+
+        if changed {
+            self.update_clicker_settings();
+        }
+    }
+
+    /// 绘制连发菜单
+    fn draw_burst_menu(&mut self, ui: &mut Ui) {
+        let burst = &mut self.settings.burst;
+        let mut changed = ui
+            .checkbox(&mut burst.enabled, t!("burst.enabled"))
+            .changed();
+
+        ui.add_enabled_ui(burst.enabled, |ui| {
+            ui.separator();
+            ui.horizontal(|ui| {
+                ui.label(t!("burst.clicks_per_burst"));
+                changed |= ui
+                    .add(egui::DragValue::new(&mut burst.clicks_per_burst).clamp_range(1..=10000))
+                    .changed();
+            });
+            ui.horizontal(|ui| {
+                ui.label(t!("burst.inner_interval"));
+                changed |= ui
+                    .add(
+                        egui::DragValue::new(&mut burst.inner_interval_ms)
+                            .suffix(" ms")
+                            .clamp_range(1..=60000),
+                    )
+                    .changed();
+            });
+            ui.horizontal(|ui| {
+                ui.label(t!("burst.rest"));
+                changed |= ui
+                    .add(
+                        egui::DragValue::new(&mut burst.rest_ms)
+                            .suffix(" ms")
+                            .clamp_range(0..=3_600_000),
+                    )
+                    .changed();
+            });
+
+            let mut limited = burst.bursts.is_some();
+            ui.horizontal(|ui| {
+                if ui.checkbox(&mut limited, t!("burst.limit")).changed() {
+                    burst.bursts = limited.then_some(10);
+                    changed = true;
+                }
+                if let Some(bursts) = &mut burst.bursts {
+                    changed |= ui
+                        .add(egui::DragValue::new(bursts).clamp_range(1..=100000))
+                        .changed();
+                }
+            });
+            ui.label(RichText::new(t!("burst.hint")).small());
+        });
+
+        if changed {
+            self.update_clicker_settings();
+        }
+    }
+
+    /// 绘制像素颜色条件菜单
+    fn draw_pixel_condition_menu(&mut self, ui: &mut Ui) {
+        let mut pick = false;
+        let blocked = self.settings.sequence.enabled;
+        let condition = &mut self.settings.pixel_condition;
+        let mut changed = ui
+            .add_enabled(
+                !blocked,
+                egui::Checkbox::new(&mut condition.enabled, t!("pixel_condition.enabled")),
+            )
+            .on_disabled_hover_text(t!("sequence.conflict_hint"))
+            .changed();
+
+        ui.add_enabled_ui(condition.enabled, |ui| {
+            ui.separator();
+            for mode in ConditionMode::all() {
+                changed |= ui
+                    .radio_value(&mut condition.mode, mode, mode.to_string())
+                    .changed();
+            }
+
+            ui.separator();
+            ui.horizontal(|ui| {
+                ui.label(t!("pixel_condition.point", point = condition.point));
+                pick = ui.button(t!("main.click_target.pick")).clicked();
+            });
+            ui.horizontal(|ui| {
+                ui.label(t!("pixel_condition.color"));
+                changed |= ui.color_edit_button_srgb(&mut condition.color).changed();
+                ui.label(format_color(condition.color));
+            });
+            ui.horizontal(|ui| {
+                ui.label(t!("pixel_condition.tolerance"));
+                changed |= ui
+                    .add(egui::DragValue::new(&mut condition.tolerance))
+                    .changed();
+            });
+            ui.horizontal(|ui| {
+                ui.label(t!("pixel_condition.radius"));
+                changed |= ui
+                    .add(
+                        egui::DragValue::new(&mut condition.radius)
+                            .clamp_range(0..=MAX_SAMPLE_RADIUS),
+                    )
+                    .changed();
+            });
+        });
+
+        if changed {
+            self.update_clicker_settings();
+        }
+        if pick {
+            self.open_point_picker(PickPurpose::PixelCondition);
+            ui.close_menu();
+        }
+    }
+
+    /// 绘制图像匹配菜单
+    fn draw_template_menu(&mut self, ui: &mut Ui) {
+        let blocked = self.settings.sequence.enabled;
+        let template = &mut self.settings.template;
+        let mut changed = ui
+            .add_enabled(
+                !blocked,
+                egui::Checkbox::new(&mut template.enabled, t!("template.enabled")),
+            )
+            .on_disabled_hover_text(t!("sequence.conflict_hint"))
+            .changed();
+
+        ui.add_enabled_ui(template.enabled, |ui| {
+            ui.separator();
+            ui.label(t!("template.path"));
+            let response = ui.text_edit_singleline(&mut self.ui_state.template_path);
+            if response.lost_focus() {
+                template.path = self.ui_state.template_path.trim().into();
+                changed = true;
+            }
+
+            ui.horizontal(|ui| {
+                ui.label(t!("template.threshold"));
+                changed |= ui
+                    .add(egui::Slider::new(&mut template.threshold, 0.5..=1.0))
+                    .changed();
+            });
+            ui.horizontal(|ui| {
+                ui.label(t!("template.timeout"));
+                changed |= ui
+                    .add(egui::DragValue::new(&mut template.timeout_ms).clamp_range(0..=60000))
+                    .changed();
+            });
+            ui.horizontal(|ui| {
+                ui.label(t!("template.offset"));
+                changed |= ui
+                    .add(egui::DragValue::new(&mut template.offset.x))
+                    .changed();
+                changed |= ui
+                    .add(egui::DragValue::new(&mut template.offset.y))
+                    .changed();
             });
+
+            ui.separator();
+            let mut limit_region = template.region.is_some();
+            if ui
+                .checkbox(&mut limit_region, t!("template.limit_region"))
+                .changed()
+            {
+                template.region = limit_region.then_some(ScreenRegion {
+                    x: 0,
+                    y: 0,
+                    width: 800,
+                    height: 600,
+                });
+                changed = true;
+            }
+            if let Some(region) = &mut template.region {
+                ui.horizontal(|ui| {
+                    ui.label("X/Y:");
+                    changed |= ui.add(egui::DragValue::new(&mut region.x)).changed();
+                    changed |= ui.add(egui::DragValue::new(&mut region.y)).changed();
+                });
+                ui.horizontal(|ui| {
+                    ui.label(t!("template.region_size"));
+                    changed |= ui
+                        .add(egui::DragValue::new(&mut region.width).clamp_range(1..=u16::MAX))
+                        .changed();
+                    changed |= ui
+                        .add(egui::DragValue::new(&mut region.height).clamp_range(1..=u16::MAX))
+                        .changed();
+                });
+            }
+        });
+
+        if changed {
+            self.update_clicker_settings();
+        }
+    }
+
+    /// 绘制目标窗口菜单
+    fn draw_target_window_menu(&mut self, ui: &mut Ui) {
+        let mut pick = false;
+        let in_use = self.settings.target_window.enabled
+            || self.settings.click_delivery == ClickDelivery::Window
+            || self
+                .settings
+                .click_target
+                .as_ref()
+                .is_some_and(|target| target.needs_window());
+        let target = &mut self.settings.target_window;
+        let mut changed = ui
+            .checkbox(&mut target.enabled, t!("target_window.enabled"))
+            .changed();
+
+        ui.add_enabled_ui(in_use || target.enabled, |ui| {
+            ui.separator();
+            for kind in WindowMatchKind::all() {
+                changed |= ui
+                    .radio_value(&mut target.kind, kind, kind.to_string())
+                    .changed();
+            }
+
+            ui.separator();
+            let response = ui.text_edit_singleline(&mut self.ui_state.window_pattern);
+            if response.lost_focus() {
+                target.pattern = self.ui_state.window_pattern.clone();
+                changed = true;
+            }
+            pick = ui.button(t!("target_window.pick")).clicked();
         });
+
+        ui.separator();
+        ui.label(t!("target_window.delivery"));
+        let blocked = self.settings.sequence.enabled;
+        ui.add_enabled_ui(!blocked, |ui| {
+            for delivery in ClickDelivery::all() {
+                // 后台点击只支持X11
+                let supported = delivery != ClickDelivery::Window || cfg!(target_os = "linux");
+                let selected = self.settings.click_delivery == delivery;
+                if ui
+                    .add_enabled(
+                        supported,
+                        egui::RadioButton::new(selected, delivery.to_string()),
+                    )
+                    .on_disabled_hover_text(t!("mouse.error.background_unsupported"))
+                    .clicked()
+                    && !selected
+                {
+                    self.settings.click_delivery = delivery;
+                    changed = true;
+                }
+            }
+        });
+        if blocked {
+            ui.label(RichText::new(t!("sequence.conflict_hint")).small().weak());
+        } else if self.settings.click_delivery == ClickDelivery::Window {
+            ui.label(
+                RichText::new(t!("target_window.delivery_hint"))
+                    .small()
+                    .weak(),
+            );
+        }
+
+        if changed {
+            self.update_clicker_settings();
+        }
+        if pick {
+            let display = BackendConfig::from_settings(&self.settings).display;
+            if let Err(e) = self.window_picker.open(display.as_deref()) {
+                self.error_message = Some(t!("main.error.pick_window", error = e));
+            }
+            ui.close_menu();
+        }
+    }
+
+    /// 目标窗口当前的客户区
+    fn target_window_area(&self) -> Result<ScreenRegion> {
+        let display = BackendConfig::from_settings(&self.settings).display;
+        let finder = WindowFinder::new(&self.settings.target_window, display.as_deref())?;
+        let window = finder
+            .find()?
+            .ok_or_else(|| t!("main.error.window_not_found").to_string())?;
+        finder.client_area(window.id)
+    }
+
+    /// 点击指示层覆盖的范围，即所有显示器的外接矩形
+    fn desktop_bounds(&self) -> Option<ScreenRegion> {
+        let display = BackendConfig::from_settings(&self.settings).display;
+        match MonitorLayout::query(display.as_deref()) {
+            Ok(monitors) => monitors.bounds(),
+            Err(e) => {
+                log::warn!("无法枚举显示器，点击指示只覆盖当前显示器: {}", e);
+                None
+            }
+        }
+    }
+
+    /// 按参照读取换算所需的目标窗口客户区和显示器布局
+    fn anchor_context(&self, anchor: PointAnchor) -> Result<(Option<ScreenRegion>, MonitorLayout)> {
+        let area = if anchor.is_window_relative() {
+            Some(self.target_window_area()?)
+        } else {
+            None
+        };
+        let monitors = if anchor == PointAnchor::Monitor {
+            let display = BackendConfig::from_settings(&self.settings).display;
+            MonitorLayout::query(display.as_deref())?
+        } else {
+            MonitorLayout::default()
+        };
+        Ok((area, monitors))
+    }
+
+    /// 按参照把屏幕坐标换算为点击位置
+    fn to_click_target(&self, anchor: PointAnchor, point: ClickPoint) -> Result<ClickTarget> {
+        let (area, monitors) = self.anchor_context(anchor)?;
+        monitors
+            .target_from_screen(anchor, point, area)
+            .ok_or_else(|| t!("main.error.point_unmapped", point = point))
+    }
+
+    /// 切换点击位置的参照，已设置的位置按目标窗口和显示器当前的位置换算
+    fn change_click_anchor(&mut self, anchor: PointAnchor) {
+        let Some(target) = self.settings.click_target.clone() else {
+            return;
+        };
+        let converted = self
+            .anchor_context(target.anchor)
+            .and_then(|(area, monitors)| {
+                monitors
+                    .resolve(&target, area)
+                    .ok_or_else(|| t!("monitor.error.unresolved", point = target))
+            })
+            .and_then(|point| self.to_click_target(anchor, point));
+
+        match converted {
+            Ok(target) => {
+                self.settings.click_target = Some(target);
+                self.update_clicker_settings();
+            }
+            Err(e) => {
+                self.ui_state.click_anchor = target.anchor;
+                self.error_message = Some(t!("main.error.click_anchor", error = e));
+            }
+        }
+    }
+
+    /// 用选取的窗口填写匹配规则
+    fn apply_picked_window(&mut self, window: &WindowInfo) {
+        let target = &mut self.settings.target_window;
+        let pattern = match (target.kind, window.value(target.kind)) {
+            // 标题按原文完整匹配
+            (WindowMatchKind::Title, Some(title)) => format!("^{}$", regex::escape(title)),
+            (_, Some(value)) if !value.is_empty() => value.to_string(),
+            (kind, _) => {
+                self.error_message = Some(t!("target_window.error.no_value", kind = kind));
+                return;
+            }
+        };
+        target.pattern = pattern;
+        self.ui_state.window_pattern = target.pattern.clone();
+        self.update_clicker_settings();
+    }
+
+    /// 打开坐标选取窗口
+    fn open_point_picker(&mut self, purpose: PickPurpose) {
+        let display = BackendConfig::from_settings(&self.settings).display;
+        if let Err(e) = self.point_picker.open(display.as_deref(), purpose) {
+            self.error_message = Some(t!("main.error.pick", error = e));
+        }
     }
 
     /// 绘制主要内容
+    ///
+    /// 窗口放大时内容居中并限制最大宽度，窗口较矮时可滚动
     fn draw_main_content(&mut self, ui: &mut Ui) {
-        ui.vertical_centered(|ui| {
-            // 标题
-            ui.add_space(10.0);
-            ui.heading(RichText::new("Mouse Clicker").size(20.0));
-            ui.add_space(15.0);
+        egui::ScrollArea::vertical().show(ui, |ui| {
+            ui.vertical_centered(|ui| {
+                ui.set_max_width(MAX_CONTENT_WIDTH);
 
-            // 设置区域
-            self.draw_settings_section(ui);
+                // 标题
+                ui.add_space(10.0);
+                ui.heading(RichText::new("Mouse Clicker").size(20.0));
+                ui.add_space(15.0);
 
-            ui.add_space(15.0);
+                // 设置区域
+                self.draw_settings_section(ui);
 
-            // 状态显示区域
-            self.draw_status_section(ui);
+                ui.add_space(15.0);
 
-            ui.add_space(15.0);
+                // 状态显示区域
+                self.draw_status_section(ui);
 
-            // 控制按钮区域
-            self.draw_control_section(ui);
+                ui.add_space(15.0);
+
+                // 控制按钮区域
+                self.draw_control_section(ui);
+            });
         });
     }
 
@@ -197,7 +1009,7 @@ impl MainWindow {
             ui.add_enabled_ui(is_enabled, |ui| {
                 // 点击间隔设置
                 ui.horizontal(|ui| {
-                    ui.label("点击间隔 (毫秒):");
+                    ui.label(t!("main.interval"));
                     ui.add_space(10.0);
 
                     let response = ui.text_edit_singleline(&mut self.ui_state.interval_text);
@@ -208,12 +1020,14 @@ impl MainWindow {
                                 self.update_clicker_settings();
                             } else if interval > 60000 {
                                 // 防止设置过大的间隔时间
-                                self.error_message = Some("点击间隔不能超过60秒".to_string());
+                                self.error_message =
+                                    Some(t!("settings.error.interval_too_long").to_string());
                                 self.ui_state.interval_text = "60000".to_string();
                                 self.settings.interval_ms = 60000;
                                 self.update_clicker_settings();
                             } else if interval == 0 {
-                                self.error_message = Some("点击间隔不能为0".to_string());
+                                self.error_message =
+                                    Some(t!("settings.error.zero_interval").to_string());
                                 self.ui_state.interval_text = "1".to_string();
                                 self.settings.interval_ms = 1;
                                 self.update_clicker_settings();
@@ -222,7 +1036,7 @@ impl MainWindow {
                             // 解析失败，可能是输入了非数字或超出u64范围
                             if !self.ui_state.interval_text.is_empty() {
                                 self.error_message =
-                                    Some("请输入有效的数字（1-60000毫秒）".to_string());
+                                    Some(t!("main.error.invalid_interval").to_string());
                             }
                         }
                     }
@@ -232,21 +1046,13 @@ impl MainWindow {
 
                 // 鼠标按键选择
                 ui.horizontal(|ui| {
-                    ui.label("鼠标按键:");
+                    ui.label(t!("main.mouse_button"));
                     ui.add_space(10.0);
 
                     egui::ComboBox::from_id_source("mouse_button")
                         .selected_text(self.settings.mouse_button.to_string())
                         .show_ui(ui, |ui| {
-                            let buttons = [
-                                MouseButton::Left,
-                                MouseButton::Right,
-                                MouseButton::LeftLongPress,
-                                MouseButton::RightLongPress,
-                                MouseButton::ScrollUp,
-                                MouseButton::ScrollDown,
-                            ];
-                            for &button in &buttons {
+                            for button in MouseButton::all() {
                                 if ui
                                     .selectable_value(
                                         &mut self.settings.mouse_button,
@@ -263,9 +1069,50 @@ impl MainWindow {
 
                 ui.add_space(8.0);
 
+                // 点击位置设置
+                ui.horizontal(|ui| {
+                    ui.label(t!("main.click_target"));
+                    ui.add_space(10.0);
+
+                    let anchor = self.ui_state.click_anchor;
+                    egui::ComboBox::from_id_source("click_anchor")
+                        .selected_text(anchor.to_string())
+                        .show_ui(ui, |ui| {
+                            for option in PointAnchor::all() {
+                                ui.selectable_value(
+                                    &mut self.ui_state.click_anchor,
+                                    option,
+                                    option.to_string(),
+                                );
+                            }
+                        });
+                    if self.ui_state.click_anchor != anchor {
+                        self.change_click_anchor(self.ui_state.click_anchor);
+                    }
+
+                    match &self.settings.click_target {
+                        Some(point) => ui.label(point.to_string()),
+                        None if self.settings.click_delivery == ClickDelivery::Window => {
+                            ui.label(t!("main.click_target.window_center"))
+                        }
+                        None => ui.label(t!("main.click_target.cursor")),
+                    };
+                    if ui.button(t!("main.click_target.pick")).clicked() {
+                        self.open_point_picker(PickPurpose::ClickTarget);
+                    }
+                    if self.settings.click_target.is_some()
+                        && ui.button(t!("main.click_target.clear")).clicked()
+                    {
+                        self.settings.click_target = None;
+                        self.update_clicker_settings();
+                    }
+                });
+
+                ui.add_space(8.0);
+
                 // 热键设置
                 ui.horizontal(|ui| {
-                    ui.label("热键:");
+                    ui.label(t!("main.hotkey"));
                     ui.add_space(10.0);
 
                     egui::ComboBox::from_id_source("hotkey")
@@ -290,11 +1137,11 @@ impl MainWindow {
 
                 // 点击次数设置
                 ui.horizontal(|ui| {
-                    ui.label("点击次数:");
+                    ui.label(t!("main.click_count"));
                     ui.add_space(10.0);
 
                     if ui
-                        .checkbox(&mut self.ui_state.unlimited_clicks, "无限制")
+                        .checkbox(&mut self.ui_state.unlimited_clicks, t!("main.unlimited"))
                         .changed()
                     {
                         if self.ui_state.unlimited_clicks {
@@ -318,7 +1165,7 @@ impl MainWindow {
                                 } else if count > 1000000 {
                                     // 防止设置过大的值导致潜在问题
                                     self.error_message =
-                                        Some("点击次数不能超过100万次".to_string());
+                                        Some(t!("settings.error.count_too_large").to_string());
                                     self.ui_state.count_text = "1000000".to_string();
                                     self.settings.click_count = Some(1000000);
                                     self.update_clicker_settings();
@@ -327,7 +1174,7 @@ impl MainWindow {
                                 // 解析失败，可能是输入了非数字或超出u32范围
                                 if !self.ui_state.count_text.is_empty() {
                                     self.error_message =
-                                        Some("请输入有效的数字（1-1000000）".to_string());
+                                        Some(t!("main.error.invalid_count").to_string());
                                 }
                             }
                         }
@@ -344,20 +1191,56 @@ impl MainWindow {
                 // 第一列：状态指示器(1/3)
                 columns[0].vertical_centered(|ui| {
                     let (color, text) = match self.current_status.state {
-                        ClickerState::Stopped => (Color32::GRAY, "已停止"),
-                        ClickerState::Running => (Color32::GREEN, "运行中"),
+                        ClickerState::Stopped => (Color32::GRAY, t!("status.stopped")),
+                        ClickerState::Running => (Color32::GREEN, t!("status.running")),
                     };
-                    ui.colored_label(color, RichText::new(format!("状态: {}", text)).size(12.0));
+                    let response = ui.colored_label(
+                        color,
+                        RichText::new(t!("status.state", state = text)).size(12.0),
+                    );
+                    if let (ClickerState::Stopped, Some(reason)) = (
+                        self.current_status.state,
+                        &self.current_status.last_stop_reason,
+                    ) {
+                        response.on_hover_text(reason.to_string());
+                    }
+                    if self.dry_run_window.enabled() {
+                        ui.colored_label(
+                            Color32::YELLOW,
+                            RichText::new(t!("status.dry_run")).size(10.0),
+                        );
+                    }
                 });
 
                 // 第二列：点击计数 (1/3)
                 columns[1].vertical_centered(|ui| {
                     let count_text = if let Some(target) = self.current_status.target_count {
-                        format!("点击: {} / {}", self.current_status.click_count, target)
+                        t!(
+                            "status.clicks",
+                            count = format!("{} / {}", self.current_status.click_count, target)
+                        )
                     } else {
-                        format!("点击: {}", self.current_status.click_count)
+                        t!("status.clicks", count = self.current_status.click_count)
                     };
                     ui.label(RichText::new(count_text).size(12.0));
+                    if let Some(burst) = self.current_status.current_burst {
+                        let burst_text = match self.current_status.bursts {
+                            Some(total) => format!("{} / {}", burst, total),
+                            None => burst.to_string(),
+                        };
+                        ui.label(
+                            RichText::new(t!(
+                                "status.burst",
+                                burst = burst_text,
+                                clicks = format!(
+                                    "{} / {}",
+                                    self.current_status.clicks_in_burst,
+                                    self.current_status.clicks_per_burst
+                                )
+                            ))
+                            .size(10.0),
+                        );
+                    }
                 });
 
                 // 第三列：运行时间 (1/3)
@@ -370,14 +1253,21 @@ impl MainWindow {
                             let days = total_seconds / 86400;
                             let hours = (total_seconds % 86400) / 3600;
                             let minutes = (total_seconds % 3600) / 60;
-                            format!("时间: {}天{:02}:{:02}", days, hours, minutes)
+                            t!(
+                                "status.time_days",
+                                days = days,
+                                time = format!("{:02}:{:02}", hours, minutes)
+                            )
                         } else {
                             let minutes = total_seconds / 60;
                             let seconds = total_seconds % 60;
-                            format!("时间: {:02}:{:02}", minutes, seconds)
+                            t!(
+                                "status.time",
+                                time = format!("{:02}:{:02}", minutes, seconds)
+                            )
                         }
                     } else {
-                        "时间: 00:00".to_string()
+                        t!("status.time", time = "00:00")
                     };
                     ui.label(RichText::new(time_text).size(12.0));
                 });
@@ -388,18 +1278,20 @@ impl MainWindow {
     /// 绘制控制按钮区域
     fn draw_control_section(&mut self, ui: &mut Ui) {
         // 激活时按钮显示热键，禁用时不显示括号
-        let button_size = egui::Vec2::new(140.0, 30.0);
+        // 两个按钮平分可用宽度
+        let button_width = (ui.available_width() - ui.spacing().item_spacing.x) / 2.0;
+        let button_size = egui::Vec2::new(button_width.max(100.0), 30.0);
         let is_stopped = self.current_status.state == ClickerState::Stopped;
         let is_running = self.current_status.state == ClickerState::Running;
         let start_text = if is_stopped {
-            format!("开始 ({})", self.settings.hotkey)
+            format!("{} ({})", t!("control.start"), self.settings.hotkey)
         } else {
-            "开始".to_string()
+            t!("control.start").to_string()
         };
         let stop_text = if is_running {
-            format!("停止 ({})", self.settings.hotkey)
+            format!("{} ({})", t!("control.stop"), self.settings.hotkey)
         } else {
-            "停止".to_string()
+            t!("control.stop").to_string()
         };
         ui.columns(2, |columns| {
             // 左侧按钮：开始
@@ -432,13 +1324,13 @@ impl MainWindow {
     /// 显示错误对话框
     fn show_error_dialog(&mut self, ctx: &Context) {
         if let Some(error) = self.error_message.clone() {
-            egui::Window::new("错误")
+            egui::Window::new(t!("main.error.title"))
                 .collapsible(false)
                 .resizable(false)
                 .show(ctx, |ui| {
                     ui.label(error);
                     ui.add_space(10.0);
-                    if ui.button("确定").clicked() {
+                    if ui.button(t!("main.ok")).clicked() {
                         self.error_message = None;
                     }
                 });
@@ -449,7 +1341,7 @@ impl MainWindow {
     fn toggle_clicking(&mut self) {
         if let Some(manager) = &mut self.clicker_manager {
             if let Err(e) = manager.toggle() {
-                self.error_message = Some(format!("切换连点器状态失败: {}", e));
+                self.error_message = Some(t!("main.error.toggle", error = e));
             }
         }
     }
@@ -458,11 +1350,73 @@ impl MainWindow {
     fn update_clicker_settings(&mut self) {
         if let Some(manager) = &mut self.clicker_manager {
             if let Err(e) = manager.update_settings(self.settings.clone()) {
-                self.error_message = Some(format!("更新设置失败: {}", e));
+                self.error_message = Some(t!("main.error.update_settings", error = e));
             }
         }
     }
 
+    /// 启用操作序列时关闭序列不支持的颜色条件、图像匹配和后台点击
+    ///
+    /// 有设置被关闭时返回true
+    fn close_sequence_conflicts(&mut self) -> bool {
+        let settings = &mut self.settings;
+        if !settings.sequence.enabled {
+            return false;
+        }
+        let conflicted = settings.has_sequence_conflict();
+        settings.pixel_condition.enabled = false;
+        settings.template.enabled = false;
+        settings.click_delivery = ClickDelivery::Cursor;
+        conflicted
+    }
+
+    /// 按当前设置重置输入框
+    fn reset_inputs(&mut self) {
+        self.ui_state.interval_text = self.settings.interval_ms.to_string();
+        self.ui_state.count_text = self
+            .settings
+            .click_count
+            .map_or(String::new(), |c| c.to_string());
+        self.ui_state.unlimited_clicks = self.settings.click_count.is_none();
+        self.ui_state.template_path = self.settings.template.path.display().to_string();
+        self.ui_state.window_pattern = self.settings.target_window.pattern.clone();
+        self.ui_state.click_anchor = self
+            .settings
+            .click_target
+            .as_ref()
+            .map_or(PointAnchor::default(), |target| target.anchor);
+        self.script_window.sync_path(&self.settings.script);
+    }
+
+    /// 是否使用暗色主题
+    pub fn dark_mode(&self) -> bool {
+        self.ui_state.dark_mode
+    }
+
+    /// 设置主题，用于恢复上次的选择
+    pub fn set_dark_mode(&mut self, dark_mode: bool) {
+        self.ui_state.dark_mode = dark_mode;
+    }
+
+    /// 获取连点器句柄，供托盘使用
+    #[cfg(feature = "tray")]
+    pub fn clicker_handle(&self) -> Option<crate::core::ClickerHandle> {
+        self.clicker_manager.as_ref().map(|m| m.handle())
+    }
+
+    /// 设置托盘是否可用，可用时允许隐藏到托盘
+    #[cfg(feature = "tray")]
+    pub fn set_tray_enabled(&mut self, enabled: bool) {
+        self.tray_enabled = enabled;
+    }
+
+    /// 停止连点并释放按键，在应用退出时调用
+    pub fn shutdown(&mut self) {
+        if let Some(manager) = &mut self.clicker_manager {
+            manager.stop_with(StopReason::Exit);
+        }
+    }
+
     /// 获取当前设置
     pub fn get_settings(&self) -> &AppSettings {
         &self.settings
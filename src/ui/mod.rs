@@ -2,6 +2,26 @@
 //!
 //! 使用egui框架实现跨平台GUI界面
 
+pub mod click_overlay;
+pub mod diagnostics_window;
+pub mod dry_run_window;
+pub mod history_window;
+pub mod key_picker;
 pub mod main_window;
+pub mod point_picker;
+pub mod script_window;
+pub mod sequence_window;
+#[cfg(feature = "tray")]
+pub mod tray;
+pub mod window_picker;
 
+pub use click_overlay::*;
+pub use diagnostics_window::*;
+pub use dry_run_window::*;
+pub use history_window::*;
+pub use key_picker::*;
 pub use main_window::*;
+pub use point_picker::*;
+pub use script_window::*;
+pub use sequence_window::*;
+pub use window_picker::*;
@@ -0,0 +1,211 @@
+//! 坐标选取窗口
+//!
+//! 倒计时期间把指针移到目标位置，结束时记录指针坐标。
+//! 放大镜实时显示指针周围的像素和坐标，便于精确对准
+
+use crate::config::ClickPoint;
+use crate::core::condition::format_color;
+use crate::core::monitor::MonitorLayout;
+use crate::core::screen::ScreenReader;
+use egui::{Color32, Context, RichText, Stroke, TextureHandle, TextureOptions};
+use std::time::{Duration, Instant};
+
+/// 倒计时时长
+const COUNTDOWN: Duration = Duration::from_secs(3);
+
+/// 放大镜半径（像素），显示 (2r+1)×(2r+1) 个像素
+const MAGNIFIER_RADIUS: i32 = 7;
+
+/// 放大镜中每个像素的显示尺寸
+const MAGNIFIER_SCALE: f32 = 8.0;
+
+/// 选取过程中的刷新间隔
+const REFRESH_INTERVAL: Duration = Duration::from_millis(33);
+
+/// 选取的用途
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PickPurpose {
+    /// 点击位置
+    ClickTarget,
+    /// 像素颜色条件的采样位置和颜色
+    PixelCondition,
+}
+
+/// 选取结果
+#[derive(Debug, Clone, Copy)]
+pub struct PickedPoint {
+    pub purpose: PickPurpose,
+    pub point: ClickPoint,
+    /// 该位置的颜色，无法读取像素时为None
+    pub color: Option<[u8; 3]>,
+}
+
+/// 进行中的选取
+struct PickSession {
+    reader: ScreenReader,
+    /// 显示器布局，无法枚举显示器时为空
+    monitors: MonitorLayout,
+    purpose: PickPurpose,
+    deadline: Instant,
+}
+
+/// 坐标选取窗口
+#[derive(Default)]
+pub struct PointPicker {
+    session: Option<PickSession>,
+    /// 放大镜纹理
+    magnifier: Option<TextureHandle>,
+    /// 选取完成的结果，等待主窗口取走
+    picked: Option<PickedPoint>,
+}
+
+impl PointPicker {
+    /// 开始选取，`display` 为读取像素使用的X11显示
+    pub fn open(&mut self, display: Option<&str>, purpose: PickPurpose) -> Result<(), String> {
+        self.session = Some(PickSession {
+            reader: ScreenReader::new(display)?,
+            monitors: MonitorLayout::query(display).unwrap_or_default(),
+            purpose,
+            deadline: Instant::now() + COUNTDOWN,
+        });
+        Ok(())
+    }
+
+    /// 取出选取结果
+    pub fn take_picked(&mut self) -> Option<PickedPoint> {
+        self.picked.take()
+    }
+
+    /// 绘制窗口
+    pub fn show(&mut self, ctx: &Context) {
+        let Some(session) = &mut self.session else {
+            return;
+        };
+
+        let position = session.reader.cursor_position();
+        let remaining = session.deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            if let Ok((x, y)) = position {
+                let color = session
+                    .reader
+                    .capture(x, y, 1, 1)
+                    .ok()
+                    .and_then(|image| image.pixel(0, 0));
+                self.picked = Some(PickedPoint {
+                    purpose: session.purpose,
+                    point: ClickPoint { x, y },
+                    color,
+                });
+            }
+            self.session = None;
+            return;
+        }
+
+        // 读取指针周围的像素
+        let size = (MAGNIFIER_RADIUS * 2 + 1) as u32;
+        let capture = position.as_ref().map_err(Clone::clone).and_then(|&(x, y)| {
+            session
+                .reader
+                .capture(x - MAGNIFIER_RADIUS, y - MAGNIFIER_RADIUS, size, size)
+        });
+        let center_color = capture.as_ref().ok().and_then(|image| {
+            let center = MAGNIFIER_RADIUS as u32;
+            image.pixel(center, center)
+        });
+        if let Ok(image) = &capture {
+            let pixels: Vec<u8> = image.pixels.iter().flatten().copied().collect();
+            let color_image = egui::ColorImage::from_rgb([size as usize; 2], &pixels);
+            match &mut self.magnifier {
+                Some(texture) => texture.set(color_image, TextureOptions::NEAREST),
+                None => {
+                    self.magnifier =
+                        Some(ctx.load_texture("magnifier", color_image, TextureOptions::NEAREST))
+                }
+            }
+        }
+
+        let mut open = true;
+        let mut restart = false;
+        egui::Window::new(t!("picker.title"))
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
+            .show(ctx, |ui| {
+                ui.label(t!("picker.hint"));
+                ui.label(
+                    RichText::new(t!(
+                        "picker.countdown",
+                        seconds = format!("{:.1}", remaining.as_secs_f32())
+                    ))
+                    .strong(),
+                );
+                ui.add_space(6.0);
+
+                match &position {
+                    Ok((x, y)) => {
+                        ui.label(format!("X: {}  Y: {}", x, y));
+                        let point = ClickPoint { x: *x, y: *y };
+                        let monitors = &session.monitors;
+                        if let (Some(monitor), Some(logical)) =
+                            (monitors.monitor_at(point), monitors.to_logical(point))
+                        {
+                            ui.label(
+                                RichText::new(t!(
+                                    "picker.monitor",
+                                    name = monitor.name,
+                                    scale = monitor.scale,
+                                    x = format!("{:.0}", logical.x),
+                                    y = format!("{:.0}", logical.y)
+                                ))
+                                .small(),
+                            );
+                        }
+                    }
+                    Err(e) => {
+                        ui.colored_label(Color32::RED, e);
+                    }
+                }
+
+                match (&capture, &self.magnifier) {
+                    (Ok(_), Some(texture)) => {
+                        let side = size as f32 * MAGNIFIER_SCALE;
+                        let response =
+                            ui.add(egui::Image::new((texture.id(), egui::vec2(side, side))));
+                        // 标出中心像素
+                        let center = egui::Rect::from_center_size(
+                            response.rect.center(),
+                            egui::vec2(MAGNIFIER_SCALE, MAGNIFIER_SCALE),
+                        );
+                        ui.painter()
+                            .rect_stroke(center, 0.0, Stroke::new(1.0, Color32::RED));
+                    }
+                    (Err(e), _) if position.is_ok() => {
+                        ui.label(RichText::new(e).small());
+                    }
+                    _ => {}
+                }
+                if let Some(color @ [r, g, b]) = center_color {
+                    ui.horizontal(|ui| {
+                        let (rect, _) =
+                            ui.allocate_exact_size(egui::vec2(12.0, 12.0), egui::Sense::hover());
+                        ui.painter()
+                            .rect_filled(rect, 2.0, Color32::from_rgb(r, g, b));
+                        ui.label(format_color(color));
+                    });
+                }
+
+                ui.separator();
+                restart = ui.button(t!("picker.restart")).clicked();
+            });
+
+        if !open {
+            self.session = None;
+            return;
+        }
+        if restart {
+            session.deadline = Instant::now() + COUNTDOWN;
+        }
+        ctx.request_repaint_after(REFRESH_INTERVAL);
+    }
+}
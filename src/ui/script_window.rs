@@ -0,0 +1,147 @@
+//! 脚本窗口
+//!
+//! 编辑并运行点击脚本，显示脚本的输出
+
+use crate::config::ScriptSettings;
+use egui::{Context, RichText};
+use std::collections::VecDeque;
+use std::path::PathBuf;
+
+/// 保留的输出行数
+const MAX_OUTPUT_LINES: usize = 200;
+
+/// 脚本窗口
+#[derive(Default)]
+pub struct ScriptWindow {
+    /// 是否显示窗口
+    open: bool,
+    /// 脚本文件路径输入框的文本
+    path_text: String,
+    /// 最近的输出，最新的在后
+    output: VecDeque<String>,
+    /// 脚本设置是否被修改
+    changed: bool,
+    /// 用户是否请求运行或停止
+    toggle_requested: bool,
+}
+
+impl ScriptWindow {
+    /// 打开窗口
+    pub fn open(&mut self, settings: &ScriptSettings) {
+        self.sync_path(settings);
+        self.open = true;
+    }
+
+    /// 按设置刷新路径输入框，切换配置方案后调用
+    pub fn sync_path(&mut self, settings: &ScriptSettings) {
+        self.path_text = settings.path.display().to_string();
+    }
+
+    /// 追加一行输出
+    pub fn push_output(&mut self, line: String) {
+        if self.output.len() == MAX_OUTPUT_LINES {
+            self.output.pop_front();
+        }
+        self.output.push_back(line);
+    }
+
+    /// 取出“设置已修改”标记
+    pub fn take_changed(&mut self) -> bool {
+        std::mem::take(&mut self.changed)
+    }
+
+    /// 取出“运行/停止”请求
+    pub fn take_toggle_request(&mut self) -> bool {
+        std::mem::take(&mut self.toggle_requested)
+    }
+
+    /// 绘制窗口，`running` 表示连点器是否正在运行
+    pub fn show(&mut self, ctx: &Context, settings: &mut ScriptSettings, running: bool) {
+        if !self.open {
+            return;
+        }
+
+        let mut open = self.open;
+        egui::Window::new(t!("menu.script"))
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(true)
+            .default_width(360.0)
+            .show(ctx, |ui| {
+                self.changed |= ui
+                    .checkbox(&mut settings.enabled, t!("script.enabled"))
+                    .changed();
+
+                ui.horizontal(|ui| {
+                    ui.label(t!("script.path"));
+                    let response = ui.add(
+                        egui::TextEdit::singleline(&mut self.path_text)
+                            .hint_text(t!("script.path_hint"))
+                            .desired_width(220.0),
+                    );
+                    if response.lost_focus() {
+                        let path = PathBuf::from(self.path_text.trim());
+                        if path != settings.path {
+                            settings.path = path;
+                            self.changed = true;
+                        }
+                    }
+                });
+
+                // 设置了文件路径时运行文件，编辑框只作参考
+                let inline = settings.path.as_os_str().is_empty();
+                if !inline {
+                    ui.label(RichText::new(t!("script.using_file")).small());
+                }
+                egui::ScrollArea::vertical()
+                    .id_source("script_source")
+                    .max_height(240.0)
+                    .show(ui, |ui| {
+                        self.changed |= ui
+                            .add_enabled(
+                                inline,
+                                egui::TextEdit::multiline(&mut settings.source)
+                                    .code_editor()
+                                    .desired_rows(12)
+                                    .desired_width(f32::INFINITY),
+                            )
+                            .changed();
+                    });
+
+                ui.horizontal(|ui| {
+                    let text = if running {
+                        t!("script.stop")
+                    } else {
+                        t!("script.run")
+                    };
+                    if ui.button(text).clicked() {
+                        // 从脚本窗口运行时总是运行脚本
+                        if !running && !settings.enabled {
+                            settings.enabled = true;
+                            self.changed = true;
+                        }
+                        self.toggle_requested = true;
+                    }
+                    if ui.button(t!("script.clear_output")).clicked() {
+                        self.output.clear();
+                    }
+                });
+
+                ui.separator();
+                egui::ScrollArea::vertical()
+                    .id_source("script_output")
+                    .max_height(120.0)
+                    .stick_to_bottom(true)
+                    .show(ui, |ui| {
+                        if self.output.is_empty() {
+                            ui.label(RichText::new(t!("script.no_output")).weak());
+                        }
+                        for line in &self.output {
+                            ui.label(RichText::new(line).monospace());
+                        }
+                    });
+            });
+
+        self.open = open;
+    }
+}
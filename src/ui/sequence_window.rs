@@ -0,0 +1,267 @@
+//! 操作序列窗口
+//!
+//! 编辑连点器每轮按顺序执行的鼠标和键盘操作，运行时高亮正在执行的步骤
+
+use crate::config::{Action, ActionKind, ClickPoint, MouseButton, SequenceSettings};
+use crate::core::actions;
+use crate::ui::key_picker;
+use egui::{Color32, Context, RichText, Ui};
+
+/// 每层循环的缩进宽度
+const LOOP_INDENT: f32 = 16.0;
+
+/// 对某一步的编辑操作
+enum StepEdit {
+    MoveUp,
+    MoveDown,
+    Duplicate,
+    Remove,
+}
+
+/// 操作序列窗口
+#[derive(Default)]
+pub struct SequenceWindow {
+    /// 是否显示窗口
+    open: bool,
+    /// 正在执行的步骤
+    current_step: Option<usize>,
+    /// 序列设置是否被修改
+    changed: bool,
+    /// 用户是否请求运行或停止
+    toggle_requested: bool,
+}
+
+impl SequenceWindow {
+    /// 打开窗口
+    pub fn open(&mut self) {
+        self.open = true;
+    }
+
+    /// 设置正在执行的步骤，None表示没有在执行
+    pub fn set_current_step(&mut self, step: Option<usize>) {
+        self.current_step = step;
+    }
+
+    /// 取出“设置已修改”标记
+    pub fn take_changed(&mut self) -> bool {
+        std::mem::take(&mut self.changed)
+    }
+
+    /// 取出“运行/停止”请求
+    pub fn take_toggle_request(&mut self) -> bool {
+        std::mem::take(&mut self.toggle_requested)
+    }
+
+    /// 绘制窗口，`running` 表示连点器是否正在运行
+    pub fn show(&mut self, ctx: &Context, settings: &mut SequenceSettings, running: bool) {
+        if !self.open {
+            return;
+        }
+
+        let mut open = self.open;
+        let mut changed = false;
+        egui::Window::new(t!("menu.sequence"))
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(true)
+            .default_width(420.0)
+            .show(ctx, |ui| {
+                changed |= ui
+                    .checkbox(&mut settings.enabled, t!("sequence.enabled"))
+                    .changed();
+                ui.separator();
+
+                let errors = actions::validate(&settings.steps);
+                let mut edit = None;
+                // 运行期间不允许修改步骤，避免高亮的序号与正在执行的步骤不一致
+                ui.add_enabled_ui(!running, |ui| {
+                    egui::ScrollArea::vertical()
+                        .max_height(320.0)
+                        .show(ui, |ui| {
+                            if settings.steps.is_empty() {
+                                ui.label(RichText::new(t!("sequence.empty")).weak());
+                            }
+                            let count = settings.steps.len();
+                            let mut depth = 0usize;
+                            for (index, action) in settings.steps.iter_mut().enumerate() {
+                                if *action == Action::EndLoop {
+                                    depth = depth.saturating_sub(1);
+                                }
+                                let current = running && self.current_step == Some(index);
+                                let frame = if current {
+                                    egui::Frame::none().fill(ui.visuals().selection.bg_fill)
+                                } else {
+                                    egui::Frame::none()
+                                };
+                                frame.show(ui, |ui| {
+                                    ui.horizontal(|ui| {
+                                        ui.add_space(depth as f32 * LOOP_INDENT);
+                                        ui.label(format!("{}. {}", index + 1, action.kind()));
+                                        changed |= edit_action(ui, index, action);
+                                        if let Some(step_edit) = step_buttons(ui, index, count) {
+                                            edit = Some((index, step_edit));
+                                        }
+                                    });
+                                    if let Some(error) = &errors[index] {
+                                        ui.horizontal(|ui| {
+                                            ui.add_space(depth as f32 * LOOP_INDENT);
+                                            ui.colored_label(Color32::RED, error);
+                                        });
+                                    }
+                                });
+                                if let Action::Loop { .. } = action {
+                                    depth += 1;
+                                }
+                            }
+                        });
+
+                    ui.separator();
+                    ui.menu_button(t!("sequence.add"), |ui| {
+                        for kind in ActionKind::all() {
+                            if ui.button(kind.to_string()).clicked() {
+                                settings.steps.push(kind.default_action());
+                                changed = true;
+                                ui.close_menu();
+                            }
+                        }
+                    });
+                });
+                if let Some((index, step_edit)) = edit {
+                    apply_edit(&mut settings.steps, index, step_edit);
+                    changed = true;
+                }
+
+                ui.separator();
+                let valid = !settings.steps.is_empty() && errors.iter().all(Option::is_none);
+                let text = if running {
+                    t!("sequence.stop")
+                } else {
+                    t!("sequence.run")
+                };
+                if ui
+                    .add_enabled(running || valid, egui::Button::new(text))
+                    .clicked()
+                {
+                    // 从序列窗口运行时总是运行序列
+                    if !running && !settings.enabled {
+                        settings.enabled = true;
+                        changed = true;
+                    }
+                    self.toggle_requested = true;
+                }
+            });
+
+        self.open = open;
+        self.changed |= changed;
+    }
+}
+
+/// 绘制步骤的上移、下移、复制和删除按钮
+fn step_buttons(ui: &mut Ui, index: usize, count: usize) -> Option<StepEdit> {
+    let mut edit = None;
+    if ui
+        .add_enabled(index > 0, egui::Button::new("⬆").small())
+        .on_hover_text(t!("sequence.move_up"))
+        .clicked()
+    {
+        edit = Some(StepEdit::MoveUp);
+    }
+    if ui
+        .add_enabled(index + 1 < count, egui::Button::new("⬇").small())
+        .on_hover_text(t!("sequence.move_down"))
+        .clicked()
+    {
+        edit = Some(StepEdit::MoveDown);
+    }
+    if ui
+        .small_button("⧉")
+        .on_hover_text(t!("sequence.duplicate"))
+        .clicked()
+    {
+        edit = Some(StepEdit::Duplicate);
+    }
+    if ui
+        .small_button("✖")
+        .on_hover_text(t!("sequence.remove"))
+        .clicked()
+    {
+        edit = Some(StepEdit::Remove);
+    }
+    edit
+}
+
+/// 对步骤列表执行编辑操作
+fn apply_edit(steps: &mut Vec<Action>, index: usize, edit: StepEdit) {
+    match edit {
+        StepEdit::MoveUp => steps.swap(index - 1, index),
+        StepEdit::MoveDown => steps.swap(index, index + 1),
+        StepEdit::Duplicate => steps.insert(index + 1, steps[index].clone()),
+        StepEdit::Remove => {
+            steps.remove(index);
+        }
+    }
+}
+
+/// 绘制一步操作的参数，返回参数是否改变
+fn edit_action(ui: &mut Ui, index: usize, action: &mut Action) -> bool {
+    match action {
+        Action::Click { button, point } => {
+            let mut changed = false;
+            egui::ComboBox::from_id_source(("sequence_button", index))
+                .selected_text(button.to_string())
+                .show_ui(ui, |ui| {
+                    for option in MouseButton::all() {
+                        changed |= ui
+                            .selectable_value(button, option, option.to_string())
+                            .changed();
+                    }
+                });
+            let mut fixed = point.is_some();
+            if ui.checkbox(&mut fixed, t!("sequence.at_point")).changed() {
+                *point = fixed.then_some(ClickPoint { x: 0, y: 0 });
+                changed = true;
+            }
+            if let Some(point) = point {
+                changed |= edit_point(ui, point);
+            }
+            changed
+        }
+        Action::Move { point } => edit_point(ui, point),
+        Action::Wait { ms } => ui
+            .add(
+                egui::DragValue::new(ms)
+                    .suffix(" ms")
+                    .clamp_range(0..=3_600_000),
+            )
+            .changed(),
+        Action::Scroll { amount } => ui
+            .add(egui::DragValue::new(amount).clamp_range(-100..=100))
+            .changed(),
+        Action::KeyTap { key } | Action::KeyPress { key } | Action::KeyRelease { key } => {
+            key_picker(ui, ("sequence_key", index), key)
+        }
+        Action::TypeText { text } => ui
+            .add(egui::TextEdit::singleline(text).desired_width(160.0))
+            .changed(),
+        Action::Drag { from, to } => {
+            let from_changed = edit_point(ui, from);
+            ui.label("→");
+            edit_point(ui, to) || from_changed
+        }
+        Action::Loop { count } => ui
+            .add(
+                egui::DragValue::new(count)
+                    .prefix("× ")
+                    .clamp_range(1..=1_000_000),
+            )
+            .changed(),
+        Action::EndLoop => false,
+    }
+}
+
+/// 绘制坐标输入框，返回坐标是否改变
+fn edit_point(ui: &mut Ui, point: &mut ClickPoint) -> bool {
+    let x = ui.add(egui::DragValue::new(&mut point.x).prefix("x: "));
+    let y = ui.add(egui::DragValue::new(&mut point.y).prefix("y: "));
+    x.changed() || y.changed()
+}
@@ -0,0 +1,371 @@
+//! 系统托盘
+//!
+//! 托盘图标显示运行状态，菜单提供开始/停止、切换配置、显示窗口和退出。
+//! 菜单事件在后台线程处理，主窗口隐藏到托盘时依然可以控制连点器并看到最新状态
+
+use crate::config::ProfileStore;
+use crate::core::{ClickerHandle, ClickerState, ClickerStatus, StopReason};
+use crate::i18n::{self, Locale};
+use egui::{Context, ViewportCommand};
+use std::thread;
+use std::time::{Duration, Instant};
+use tray_icon::menu::{CheckMenuItem, Menu, MenuEvent, MenuItem, PredefinedMenuItem, Submenu};
+use tray_icon::{Icon, TrayIcon, TrayIconBuilder};
+
+/// 托盘刷新间隔（毫秒）
+#[cfg(any(target_os = "linux", target_os = "windows"))]
+const REFRESH_INTERVAL_MS: u64 = 500;
+
+/// 重新读取配置列表的间隔
+const PROFILE_LIST_INTERVAL: Duration = Duration::from_secs(2);
+
+const ID_TOGGLE: &str = "toggle";
+const ID_SHOW: &str = "show";
+const ID_QUIT: &str = "quit";
+const PROFILE_ID_PREFIX: &str = "profile:";
+
+/// 托盘菜单命令
+enum TrayCommand {
+    Toggle,
+    ShowWindow,
+    SwitchProfile(String),
+    Quit,
+}
+
+impl TrayCommand {
+    /// 根据菜单项ID解析命令
+    fn from_id(id: &str) -> Option<Self> {
+        match id {
+            ID_TOGGLE => Some(Self::Toggle),
+            ID_SHOW => Some(Self::ShowWindow),
+            ID_QUIT => Some(Self::Quit),
+            _ => id
+                .strip_prefix(PROFILE_ID_PREFIX)
+                .map(|name| Self::SwitchProfile(name.to_string())),
+        }
+    }
+}
+
+/// 托盘图标
+pub struct Tray {
+    handle: ClickerHandle,
+    icon: TrayIcon,
+    running_icon: Icon,
+    stopped_icon: Icon,
+    toggle_item: MenuItem,
+    show_item: MenuItem,
+    quit_item: MenuItem,
+    profiles_menu: Submenu,
+    profile_items: Vec<CheckMenuItem>,
+    /// 上次显示的状态，状态不变时不重复更新
+    shown_state: Option<ClickerState>,
+    shown_locale: Locale,
+    shown_tooltip: String,
+    /// 上次显示的配置列表及当前配置
+    shown_profiles: (Vec<String>, String),
+    /// 上次读取配置列表的时间
+    profiles_listed_at: Option<Instant>,
+}
+
+impl Tray {
+    /// 创建托盘图标
+    fn new(handle: ClickerHandle) -> Result<Self, String> {
+        let (running_icon, stopped_icon) = load_icons()?;
+
+        let toggle_item = MenuItem::with_id(ID_TOGGLE, t!("control.start"), true, None);
+        let profiles_menu = Submenu::new(t!("tray.switch_profile"), true);
+        let show_item = MenuItem::with_id(ID_SHOW, t!("tray.show_window"), true, None);
+        let quit_item = MenuItem::with_id(ID_QUIT, t!("app.quit"), true, None);
+        let menu = Menu::new();
+        menu.append_items(&[
+            &toggle_item,
+            &profiles_menu,
+            &PredefinedMenuItem::separator(),
+            &show_item,
+            &quit_item,
+        ])
+        .map_err(|e| t!("tray.error.menu", error = e))?;
+
+        let icon = TrayIconBuilder::new()
+            .with_menu(Box::new(menu))
+            .with_tooltip("Mouse Clicker")
+            .with_icon(stopped_icon.clone())
+            .build()
+            .map_err(|e| t!("tray.error.icon", error = e))?;
+
+        Ok(Self {
+            handle,
+            icon,
+            running_icon,
+            stopped_icon,
+            toggle_item,
+            show_item,
+            quit_item,
+            profiles_menu,
+            profile_items: Vec::new(),
+            shown_state: None,
+            shown_locale: i18n::locale(),
+            shown_tooltip: String::new(),
+            shown_profiles: (Vec::new(), String::new()),
+            profiles_listed_at: None,
+        })
+    }
+
+    /// 根据连点器状态刷新图标、提示和菜单
+    pub fn refresh(&mut self) {
+        let status = self.handle.get_status();
+        let profile = self.handle.settings().profile;
+
+        // 切换语言后重新设置固定菜单项的文字
+        let locale = i18n::locale();
+        if locale != self.shown_locale {
+            self.profiles_menu.set_text(t!("tray.switch_profile"));
+            self.show_item.set_text(t!("tray.show_window"));
+            self.quit_item.set_text(t!("app.quit"));
+            self.shown_locale = locale;
+            self.shown_state = None;
+        }
+
+        if self.shown_state != Some(status.state) {
+            let (icon, text) = match status.state {
+                ClickerState::Running => (&self.running_icon, t!("control.stop")),
+                ClickerState::Stopped => (&self.stopped_icon, t!("control.start")),
+            };
+            if let Err(e) = self.icon.set_icon(Some(icon.clone())) {
+                log::warn!("更新托盘图标失败: {}", e);
+            }
+            self.toggle_item.set_text(text);
+            self.shown_state = Some(status.state);
+        }
+
+        let tooltip = tooltip(&status, &profile);
+        if tooltip != self.shown_tooltip {
+            if let Err(e) = self.icon.set_tooltip(Some(&tooltip)) {
+                log::warn!("更新托盘提示失败: {}", e);
+            }
+            self.shown_tooltip = tooltip;
+        }
+
+        // 配置列表变化不频繁，当前配置未变时限制读取目录的频率
+        let list_due = self
+            .profiles_listed_at
+            .is_none_or(|at| at.elapsed() >= PROFILE_LIST_INTERVAL);
+        if !list_due && profile == self.shown_profiles.1 {
+            return;
+        }
+        self.profiles_listed_at = Some(Instant::now());
+
+        // 当前配置可能尚未保存过，同样列出
+        let mut profiles = ProfileStore::new()
+            .and_then(|store| store.list())
+            .unwrap_or_default();
+        if !profiles.contains(&profile) {
+            profiles.push(profile.clone());
+            profiles.sort();
+        }
+        if (&profiles, &profile) != (&self.shown_profiles.0, &self.shown_profiles.1) {
+            self.rebuild_profiles(&profiles, &profile);
+            self.shown_profiles = (profiles, profile);
+        }
+    }
+
+    /// 重建配置子菜单
+    fn rebuild_profiles(&mut self, profiles: &[String], current: &str) {
+        for item in self.profile_items.drain(..) {
+            let _ = self.profiles_menu.remove(&item);
+        }
+        for name in profiles {
+            let item = CheckMenuItem::with_id(
+                format!("{}{}", PROFILE_ID_PREFIX, name),
+                name,
+                true,
+                name == current,
+                None,
+            );
+            if let Err(e) = self.profiles_menu.append(&item) {
+                log::warn!("添加托盘配置项失败: {}", e);
+            }
+            self.profile_items.push(item);
+        }
+    }
+}
+
+/// 托盘提示文字
+fn tooltip(status: &ClickerStatus, profile: &str) -> String {
+    match status.state {
+        ClickerState::Running => match status.target_count {
+            Some(target) => t!(
+                "tray.tooltip.running",
+                profile = profile,
+                count = format!("{}/{}", status.click_count, target)
+            ),
+            None => t!(
+                "tray.tooltip.running",
+                profile = profile,
+                count = status.click_count
+            ),
+        },
+        ClickerState::Stopped => t!("tray.tooltip.stopped", profile = profile),
+    }
+}
+
+/// 加载运行中和已停止两种托盘图标，停止时显示灰度图标
+fn load_icons() -> Result<(Icon, Icon), String> {
+    let data = eframe::icon_data::from_png_bytes(&include_bytes!("../../assets/icon.png")[..])
+        .map_err(|e| t!("tray.error.load_icon", error = e))?;
+
+    let gray = data
+        .rgba
+        .chunks_exact(4)
+        .flat_map(|p| {
+            let l =
+                (0.299 * f32::from(p[0]) + 0.587 * f32::from(p[1]) + 0.114 * f32::from(p[2])) as u8;
+            [l, l, l, p[3]]
+        })
+        .collect();
+
+    let running = Icon::from_rgba(data.rgba, data.width, data.height)
+        .map_err(|e| t!("tray.error.load_icon", error = e))?;
+    let stopped = Icon::from_rgba(gray, data.width, data.height)
+        .map_err(|e| t!("tray.error.load_icon", error = e))?;
+    Ok((running, stopped))
+}
+
+/// 启动托盘
+///
+/// Linux和Windows上托盘运行在独立线程的事件循环中并自行刷新，返回None；
+/// macOS上托盘必须在主线程创建，返回的托盘需要由调用方定期调用 [`Tray::refresh`]，
+/// 连点器状态变化时会请求重绘，窗口隐藏到托盘后依然刷新
+pub fn spawn(handle: ClickerHandle, ctx: Context) -> Result<Option<Tray>, String> {
+    #[cfg(any(target_os = "linux", target_os = "windows"))]
+    let tray = {
+        spawn_thread(handle.clone())?;
+        None
+    };
+    #[cfg(not(any(target_os = "linux", target_os = "windows")))]
+    let tray = {
+        spawn_repaint_on_events(&handle, ctx.clone());
+        Some(Tray::new(handle.clone())?)
+    };
+
+    spawn_menu_handler(handle, ctx);
+    Ok(tray)
+}
+
+/// 在独立线程中创建托盘，并在该线程的事件循环中定期刷新
+#[cfg(any(target_os = "linux", target_os = "windows"))]
+fn spawn_thread(handle: ClickerHandle) -> Result<(), String> {
+    use std::sync::mpsc;
+
+    let (result_sender, result_receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let tray = init_event_loop().and_then(|_| Tray::new(handle));
+        let mut tray = match tray {
+            Ok(tray) => {
+                let _ = result_sender.send(Ok(()));
+                tray
+            }
+            Err(e) => {
+                let _ = result_sender.send(Err(e));
+                return;
+            }
+        };
+
+        tray.refresh();
+        run_event_loop(tray);
+    });
+
+    result_receiver
+        .recv()
+        .map_err(|_| t!("tray.error.thread").to_string())?
+}
+
+/// 初始化当前线程的GTK
+#[cfg(target_os = "linux")]
+fn init_event_loop() -> Result<(), String> {
+    gtk::init().map_err(|e| t!("tray.error.gtk", error = e))
+}
+
+/// Win32消息循环不需要初始化，托盘图标在首次创建时注册到当前线程
+#[cfg(target_os = "windows")]
+fn init_event_loop() -> Result<(), String> {
+    Ok(())
+}
+
+/// 运行GTK主循环并定期刷新托盘
+#[cfg(target_os = "linux")]
+fn run_event_loop(mut tray: Tray) {
+    gtk::glib::timeout_add_local(Duration::from_millis(REFRESH_INTERVAL_MS), move || {
+        tray.refresh();
+        gtk::glib::ControlFlow::Continue
+    });
+    gtk::main();
+}
+
+/// 运行Win32消息循环，由线程计时器定期刷新托盘
+#[cfg(target_os = "windows")]
+fn run_event_loop(mut tray: Tray) {
+    use windows::Win32::UI::WindowsAndMessaging::{
+        DispatchMessageW, GetMessageW, SetTimer, TranslateMessage, MSG, WM_TIMER,
+    };
+
+    // 不指定窗口的计时器把WM_TIMER投递到当前线程的消息队列
+    unsafe { SetTimer(None, 0, REFRESH_INTERVAL_MS as u32, None) };
+    let mut msg = MSG::default();
+    // GetMessageW收到WM_QUIT时返回0，出错时返回-1
+    while unsafe { GetMessageW(&mut msg, None, 0, 0) }.0 > 0 {
+        if msg.message == WM_TIMER && msg.hwnd.is_invalid() {
+            tray.refresh();
+            continue;
+        }
+        unsafe {
+            let _ = TranslateMessage(&msg);
+            DispatchMessageW(&msg);
+        }
+    }
+}
+
+/// 连点器状态变化时请求重绘，让主线程刷新托盘
+///
+/// 窗口隐藏到托盘后没有输入事件驱动界面更新，只能依靠订阅的事件唤醒
+#[cfg(not(any(target_os = "linux", target_os = "windows")))]
+fn spawn_repaint_on_events(handle: &ClickerHandle, ctx: Context) {
+    let events = handle.subscribe();
+    thread::spawn(move || {
+        for _ in events.iter() {
+            ctx.request_repaint();
+        }
+    });
+}
+
+/// 在后台线程处理托盘菜单事件
+fn spawn_menu_handler(handle: ClickerHandle, ctx: Context) {
+    thread::spawn(move || {
+        for event in MenuEvent::receiver().iter() {
+            let Some(command) = TrayCommand::from_id(event.id.as_ref()) else {
+                continue;
+            };
+            match command {
+                TrayCommand::Toggle => handle.toggle_and_report(),
+                TrayCommand::ShowWindow => {
+                    ctx.send_viewport_cmd(ViewportCommand::Visible(true));
+                    ctx.send_viewport_cmd(ViewportCommand::Focus);
+                }
+                TrayCommand::SwitchProfile(name) => {
+                    if let Err(e) =
+                        ProfileStore::new().and_then(|store| handle.switch_profile(&store, &name))
+                    {
+                        log::error!("切换配置失败: {}", e);
+                    }
+                }
+                TrayCommand::Quit => {
+                    handle.stop_with(StopReason::Exit);
+                    // 窗口隐藏时可能不处理关闭命令，先恢复显示
+                    ctx.send_viewport_cmd(ViewportCommand::Visible(true));
+                    ctx.send_viewport_cmd(ViewportCommand::Close);
+                }
+            }
+            ctx.request_repaint();
+        }
+    });
+}
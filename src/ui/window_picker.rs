@@ -0,0 +1,115 @@
+//! 目标窗口选取窗口
+//!
+//! 倒计时期间切换到目标窗口，结束时记录当前的前台窗口
+
+use crate::core::window::{WindowInfo, WindowInspector};
+use egui::{Color32, Context, RichText};
+use std::time::{Duration, Instant};
+
+/// 倒计时时长
+const COUNTDOWN: Duration = Duration::from_secs(3);
+
+/// 选取过程中的刷新间隔
+const REFRESH_INTERVAL: Duration = Duration::from_millis(100);
+
+/// 进行中的选取
+struct PickSession {
+    inspector: WindowInspector,
+    deadline: Instant,
+}
+
+/// 目标窗口选取窗口
+#[derive(Default)]
+pub struct WindowPicker {
+    session: Option<PickSession>,
+    /// 选取完成的窗口，等待主窗口取走
+    picked: Option<WindowInfo>,
+}
+
+impl WindowPicker {
+    /// 开始选取，`display` 为读取前台窗口使用的X11显示
+    pub fn open(&mut self, display: Option<&str>) -> Result<(), String> {
+        self.session = Some(PickSession {
+            inspector: WindowInspector::new(display)?,
+            deadline: Instant::now() + COUNTDOWN,
+        });
+        Ok(())
+    }
+
+    /// 取出选取完成的窗口
+    pub fn take_picked(&mut self) -> Option<WindowInfo> {
+        self.picked.take()
+    }
+
+    /// 绘制窗口
+    pub fn show(&mut self, ctx: &Context) {
+        let Some(session) = &mut self.session else {
+            return;
+        };
+
+        let active = session.inspector.active_window();
+        let remaining = session.deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            if let Ok(window) = active {
+                self.picked = window;
+            }
+            self.session = None;
+            return;
+        }
+
+        let mut open = true;
+        let mut restart = false;
+        egui::Window::new(t!("window_picker.title"))
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
+            .show(ctx, |ui| {
+                ui.label(t!("window_picker.hint"));
+                ui.label(
+                    RichText::new(t!(
+                        "picker.countdown",
+                        seconds = format!("{:.1}", remaining.as_secs_f32())
+                    ))
+                    .strong(),
+                );
+                ui.add_space(6.0);
+
+                match &active {
+                    Ok(Some(window)) => {
+                        egui::Grid::new("active_window")
+                            .num_columns(2)
+                            .show(ui, |ui| {
+                                ui.label(t!("window_match.title"));
+                                ui.label(&window.title);
+                                ui.end_row();
+                                ui.label(t!("window_match.class"));
+                                ui.label(&window.class);
+                                ui.end_row();
+                                ui.label(t!("window_match.process"));
+                                ui.label(window.process.as_deref().unwrap_or("-"));
+                                ui.end_row();
+                            });
+                    }
+                    Ok(None) => {
+                        ui.label(t!("window_picker.none"));
+                    }
+                    Err(e) => {
+                        ui.colored_label(Color32::RED, e);
+                    }
+                }
+
+                ui.separator();
+                restart = ui.button(t!("picker.restart")).clicked();
+            });
+
+        if !open {
+            self.session = None;
+            return;
+        }
+        if restart {
+            session.deadline = Instant::now() + COUNTDOWN;
+        }
+        ctx.request_repaint_after(REFRESH_INTERVAL);
+    }
+}
@@ -0,0 +1,76 @@
+//! XTest后端集成测试
+//!
+//! 需要一个可用的X11显示（通常是Xvfb），测试读取环境变量 `MOUSE_CLICKER_TEST_DISPLAY`
+//! 作为显示名称，未设置时跳过。测试会监听根窗口上的点击，显示上不能运行窗口管理器。
+//! 与CI相同的用法，`xvfb-run` 启动Xvfb并在命令结束后关闭：
+//!
+//! ```sh
+//! xvfb-run --server-num=99 --server-args="-screen 0 1280x720x24" \
+//!     env MOUSE_CLICKER_TEST_DISPLAY=:99 cargo test --test xtest
+//! ```
+#![cfg(target_os = "linux")]
+
+use std::process::Command;
+use std::time::{Duration, Instant};
+use x11rb::connection::Connection;
+use x11rb::protocol::xproto::{ChangeWindowAttributesAux, ConnectionExt as _, EventMask};
+use x11rb::protocol::Event;
+
+/// 测试使用的显示，未配置时返回None
+fn test_display() -> Option<String> {
+    let display = std::env::var("MOUSE_CLICKER_TEST_DISPLAY").ok();
+    if display.is_none() {
+        eprintln!("未设置 MOUSE_CLICKER_TEST_DISPLAY，跳过XTest集成测试");
+    }
+    display
+}
+
+#[test]
+fn probe_moves_the_pointer_and_clicks_at_the_screen_center() {
+    let Some(display) = test_display() else {
+        return;
+    };
+
+    let (conn, screen_num) = x11rb::connect(Some(&display)).expect("连接测试显示失败");
+    let screen = &conn.setup().roots[screen_num];
+    let root = screen.root;
+    let center = (
+        i16::try_from(screen.width_in_pixels / 2).unwrap(),
+        i16::try_from(screen.height_in_pixels / 2).unwrap(),
+    );
+
+    // 没有窗口管理器时可以直接监听根窗口上的点击
+    conn.change_window_attributes(
+        root,
+        &ChangeWindowAttributesAux::new().event_mask(EventMask::BUTTON_PRESS),
+    )
+    .unwrap()
+    .check()
+    .expect("无法监听根窗口的点击事件");
+    conn.flush().unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_mouse-clicker"))
+        .args(["--backend", "xtest", "--display", &display, "probe"])
+        .output()
+        .expect("无法运行mouse-clicker");
+    assert!(
+        output.status.success(),
+        "probe失败: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let pointer = conn.query_pointer(root).unwrap().reply().unwrap();
+    assert_eq!((pointer.root_x, pointer.root_y), center);
+
+    let deadline = Instant::now() + Duration::from_secs(5);
+    let press = loop {
+        match conn.poll_for_event().unwrap() {
+            Some(Event::ButtonPress(event)) => break event,
+            Some(_) => {}
+            None if Instant::now() < deadline => std::thread::sleep(Duration::from_millis(10)),
+            None => panic!("没有收到点击事件"),
+        }
+    };
+    assert_eq!(press.detail, 1);
+    assert_eq!((press.root_x, press.root_y), center);
+}